@@ -0,0 +1,80 @@
+//! Action
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::ActionVersion;
+
+/// The build status of an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionStatus {
+  /// The action is waiting to be built.
+  Pending,
+  /// The action is being built.
+  Building,
+  /// The action was built successfully.
+  Built,
+  /// The action failed to build.
+  Failed,
+}
+
+/// Trigger an action can be bound to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionTrigger {
+  /// ID of the trigger, e.g. `post-login`.
+  pub id: String,
+  /// Version of the trigger, e.g. `v3`.
+  pub version: String,
+}
+
+/// npm dependency of an action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionDependency {
+  /// Name of the npm package.
+  pub name: String,
+  /// Version of the npm package.
+  pub version: String,
+}
+
+/// Secret available to an action at runtime.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionSecret {
+  /// Name of the secret.
+  pub name: String,
+  /// Value of the secret.  Never returned by Auth0.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub value: Option<String>,
+}
+
+/// An action.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Action {
+  /// The unique ID of the action.
+  pub id: String,
+  /// The name of the action.
+  #[serde(default)]
+  pub name: String,
+  /// The list of triggers that this action supports.
+  #[serde(default)]
+  pub supported_triggers: Vec<ActionTrigger>,
+  /// The source code of the action.
+  pub code: Option<String>,
+  /// The list of third party npm modules, and their versions, that this action depends
+  /// on.
+  #[serde(default)]
+  pub dependencies: Vec<ActionDependency>,
+  /// The Node runtime, e.g. `node18`.
+  pub runtime: Option<String>,
+  /// The list of secrets that are included in the action.
+  #[serde(default)]
+  pub secrets: Vec<ActionSecret>,
+  /// The build status of the action.
+  pub status: Option<ActionStatus>,
+  /// True if all of an action's contents have been deployed.
+  #[serde(default)]
+  pub all_changes_deployed: bool,
+  /// The time when the action was built.
+  pub built_at: Option<DateTime<Utc>>,
+  /// The version of the action that is currently deployed.
+  pub deployed_version: Option<ActionVersion>,
+}
@@ -0,0 +1,93 @@
+//! Update the actions bound to a trigger.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Reference to the action being bound.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ActionBindingRef {
+  /// Reference an action by ID.
+  ActionId(String),
+  /// Reference an action by name.
+  ActionName(String),
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ActionBindingUpdateItem {
+  #[serde(rename = "ref")]
+  reference: ActionBindingRef,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  display_name: Option<String>,
+}
+
+/// Update the actions that are bound to a trigger.  The bindings given replace the
+/// existing ones and are executed in the order they are added.
+///
+/// Send as `ResponsePage<ActionBinding>`.
+///
+/// # Scopes
+/// * `update:actions`
+#[derive(Serialize)]
+pub struct ActionBindingUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  trigger_id: String,
+  bindings: Vec<ActionBindingUpdateItem>,
+}
+
+impl<'a> ActionBindingUpdate<'a> {
+  /// Create update action bindings request.
+  ///
+  /// # Arguments
+  /// * `trigger_id` - An actions extensibility point, e.g. `post-login`.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, trigger_id: S) -> Self {
+    Self {
+      client,
+
+      trigger_id: trigger_id.as_ref().to_string(),
+      bindings: Vec::new(),
+    }
+  }
+
+  /// Append an action to the trigger pipeline.
+  ///
+  /// # Arguments
+  /// * `reference` - The action to bind.
+  /// * `display_name` - The name of the binding.
+  pub fn binding(
+    mut self,
+    reference: ActionBindingRef,
+    display_name: Option<&str>,
+  ) -> Self {
+    self.bindings.push(ActionBindingUpdateItem {
+      reference,
+      display_name: display_name.map(|name| name.to_owned()),
+    });
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ActionBindingUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ActionBindingUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!(
+          "api/v2/actions/triggers/{}/bindings",
+          path_segment(&self.trigger_id)
+        ),
+      )
+      .json(self)
+  }
+}
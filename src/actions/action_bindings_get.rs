@@ -0,0 +1,60 @@
+//! Retrieve the actions bound to a trigger.
+use chrono::{DateTime, Utc};
+use reqwest::{Method, RequestBuilder};
+use serde::Deserialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Summary of the action referenced by a binding.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionBindingAction {
+  /// The unique ID of the action.
+  pub id: String,
+  /// The name of the action.
+  pub name: String,
+}
+
+/// Binding of an action to a trigger.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionBinding {
+  /// The unique ID of this binding.
+  pub id: String,
+  /// The trigger the action is bound to, e.g. `post-login`.
+  pub trigger_id: String,
+  /// The name of the binding.
+  pub display_name: Option<String>,
+  /// The action bound to the trigger.
+  pub action: ActionBindingAction,
+  /// The time when the binding was created.
+  pub created_at: DateTime<Utc>,
+  /// The time when the binding was updated.
+  pub updated_at: DateTime<Utc>,
+}
+
+/// Retrieve the actions that are bound to a trigger, in the order they are executed.
+///
+/// Send as `ResponsePage<ActionBinding>`.
+///
+/// # Scopes
+/// * `read:actions`
+pub struct ActionBindingsGet(String);
+
+impl ActionBindingsGet {
+  /// Create get action bindings request.
+  ///
+  /// # Arguments
+  /// * `trigger_id` - An actions extensibility point, e.g. `post-login`.
+  pub fn new<S: AsRef<str>>(trigger_id: S) -> Self {
+    Self(trigger_id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for ActionBindingsGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/actions/triggers/{}/bindings", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,102 @@
+//! Create an action.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{ActionDependency, ActionSecret, ActionTrigger};
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Create an action.  Once created, the action must be deployed with
+/// [ActionDeploy](crate::ActionDeploy) before it can be bound to a trigger.
+///
+/// Send as [Action](crate::Action).
+///
+/// # Scopes
+/// * `create:actions`
+#[derive(Serialize)]
+pub struct ActionCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  name: String,
+  supported_triggers: Vec<ActionTrigger>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  code: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  dependencies: Vec<ActionDependency>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  runtime: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  secrets: Vec<ActionSecret>,
+}
+
+impl<'a> ActionCreate<'a> {
+  /// Create create action request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the action.
+  pub fn new(client: &'a Auth0Client, name: &str) -> Self {
+    Self {
+      client,
+
+      name: name.to_owned(),
+      supported_triggers: Vec::new(),
+      code: None,
+      dependencies: Vec::new(),
+      runtime: None,
+      secrets: Vec::new(),
+    }
+  }
+
+  /// Add a trigger that this action supports, e.g. `post-login` version `v3`.
+  pub fn trigger(mut self, id: &str, version: &str) -> Self {
+    self.supported_triggers.push(ActionTrigger {
+      id: id.to_owned(),
+      version: version.to_owned(),
+    });
+    self
+  }
+
+  /// The source code of the action.
+  pub fn code(mut self, code: &str) -> Self {
+    self.code = Some(code.to_owned());
+    self
+  }
+
+  /// Add a third party npm module that this action depends on.
+  pub fn dependency(mut self, name: &str, version: &str) -> Self {
+    self.dependencies.push(ActionDependency {
+      name: name.to_owned(),
+      version: version.to_owned(),
+    });
+    self
+  }
+
+  /// The Node runtime, e.g. `node18`.
+  pub fn runtime(mut self, runtime: &str) -> Self {
+    self.runtime = Some(runtime.to_owned());
+    self
+  }
+
+  /// Add a secret that is available to the action at runtime.
+  pub fn secret(mut self, name: &str, value: &str) -> Self {
+    self.secrets.push(ActionSecret {
+      name: name.to_owned(),
+      value: Some(value.to_owned()),
+    });
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ActionCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ActionCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/actions/actions")
+      .json(self)
+  }
+}
@@ -0,0 +1,30 @@
+//! Delete an action.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete an action by its ID.
+///
+/// # Scopes
+/// * `delete:actions`
+pub struct ActionDelete(String);
+
+impl ActionDelete {
+  /// Create delete action request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action to delete.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for ActionDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/actions/actions/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,33 @@
+//! Deploy an action.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Deploy an action.  Changes to an action are not live until it is deployed, which
+/// creates a new version of the action.
+///
+/// Send as [ActionVersion](crate::ActionVersion).
+///
+/// # Scopes
+/// * `create:actions`
+pub struct ActionDeploy(String);
+
+impl ActionDeploy {
+  /// Create deploy action request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action to deploy.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for ActionDeploy {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::POST,
+      &format!("api/v2/actions/actions/{}/deploy", path_segment(&self.0)),
+    )
+  }
+}
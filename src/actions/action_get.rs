@@ -0,0 +1,30 @@
+//! Retrieve an action.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve an action by its ID.
+///
+/// # Scopes
+/// * `read:actions`
+pub struct ActionGet(String);
+
+impl ActionGet {
+  /// Create get action request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action to retrieve.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for ActionGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/actions/actions/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,129 @@
+//! Update an action.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{ActionDependency, ActionSecret, ActionTrigger};
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Update an existing action.  If this action is currently bound to a trigger, updating
+/// it will not affect the running action until it is deployed.
+///
+/// Send as [Action](crate::Action).
+///
+/// # Scopes
+/// * `update:actions`
+#[derive(Serialize)]
+pub struct ActionUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  supported_triggers: Option<Vec<ActionTrigger>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  dependencies: Option<Vec<ActionDependency>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  runtime: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  secrets: Option<Vec<ActionSecret>>,
+}
+
+impl<'a> ActionUpdate<'a> {
+  /// Create update action request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action to update.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      name: None,
+      supported_triggers: None,
+      code: None,
+      dependencies: None,
+      runtime: None,
+      secrets: None,
+    }
+  }
+
+  /// The name of the action.
+  pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
+    self
+  }
+
+  /// Add a trigger that this action supports, e.g. `post-login` version `v3`.  Replaces
+  /// the existing triggers.
+  pub fn trigger(mut self, id: &str, version: &str) -> Self {
+    self
+      .supported_triggers
+      .get_or_insert_with(Vec::new)
+      .push(ActionTrigger {
+        id: id.to_owned(),
+        version: version.to_owned(),
+      });
+    self
+  }
+
+  /// The source code of the action.
+  pub fn code(mut self, code: &str) -> Self {
+    self.code = Some(code.to_owned());
+    self
+  }
+
+  /// Add a third party npm module that this action depends on.  Replaces the existing
+  /// dependencies.
+  pub fn dependency(mut self, name: &str, version: &str) -> Self {
+    self
+      .dependencies
+      .get_or_insert_with(Vec::new)
+      .push(ActionDependency {
+        name: name.to_owned(),
+        version: version.to_owned(),
+      });
+    self
+  }
+
+  /// The Node runtime, e.g. `node18`.
+  pub fn runtime(mut self, runtime: &str) -> Self {
+    self.runtime = Some(runtime.to_owned());
+    self
+  }
+
+  /// Add a secret that is available to the action at runtime.  Replaces the existing
+  /// secrets.
+  pub fn secret(mut self, name: &str, value: &str) -> Self {
+    self
+      .secrets
+      .get_or_insert_with(Vec::new)
+      .push(ActionSecret {
+        name: name.to_owned(),
+        value: Some(value.to_owned()),
+      });
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ActionUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ActionUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!("api/v2/actions/actions/{}", path_segment(&self.id)),
+      )
+      .json(self)
+  }
+}
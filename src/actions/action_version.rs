@@ -0,0 +1,24 @@
+//! Action version
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// A deployed version of an action.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionVersion {
+  /// The unique id of an action version.
+  pub id: String,
+  /// The source code of this specific version of the action.
+  pub code: String,
+  /// The Node runtime, e.g. `node18`.
+  pub runtime: String,
+  /// The build status of this specific version.
+  pub status: String,
+  /// The index of this version in the list of versions for the action.
+  pub number: u32,
+  /// Indicates if this specific version is the currently one deployed.
+  pub deployed: bool,
+  /// The time when this version was deployed.
+  pub deployed_at: Option<DateTime<Utc>>,
+  /// The time when this version was created.
+  pub created_at: DateTime<Utc>,
+}
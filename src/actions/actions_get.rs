@@ -0,0 +1,62 @@
+//! Retrieve actions.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve all actions.
+///
+/// Send as `ResponsePage<Action>`.
+///
+/// # Scopes
+/// * `read:actions`
+#[derive(Serialize)]
+pub struct ActionsGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(rename = "triggerId")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  trigger_id: Option<String>,
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> ActionsGet<'a> {
+  /// Create get actions request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      trigger_id: None,
+      page: Default::default(),
+    }
+  }
+
+  /// Only retrieve actions bound to the given trigger, e.g. `post-login`.
+  pub fn trigger_id(mut self, trigger_id: &str) -> Self {
+    self.trigger_id = Some(trigger_id.to_owned());
+    self
+  }
+}
+
+impl<'a> AsMut<Page> for ActionsGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ActionsGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ActionsGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::GET, "api/v2/actions/actions")
+      .query(&self)
+  }
+}
@@ -0,0 +1,41 @@
+//! Retrieve a specific version of an action.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve a specific version of an action.
+///
+/// # Scopes
+/// * `read:actions`
+pub struct ActionsVersionGet {
+  id: String,
+  version_id: String,
+}
+
+impl ActionsVersionGet {
+  /// Create get action version request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action.
+  /// * `version_id` - The ID of the action version.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(id: S, version_id: T) -> Self {
+    Self {
+      id: id.as_ref().to_string(),
+      version_id: version_id.as_ref().to_string(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for ActionsVersionGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!(
+        "api/v2/actions/actions/{}/versions/{}",
+        path_segment(&self.id),
+        path_segment(&self.version_id)
+      ),
+    )
+  }
+}
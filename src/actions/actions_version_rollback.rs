@@ -0,0 +1,44 @@
+//! Roll back an action to a previous version.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Roll back an action to a previously deployed version.
+///
+/// Deploying an older version creates a new version with the same code, so this is the
+/// quickest way to recover when a newly deployed action breaks authentication.
+///
+/// # Scopes
+/// * `create:actions`
+pub struct ActionsVersionRollback {
+  id: String,
+  version_id: String,
+}
+
+impl ActionsVersionRollback {
+  /// Create action rollback request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action.
+  /// * `version_id` - The ID of the version to roll back to.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(id: S, version_id: T) -> Self {
+    Self {
+      id: id.as_ref().to_string(),
+      version_id: version_id.as_ref().to_string(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for ActionsVersionRollback {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::POST,
+      &format!(
+        "api/v2/actions/actions/{}/versions/{}/deploy",
+        path_segment(&self.id),
+        path_segment(&self.version_id)
+      ),
+    )
+  }
+}
@@ -0,0 +1,60 @@
+//! Retrieve the versions of an action.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve all versions of an action.
+///
+/// # Scopes
+/// * `read:actions`
+#[derive(Serialize)]
+pub struct ActionsVersionsList<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> ActionsVersionsList<'a> {
+  /// Create list action versions request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      page: Default::default(),
+    }
+  }
+}
+
+impl<'a> AsMut<Page> for ActionsVersionsList<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ActionsVersionsList<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ActionsVersionsList<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::GET,
+        &format!("api/v2/actions/actions/{}/versions", path_segment(&self.id)),
+      )
+      .query(&self)
+  }
+}
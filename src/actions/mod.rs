@@ -0,0 +1,187 @@
+#![warn(missing_docs)]
+//! Action request builders.
+
+#[doc(inline)]
+pub use action::*;
+#[doc(inline)]
+pub use action_binding_update::*;
+#[doc(inline)]
+pub use action_bindings_get::*;
+#[doc(inline)]
+pub use action_create::*;
+#[doc(inline)]
+pub use action_delete::*;
+#[doc(inline)]
+pub use action_deploy::*;
+#[doc(inline)]
+pub use action_get::*;
+#[doc(inline)]
+pub use action_update::*;
+#[doc(inline)]
+pub use action_version::*;
+#[doc(inline)]
+pub use actions_get::*;
+#[doc(inline)]
+pub use actions_version_get::*;
+#[doc(inline)]
+pub use actions_version_rollback::*;
+#[doc(inline)]
+pub use actions_versions_list::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result, ResponsePage};
+use std::sync::Arc;
+
+pub mod action;
+pub mod action_binding_update;
+pub mod action_bindings_get;
+pub mod action_create;
+pub mod action_delete;
+pub mod action_deploy;
+pub mod action_get;
+pub mod action_update;
+pub mod action_version;
+pub mod actions_get;
+pub mod actions_version_get;
+pub mod actions_version_rollback;
+pub mod actions_versions_list;
+
+/// Actions manager
+pub struct ActionsManager(Arc<Auth0Client>);
+
+impl ActionsManager {
+  /// Create actions manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create an action.  Once created, the action must be deployed before it can be bound
+  /// to a trigger.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the action.
+  /// # Scopes
+  /// * `create:actions`
+  pub fn create(&self, name: &str) -> ActionCreate<'_> {
+    ActionCreate::new(&self.0, name)
+  }
+
+  /// Delete an action and all of its associated versions.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action to delete.
+  /// # Scopes
+  /// * `delete:actions`
+  pub async fn delete<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    ActionDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Deploy an action, creating a new version from its current contents.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action to deploy.
+  /// # Scopes
+  /// * `create:actions`
+  pub async fn deploy<S: AsRef<str>>(&self, id: S) -> Auth0Result<ActionVersion> {
+    ActionDeploy::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve an action by its ID.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action to retrieve.
+  /// # Scopes
+  /// * `read:actions`
+  pub async fn get<S: AsRef<str>>(&self, id: S) -> Auth0Result<Action> {
+    ActionGet::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve the actions that are bound to a trigger, in the order they are executed.
+  ///
+  /// # Arguments
+  /// * `trigger_id` - An actions extensibility point, e.g. `post-login`.
+  /// # Scopes
+  /// * `read:actions`
+  pub async fn get_bindings<S: AsRef<str>>(
+    &self,
+    trigger_id: S,
+  ) -> Auth0Result<Vec<ActionBinding>> {
+    let page: ResponsePage<ActionBinding> =
+      ActionBindingsGet::new(trigger_id).send_to(&self.0).await?;
+
+    Ok(page.items)
+  }
+
+  /// Retrieve a specific version of an action.  An action version is created whenever an
+  /// action is deployed.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action.
+  /// * `version_id` - The ID of the action version.
+  /// # Scopes
+  /// * `read:actions`
+  pub async fn get_version<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    version_id: T,
+  ) -> Auth0Result<ActionVersion> {
+    ActionsVersionGet::new(id, version_id)
+      .send_to(&self.0)
+      .await
+  }
+
+  /// Retrieve all of an action's versions.  An action version is created whenever an
+  /// action is deployed.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action.
+  /// # Scopes
+  /// * `read:actions`
+  pub fn get_versions<S: AsRef<str>>(&self, id: S) -> ActionsVersionsList<'_> {
+    ActionsVersionsList::new(&self.0, id)
+  }
+
+  /// Roll back an action to a previously deployed version.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action.
+  /// * `version_id` - The ID of the version to roll back to.
+  /// # Scopes
+  /// * `create:actions`
+  pub async fn rollback<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    version_id: T,
+  ) -> Auth0Result<Action> {
+    ActionsVersionRollback::new(id, version_id)
+      .send_to(&self.0)
+      .await
+  }
+
+  /// Retrieve all actions.
+  ///
+  /// # Scopes
+  /// * `read:actions`
+  pub fn list(&self) -> ActionsGet<'_> {
+    ActionsGet::new(&self.0)
+  }
+
+  /// Update the actions that are bound to a trigger.
+  ///
+  /// # Arguments
+  /// * `trigger_id` - An actions extensibility point, e.g. `post-login`.
+  /// # Scopes
+  /// * `update:actions`
+  pub fn update_bindings<S: AsRef<str>>(&self, trigger_id: S) -> ActionBindingUpdate<'_> {
+    ActionBindingUpdate::new(&self.0, trigger_id)
+  }
+
+  /// Update an existing action.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the action to update.
+  /// # Scopes
+  /// * `update:actions`
+  pub fn update<S: AsRef<str>>(&self, id: S) -> ActionUpdate<'_> {
+    ActionUpdate::new(&self.0, id)
+  }
+}
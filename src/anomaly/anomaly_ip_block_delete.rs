@@ -0,0 +1,32 @@
+//! Unblock an IP address.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Remove a block imposed by
+/// [Suspicious IP Throttling](https://auth0.com/docs/attack-protection/suspicious-ip-throttling)
+/// for the given IP address.
+///
+/// # Scopes
+/// * `delete:anomaly_blocks`
+pub struct AnomalyIpBlockDelete(String);
+
+impl AnomalyIpBlockDelete {
+  /// Create delete anomaly IP block request.
+  ///
+  /// # Arguments
+  /// * `ip` - IP address to unblock.
+  pub fn new<S: AsRef<str>>(ip: S) -> Self {
+    Self(ip.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for AnomalyIpBlockDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/anomaly/blocks/ips/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,35 @@
+//! Check if an IP address is blocked.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Check if the given IP address is blocked by the
+/// [Suspicious IP Throttling](https://auth0.com/docs/attack-protection/suspicious-ip-throttling)
+/// due to multiple suspicious attempts.
+///
+/// Auth0 answers with an empty `200 OK` when the address is blocked and `404 Not Found`
+/// when it is not, so send with [Auth0Client::exists] to get a `bool`.
+///
+/// # Scopes
+/// * `read:anomaly_blocks`
+pub struct AnomalyIpBlockGet(String);
+
+impl AnomalyIpBlockGet {
+  /// Create get anomaly IP block request.
+  ///
+  /// # Arguments
+  /// * `ip` - IP address to check.
+  pub fn new<S: AsRef<str>>(ip: S) -> Self {
+    Self(ip.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for AnomalyIpBlockGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/anomaly/blocks/ips/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,47 @@
+#![warn(missing_docs)]
+//! Anomaly detection request builders.
+
+#[doc(inline)]
+pub use anomaly_ip_block_delete::*;
+#[doc(inline)]
+pub use anomaly_ip_block_get::*;
+
+use crate::{Auth0Client, Auth0RequestBuilder, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod anomaly_ip_block_delete;
+pub mod anomaly_ip_block_get;
+
+/// Anomaly manager
+pub struct AnomalyManager(Arc<Auth0Client>);
+
+impl AnomalyManager {
+  /// Create anomaly manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Check if the given IP address is blocked due to multiple suspicious attempts.
+  ///
+  /// # Arguments
+  /// * `ip` - IP address to check.
+  /// # Scopes
+  /// * `read:anomaly_blocks`
+  pub async fn is_ip_blocked<S: AsRef<str>>(&self, ip: S) -> Auth0Result<bool> {
+    self
+      .0
+      .exists(AnomalyIpBlockGet::new(ip).build(&self.0))
+      .await
+  }
+
+  /// Remove a block imposed due to multiple suspicious attempts for the given IP
+  /// address.
+  ///
+  /// # Arguments
+  /// * `ip` - IP address to unblock.
+  /// # Scopes
+  /// * `delete:anomaly_blocks`
+  pub async fn unblock_ip<S: AsRef<str>>(&self, ip: S) -> Auth0Result<()> {
+    AnomalyIpBlockDelete::new(ip).send_to(&self.0).await
+  }
+}
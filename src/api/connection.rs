@@ -1,17 +1,37 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::ConnectionStrategy;
+
 /// Connection
-#[derive(Serialize, Deserialize)]
-pub struct Connection {
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Connection<Options> {
   /// The connection's identifier.
-  id: String,
+  pub id: String,
   /// The name of the connection.
-  name: String,
-  /// The type of the connection, related to the identity provider.
-  strategy: String,
+  pub name: String,
   /// Connection name used in login screen.
-  display_name: String,
+  pub display_name: Option<String>,
+  /// The type of the connection, related to the identity provider.
+  pub strategy: ConnectionStrategy,
+  /// Options which depend on the connection strategy.
+  pub options: Option<Options>,
+  /// The identifiers of the clients for which the connection is enabled.
+  #[serde(default)]
+  pub enabled_clients: Vec<String>,
+  /// Defines the realms for which the connection will be used (ie: email domains).
+  #[serde(default)]
+  pub realms: Vec<String>,
+  /// True if the connection is domain level.
+  #[serde(default)]
+  pub is_domain_connection: bool,
+  /// Metadata associated with the connection.
+  pub metadata: Option<HashMap<String, String>>,
+}
 
-  options: HashMap<String, String>,
+impl<Options> Connection<Options> {
+  /// True if the connection is enabled for the given client.
+  pub fn is_active_for_client(&self, client_id: &str) -> bool {
+    self.enabled_clients.iter().any(|id| id == client_id)
+  }
 }
@@ -1,3 +1,6 @@
+pub use connection::*;
+pub use strategy::*;
+
 #[allow(missing_docs)]
 pub mod connection;
 #[allow(missing_docs)]
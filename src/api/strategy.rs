@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[allow(missing_docs)]
+#[deprecated(note = "use `ConnectionStrategy`, which also keeps unknown strategies")]
 #[derive(Serialize, Deserialize, PartialEq, Debug)]
 pub enum Strategy {
   #[serde(alias = "ad")]
@@ -0,0 +1,54 @@
+//! Breached password detection settings.
+use serde::{Deserialize, Serialize};
+
+/// Action taken when a breached password is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreachedPasswordShield {
+  /// Block the login until the password is changed.
+  Block,
+  /// Email the user.
+  UserNotification,
+  /// Email the tenant administrators.
+  AdminNotification,
+}
+
+/// How often tenant administrators are notified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminNotificationFrequency {
+  /// On every detection.
+  Immediately,
+  /// Daily summary.
+  Daily,
+  /// Weekly summary.
+  Weekly,
+  /// Monthly summary.
+  Monthly,
+}
+
+/// Source of breached credentials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreachedPasswordMethod {
+  /// Credentials from public breaches.
+  Standard,
+  /// Credentials from public breaches and private sources, requires a Credential Guard
+  /// subscription.
+  Enhanced,
+}
+
+/// Breached password detection settings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BreachedPasswordDetection {
+  /// Whether breached password detection is enabled.
+  pub enabled: bool,
+  /// Actions taken when a breached password is used.
+  #[serde(default)]
+  pub shields: Vec<BreachedPasswordShield>,
+  /// How often tenant administrators are notified.
+  #[serde(default)]
+  pub admin_notification_frequency: Vec<AdminNotificationFrequency>,
+  /// Source of breached credentials.
+  pub method: Option<BreachedPasswordMethod>,
+}
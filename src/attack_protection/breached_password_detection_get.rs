@@ -0,0 +1,29 @@
+//! Retrieve breached password detection settings.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the breached password detection settings.
+///
+/// Send as [BreachedPasswordDetection](crate::BreachedPasswordDetection).
+///
+/// # Scopes
+/// * `read:attack_protection`
+#[derive(Default)]
+pub struct BreachedPasswordDetectionGet;
+
+impl BreachedPasswordDetectionGet {
+  /// Create get breached password detection request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for BreachedPasswordDetectionGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      "api/v2/attack-protection/breached-password-detection",
+    )
+  }
+}
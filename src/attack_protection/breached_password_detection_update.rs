@@ -0,0 +1,90 @@
+//! Update breached password detection settings.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{AdminNotificationFrequency, Auth0Client, Auth0RequestBuilder};
+use crate::{BreachedPasswordMethod, BreachedPasswordShield};
+
+/// Update the breached password detection settings.
+///
+/// Send as [BreachedPasswordDetection](crate::BreachedPasswordDetection).
+///
+/// # Scopes
+/// * `update:attack_protection`
+#[derive(Serialize)]
+pub struct BreachedPasswordDetectionUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  shields: Option<Vec<BreachedPasswordShield>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  admin_notification_frequency: Option<Vec<AdminNotificationFrequency>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  method: Option<BreachedPasswordMethod>,
+}
+
+impl<'a> BreachedPasswordDetectionUpdate<'a> {
+  /// Create update breached password detection request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      enabled: None,
+      shields: None,
+      admin_notification_frequency: None,
+      method: None,
+    }
+  }
+
+  /// Whether breached password detection is enabled.
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = Some(enabled);
+    self
+  }
+
+  /// Add an action taken when a breached password is used.  Replaces the existing
+  /// actions.
+  pub fn shield(mut self, shield: BreachedPasswordShield) -> Self {
+    self.shields.get_or_insert_with(Vec::new).push(shield);
+    self
+  }
+
+  /// Add how often tenant administrators are notified.  Replaces the existing
+  /// frequencies.
+  pub fn admin_notification_frequency(
+    mut self,
+    frequency: AdminNotificationFrequency,
+  ) -> Self {
+    self
+      .admin_notification_frequency
+      .get_or_insert_with(Vec::new)
+      .push(frequency);
+    self
+  }
+
+  /// Source of breached credentials.
+  pub fn method(mut self, method: BreachedPasswordMethod) -> Self {
+    self.method = Some(method);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for BreachedPasswordDetectionUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for BreachedPasswordDetectionUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        "api/v2/attack-protection/breached-password-detection",
+      )
+      .json(self)
+  }
+}
@@ -0,0 +1,41 @@
+//! Brute-force protection settings.
+use serde::{Deserialize, Serialize};
+
+/// Action taken when brute-force protection is triggered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BruteForceShield {
+  /// Block logins for the account from the offending IP address.
+  Block,
+  /// Email the user that their account was blocked.
+  UserNotification,
+}
+
+/// How failed login attempts are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BruteForceMode {
+  /// Count attempts per account and IP address.
+  CountPerIdentifierAndIp,
+  /// Count attempts per account, regardless of IP address.
+  CountPerIdentifier,
+}
+
+/// Brute-force protection settings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BruteForceProtection {
+  /// Whether brute-force protection is enabled.
+  pub enabled: bool,
+  /// Actions taken when protection is triggered.
+  #[serde(default)]
+  pub shields: Vec<BruteForceShield>,
+  /// IP addresses and CIDR ranges that are never blocked.
+  #[serde(default)]
+  pub allowlist: Vec<String>,
+  /// How failed login attempts are counted.
+  pub mode: Option<BruteForceMode>,
+  /// Number of failed attempts before protection is triggered.
+  pub max_attempts: Option<u32>,
+  /// Seconds before a blocked account can be retried.
+  pub cooldown_duration: Option<u32>,
+}
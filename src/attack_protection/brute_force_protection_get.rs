@@ -0,0 +1,29 @@
+//! Retrieve brute-force protection settings.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the brute-force protection settings.
+///
+/// Send as [BruteForceProtection](crate::BruteForceProtection).
+///
+/// # Scopes
+/// * `read:attack_protection`
+#[derive(Default)]
+pub struct BruteForceProtectionGet;
+
+impl BruteForceProtectionGet {
+  /// Create get brute-force protection request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for BruteForceProtectionGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      "api/v2/attack-protection/brute-force-protection",
+    )
+  }
+}
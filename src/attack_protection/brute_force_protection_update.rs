@@ -0,0 +1,103 @@
+//! Update brute-force protection settings.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, BruteForceMode, BruteForceShield};
+
+/// Update the brute-force protection settings.
+///
+/// Send as [BruteForceProtection](crate::BruteForceProtection).
+///
+/// # Scopes
+/// * `update:attack_protection`
+#[derive(Serialize)]
+pub struct BruteForceProtectionUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  shields: Option<Vec<BruteForceShield>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  allowlist: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  mode: Option<BruteForceMode>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  max_attempts: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  cooldown_duration: Option<u32>,
+}
+
+impl<'a> BruteForceProtectionUpdate<'a> {
+  /// Create update brute-force protection request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      enabled: None,
+      shields: None,
+      allowlist: None,
+      mode: None,
+      max_attempts: None,
+      cooldown_duration: None,
+    }
+  }
+
+  /// Whether brute-force protection is enabled.
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = Some(enabled);
+    self
+  }
+
+  /// Add an action taken when protection is triggered.  Replaces the existing actions.
+  pub fn shield(mut self, shield: BruteForceShield) -> Self {
+    self.shields.get_or_insert_with(Vec::new).push(shield);
+    self
+  }
+
+  /// Add an IP address or CIDR range that is never blocked.  Replaces the existing
+  /// allowlist.
+  pub fn allow(mut self, address: &str) -> Self {
+    self
+      .allowlist
+      .get_or_insert_with(Vec::new)
+      .push(address.to_owned());
+    self
+  }
+
+  /// How failed login attempts are counted.
+  pub fn mode(mut self, mode: BruteForceMode) -> Self {
+    self.mode = Some(mode);
+    self
+  }
+
+  /// Number of failed attempts before protection is triggered.
+  pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+    self.max_attempts = Some(max_attempts);
+    self
+  }
+
+  /// Seconds before a blocked account can be retried.
+  pub fn cooldown_duration(mut self, cooldown_duration: u32) -> Self {
+    self.cooldown_duration = Some(cooldown_duration);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for BruteForceProtectionUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for BruteForceProtectionUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        "api/v2/attack-protection/brute-force-protection",
+      )
+      .json(self)
+  }
+}
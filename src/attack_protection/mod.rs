@@ -0,0 +1,98 @@
+#![warn(missing_docs)]
+//! Attack protection request builders.
+
+#[doc(inline)]
+pub use breached_password_detection::*;
+#[doc(inline)]
+pub use breached_password_detection_get::*;
+#[doc(inline)]
+pub use breached_password_detection_update::*;
+#[doc(inline)]
+pub use brute_force_protection::*;
+#[doc(inline)]
+pub use brute_force_protection_get::*;
+#[doc(inline)]
+pub use brute_force_protection_update::*;
+#[doc(inline)]
+pub use suspicious_ip_throttling::*;
+#[doc(inline)]
+pub use suspicious_ip_throttling_get::*;
+#[doc(inline)]
+pub use suspicious_ip_throttling_update::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod breached_password_detection;
+pub mod breached_password_detection_get;
+pub mod breached_password_detection_update;
+pub mod brute_force_protection;
+pub mod brute_force_protection_get;
+pub mod brute_force_protection_update;
+pub mod suspicious_ip_throttling;
+pub mod suspicious_ip_throttling_get;
+pub mod suspicious_ip_throttling_update;
+
+/// Attack protection manager
+pub struct AttackProtectionManager(Arc<Auth0Client>);
+
+impl AttackProtectionManager {
+  /// Create attack protection manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve the breached password detection settings.
+  ///
+  /// # Scopes
+  /// * `read:attack_protection`
+  pub async fn get_breached_password_detection(
+    &self,
+  ) -> Auth0Result<BreachedPasswordDetection> {
+    BreachedPasswordDetectionGet::new().send_to(&self.0).await
+  }
+
+  /// Retrieve the brute-force protection settings.
+  ///
+  /// # Scopes
+  /// * `read:attack_protection`
+  pub async fn get_brute_force_protection(&self) -> Auth0Result<BruteForceProtection> {
+    BruteForceProtectionGet::new().send_to(&self.0).await
+  }
+
+  /// Retrieve the suspicious IP throttling settings.
+  ///
+  /// # Scopes
+  /// * `read:attack_protection`
+  pub async fn get_suspicious_ip_throttling(
+    &self,
+  ) -> Auth0Result<SuspiciousIpThrottling> {
+    SuspiciousIpThrottlingGet::new().send_to(&self.0).await
+  }
+
+  /// Update the breached password detection settings.
+  ///
+  /// # Scopes
+  /// * `update:attack_protection`
+  pub fn update_breached_password_detection(
+    &self,
+  ) -> BreachedPasswordDetectionUpdate<'_> {
+    BreachedPasswordDetectionUpdate::new(&self.0)
+  }
+
+  /// Update the brute-force protection settings.
+  ///
+  /// # Scopes
+  /// * `update:attack_protection`
+  pub fn update_brute_force_protection(&self) -> BruteForceProtectionUpdate<'_> {
+    BruteForceProtectionUpdate::new(&self.0)
+  }
+
+  /// Update the suspicious IP throttling settings.
+  ///
+  /// # Scopes
+  /// * `update:attack_protection`
+  pub fn update_suspicious_ip_throttling(&self) -> SuspiciousIpThrottlingUpdate<'_> {
+    SuspiciousIpThrottlingUpdate::new(&self.0)
+  }
+}
@@ -0,0 +1,50 @@
+//! Suspicious IP throttling settings.
+use serde::{Deserialize, Serialize};
+
+/// Action taken when suspicious IP throttling is triggered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuspiciousIpShield {
+  /// Block traffic from the offending IP address.
+  Block,
+  /// Email the tenant administrators.
+  AdminNotification,
+}
+
+/// Throttling limits of a single stage.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SuspiciousIpThrottlingLimits {
+  /// Number of attempts allowed from an IP address before it is throttled.
+  pub max_attempts: u32,
+  /// Interval in milliseconds at which new attempts are granted.
+  pub rate: u32,
+}
+
+/// Throttling limits per stage.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuspiciousIpThrottlingStage {
+  /// Limits of login attempts.
+  #[serde(rename = "pre-login")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pre_login: Option<SuspiciousIpThrottlingLimits>,
+  /// Limits of sign up attempts.
+  #[serde(rename = "pre-user-registration")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pre_user_registration: Option<SuspiciousIpThrottlingLimits>,
+}
+
+/// Suspicious IP throttling settings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SuspiciousIpThrottling {
+  /// Whether suspicious IP throttling is enabled.
+  pub enabled: bool,
+  /// Actions taken when throttling is triggered.
+  #[serde(default)]
+  pub shields: Vec<SuspiciousIpShield>,
+  /// IP addresses and CIDR ranges that are never throttled.
+  #[serde(default)]
+  pub allowlist: Vec<String>,
+  /// Throttling limits per stage.
+  #[serde(default)]
+  pub stage: SuspiciousIpThrottlingStage,
+}
@@ -0,0 +1,29 @@
+//! Retrieve suspicious IP throttling settings.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the suspicious IP throttling settings.
+///
+/// Send as [SuspiciousIpThrottling](crate::SuspiciousIpThrottling).
+///
+/// # Scopes
+/// * `read:attack_protection`
+#[derive(Default)]
+pub struct SuspiciousIpThrottlingGet;
+
+impl SuspiciousIpThrottlingGet {
+  /// Create get suspicious IP throttling request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for SuspiciousIpThrottlingGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      "api/v2/attack-protection/suspicious-ip-throttling",
+    )
+  }
+}
@@ -0,0 +1,96 @@
+//! Update suspicious IP throttling settings.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, SuspiciousIpShield};
+use crate::{SuspiciousIpThrottlingLimits, SuspiciousIpThrottlingStage};
+
+/// Update the suspicious IP throttling settings.
+///
+/// Send as [SuspiciousIpThrottling](crate::SuspiciousIpThrottling).
+///
+/// # Scopes
+/// * `update:attack_protection`
+#[derive(Serialize)]
+pub struct SuspiciousIpThrottlingUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  shields: Option<Vec<SuspiciousIpShield>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  allowlist: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  stage: Option<SuspiciousIpThrottlingStage>,
+}
+
+impl<'a> SuspiciousIpThrottlingUpdate<'a> {
+  /// Create update suspicious IP throttling request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      enabled: None,
+      shields: None,
+      allowlist: None,
+      stage: None,
+    }
+  }
+
+  /// Whether suspicious IP throttling is enabled.
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = Some(enabled);
+    self
+  }
+
+  /// Add an action taken when throttling is triggered.  Replaces the existing actions.
+  pub fn shield(mut self, shield: SuspiciousIpShield) -> Self {
+    self.shields.get_or_insert_with(Vec::new).push(shield);
+    self
+  }
+
+  /// Add an IP address or CIDR range that is never throttled.  Replaces the existing
+  /// allowlist.
+  pub fn allow(mut self, address: &str) -> Self {
+    self
+      .allowlist
+      .get_or_insert_with(Vec::new)
+      .push(address.to_owned());
+    self
+  }
+
+  /// Throttling limits of login attempts.
+  pub fn pre_login(mut self, max_attempts: u32, rate: u32) -> Self {
+    self.stage.get_or_insert_with(Default::default).pre_login =
+      Some(SuspiciousIpThrottlingLimits { max_attempts, rate });
+    self
+  }
+
+  /// Throttling limits of sign up attempts.
+  pub fn pre_user_registration(mut self, max_attempts: u32, rate: u32) -> Self {
+    self
+      .stage
+      .get_or_insert_with(Default::default)
+      .pre_user_registration = Some(SuspiciousIpThrottlingLimits { max_attempts, rate });
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for SuspiciousIpThrottlingUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for SuspiciousIpThrottlingUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        "api/v2/attack-protection/suspicious-ip-throttling",
+      )
+      .json(self)
+  }
+}
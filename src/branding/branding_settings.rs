@@ -0,0 +1,31 @@
+//! Universal Login branding settings.
+use serde::{Deserialize, Serialize};
+
+/// Universal Login colors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrandingColors {
+  /// Accent color as a CSS hex value, e.g. `#0059d6`.
+  pub primary: String,
+  /// Page background color as a CSS hex value, e.g. `#000000`.
+  pub page_background: String,
+}
+
+/// Universal Login font.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrandingFont {
+  /// URL of the custom font.
+  pub url: Option<String>,
+}
+
+/// Universal Login branding settings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BrandingSettings {
+  /// Custom color settings.
+  pub colors: Option<BrandingColors>,
+  /// URL for the favicon.
+  pub favicon_url: Option<String>,
+  /// URL for the logo.
+  pub logo_url: Option<String>,
+  /// Custom font settings.
+  pub font: Option<BrandingFont>,
+}
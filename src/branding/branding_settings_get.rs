@@ -0,0 +1,26 @@
+//! Retrieve branding settings.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the Universal Login branding settings.
+///
+/// Send as [BrandingSettings](crate::BrandingSettings).
+///
+/// # Scopes
+/// * `read:branding`
+#[derive(Default)]
+pub struct BrandingSettingsGet;
+
+impl BrandingSettingsGet {
+  /// Create get branding settings request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for BrandingSettingsGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/branding")
+  }
+}
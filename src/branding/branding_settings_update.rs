@@ -0,0 +1,81 @@
+//! Update branding settings.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, BrandingColors, BrandingFont};
+
+/// Update the Universal Login branding settings.
+///
+/// Send as [BrandingSettings](crate::BrandingSettings).
+///
+/// # Scopes
+/// * `update:branding`
+#[derive(Serialize)]
+pub struct BrandingSettingsUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  colors: Option<BrandingColors>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  favicon_url: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  logo_url: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  font: Option<BrandingFont>,
+}
+
+impl<'a> BrandingSettingsUpdate<'a> {
+  /// Create update branding settings request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      colors: None,
+      favicon_url: None,
+      logo_url: None,
+      font: None,
+    }
+  }
+
+  /// Accent and page background colors as CSS hex values.
+  pub fn colors(mut self, primary: &str, page_background: &str) -> Self {
+    self.colors = Some(BrandingColors {
+      primary: primary.to_owned(),
+      page_background: page_background.to_owned(),
+    });
+    self
+  }
+
+  /// URL for the favicon.
+  pub fn favicon_url(mut self, favicon_url: &str) -> Self {
+    self.favicon_url = Some(favicon_url.to_owned());
+    self
+  }
+
+  /// URL for the logo.
+  pub fn logo_url(mut self, logo_url: &str) -> Self {
+    self.logo_url = Some(logo_url.to_owned());
+    self
+  }
+
+  /// URL of the custom font.
+  pub fn font_url(mut self, font_url: &str) -> Self {
+    self.font = Some(BrandingFont {
+      url: Some(font_url.to_owned()),
+    });
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for BrandingSettingsUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for BrandingSettingsUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::PATCH, "api/v2/branding").json(self)
+  }
+}
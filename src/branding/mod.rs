@@ -0,0 +1,76 @@
+#![warn(missing_docs)]
+//! Branding request builders.
+
+#[doc(inline)]
+pub use branding_settings::*;
+#[doc(inline)]
+pub use branding_settings_get::*;
+#[doc(inline)]
+pub use branding_settings_update::*;
+#[doc(inline)]
+pub use universal_login_template::*;
+#[doc(inline)]
+pub use universal_login_template_get::*;
+#[doc(inline)]
+pub use universal_login_template_update::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod branding_settings;
+pub mod branding_settings_get;
+pub mod branding_settings_update;
+pub mod universal_login_template;
+pub mod universal_login_template_get;
+pub mod universal_login_template_update;
+
+/// Branding manager
+pub struct BrandingManager(Arc<Auth0Client>);
+
+impl BrandingManager {
+  /// Create branding manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve the Universal Login branding settings.
+  ///
+  /// # Scopes
+  /// * `read:branding`
+  pub async fn get(&self) -> Auth0Result<BrandingSettings> {
+    BrandingSettingsGet::new().send_to(&self.0).await
+  }
+
+  /// Update the Universal Login branding settings.
+  ///
+  /// # Scopes
+  /// * `update:branding`
+  pub fn update(&self) -> BrandingSettingsUpdate<'_> {
+    BrandingSettingsUpdate::new(&self.0)
+  }
+
+  /// Retrieve the custom Universal Login page template.
+  ///
+  /// # Scopes
+  /// * `read:branding`
+  pub async fn get_universal_login_template(
+    &self,
+  ) -> Auth0Result<UniversalLoginTemplate> {
+    UniversalLoginTemplateGet::new().send_to(&self.0).await
+  }
+
+  /// Set the custom Universal Login page template.
+  ///
+  /// # Arguments
+  /// * `template` - The HTML of the template.
+  /// # Scopes
+  /// * `update:branding`
+  pub async fn update_universal_login_template<S: AsRef<str>>(
+    &self,
+    template: S,
+  ) -> Auth0Result<()> {
+    UniversalLoginTemplateUpdate::new(template)
+      .send_to(&self.0)
+      .await
+  }
+}
@@ -0,0 +1,9 @@
+//! Universal Login page template.
+use serde::Deserialize;
+
+/// Custom Universal Login page template.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UniversalLoginTemplate {
+  /// The HTML of the template.
+  pub body: String,
+}
@@ -0,0 +1,26 @@
+//! Retrieve the Universal Login page template.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the custom Universal Login page template.
+///
+/// Send as [UniversalLoginTemplate](crate::UniversalLoginTemplate).
+///
+/// # Scopes
+/// * `read:branding`
+#[derive(Default)]
+pub struct UniversalLoginTemplateGet;
+
+impl UniversalLoginTemplateGet {
+  /// Create get universal login template request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for UniversalLoginTemplateGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/branding/templates/universal-login")
+  }
+}
@@ -0,0 +1,32 @@
+//! Set the Universal Login page template.
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Set the custom Universal Login page template.  The template is sent as the raw
+/// request body rather than as JSON.
+///
+/// # Scopes
+/// * `update:branding`
+pub struct UniversalLoginTemplateUpdate(String);
+
+impl UniversalLoginTemplateUpdate {
+  /// Create update universal login template request.
+  ///
+  /// # Arguments
+  /// * `template` - The HTML of the template, must contain `{%- auth0:head -%}` and
+  ///   `{%- auth0:widget -%}`.
+  pub fn new<S: AsRef<str>>(template: S) -> Self {
+    Self(template.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for UniversalLoginTemplateUpdate {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::PUT, "api/v2/branding/templates/universal-login")
+      .header(CONTENT_TYPE, "text/html")
+      .body(self.0.clone())
+  }
+}
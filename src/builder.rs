@@ -2,18 +2,25 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::Client;
+use url::Url;
 
 use crate::rate::RateLimit;
 use crate::token::TokenManager;
 use crate::{Auth0, Auth0Client};
 
 /// Management client interface.
+#[derive(Default)]
 pub struct Auth0Builder {
   domain: Option<String>,
   audience: Option<String>,
   client_id: Option<String>,
   client_secret: Option<String>,
+  token: Option<String>,
+  base_url: Option<String>,
+  default_headers: HeaderMap,
+  rate_limit_retries: usize,
 }
 
 impl Auth0Builder {
@@ -25,27 +32,43 @@ impl Auth0Builder {
   /// Get instance of management client.
   ///
   /// Creates instance of management client and validates builder options.  Valid builder options
-  /// requires all fields to be populated.
+  /// requires the domain and either a [token](Auth0Builder::token) or the audience, client id
+  /// and client secret to be populated.
   pub fn build(self) -> Result<Auth0, Auth0BuilderError> {
     let client = Client::new();
     let domain = self.domain.ok_or(Auth0BuilderError::MissingDomain)?;
-    let audience = self.audience.ok_or(Auth0BuilderError::MissingAudience)?;
-    let client_id = self.client_id.ok_or(Auth0BuilderError::MissingClientID)?;
-    let client_secret = self
-      .client_secret
-      .ok_or(Auth0BuilderError::MissingClientSecret)?;
-    let client = Auth0Client::new(
-      RateLimit::new(),
-      TokenManager::new(
-        client.clone(),
-        &domain,
-        &audience,
-        &client_id,
-        &client_secret,
-      ),
-      client,
-      &domain,
-    );
+    Url::parse(&format!("https://{}/", domain))
+      .map_err(|_| Auth0BuilderError::InvalidDomain)?;
+    let base_url = match self.base_url {
+      Some(base_url) => match Url::parse(&base_url) {
+        Ok(url) if url.scheme() == "http" || url.scheme() == "https" => base_url,
+        _ => return Err(Auth0BuilderError::InvalidBaseUrl),
+      },
+      None => format!("https://{}", domain),
+    };
+    let token = match self.token {
+      Some(token) => TokenManager::from_token(client.clone(), &token),
+      None => {
+        let audience = self.audience.ok_or(Auth0BuilderError::MissingAudience)?;
+        let client_id = self.client_id.ok_or(Auth0BuilderError::MissingClientID)?;
+        let client_secret = self
+          .client_secret
+          .ok_or(Auth0BuilderError::MissingClientSecret)?;
+
+        TokenManager::new(
+          client.clone(),
+          &domain,
+          &audience,
+          &client_id,
+          &client_secret,
+        )
+        .base_url(&base_url)
+      }
+    };
+    let client = Auth0Client::new(RateLimit::new(), token, client, &domain)
+      .base_url(&base_url)
+      .default_headers(self.default_headers)
+      .auto_retry_on_rate_limit(self.rate_limit_retries);
 
     Ok(Auth0::new(client))
   }
@@ -87,16 +110,34 @@ impl Auth0Builder {
     self.client_secret = Some(client_secret.to_owned());
     self
   }
-}
 
-impl Default for Auth0Builder {
-  fn default() -> Self {
-    Self {
-      domain: None,
-      audience: None,
-      client_id: None,
-      client_secret: None,
-    }
+  /// A pre-fetched management API token, used instead of fetching one with the client
+  /// credentials.  The token is never refreshed, so requests fail once it expires.
+  pub fn token(mut self, token: &str) -> Self {
+    self.token = Some(token.to_owned());
+    self
+  }
+
+  /// Send requests to `base_url` instead of `https://{domain}`, e.g. a local mock server
+  /// such as `http://127.0.0.1:8080`.  Tokens are fetched from the same base URL.
+  pub fn base_url(mut self, base_url: &str) -> Self {
+    self.base_url = Some(base_url.to_owned());
+    self
+  }
+
+  /// Add a header sent with every management API request, e.g. an `X-Request-ID`
+  /// correlation ID.  Headers set by a request builder, such as the `Content-Type` of a
+  /// JSON body, take precedence.
+  pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+    self.default_headers.insert(name, value);
+    self
+  }
+
+  /// Retry requests rejected with `429 Too Many Requests` up to `max_attempts` times,
+  /// waiting for the `Retry-After` duration in between.  Disabled by default.
+  pub fn auto_retry_on_rate_limit(mut self, max_attempts: usize) -> Self {
+    self.rate_limit_retries = max_attempts;
+    self
   }
 }
 
@@ -105,6 +146,10 @@ impl Default for Auth0Builder {
 pub enum Auth0BuilderError {
   /// Indicates builder didn't set [Auth0Builder::domain].
   MissingDomain,
+  /// Indicates [Auth0Builder::domain] isn't a valid host name.
+  InvalidDomain,
+  /// Indicates [Auth0Builder::base_url] isn't a valid http or https URL.
+  InvalidBaseUrl,
   /// Indicates builder didn't set [Auth0Builder::audience].
   MissingAudience,
   /// Indicates builder didn't set [Auth0Builder::client_id].
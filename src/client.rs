@@ -1,11 +1,28 @@
 //! Auth0 request client.
-use reqwest::{Client, Method, RequestBuilder};
+use chrono::Utc;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::header::{HeaderMap, LINK};
+use reqwest::{Client, Method, Request, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
+use std::time::Duration;
+use url::Url;
 
-use crate::rate::{RateLimit, RateLimitResponse};
+use crate::rate::{rate_limited, retry_delay, RateLimit};
 use crate::token::TokenManager;
-use crate::Auth0Error;
-use crate::{Auth0ErrorResponse, Auth0Result};
+use crate::{next_link, Auth0ErrorResponse, Auth0Result};
+use crate::{Auth0Error, MultipartAuth0RequestBuilder};
+
+/// Characters left as-is when encoding a path segment.  Matches the RFC 3986 unreserved set.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+  .remove(b'-')
+  .remove(b'.')
+  .remove(b'_')
+  .remove(b'~');
+
+/// Percent encode a value so it can be used as a single segment of a request path.
+pub(crate) fn path_segment(segment: &str) -> String {
+  utf8_percent_encode(segment, PATH_SEGMENT).to_string()
+}
 
 /// Auth0 management client.
 #[derive(Debug)]
@@ -14,6 +31,9 @@ pub struct Auth0Client {
   token: TokenManager,
   client: Client,
   domain: String,
+  base_url: String,
+  default_headers: HeaderMap,
+  rate_limit_retries: usize,
 }
 
 impl Auth0Client {
@@ -24,41 +44,262 @@ impl Auth0Client {
       token,
       client,
       domain: domain.to_owned(),
+      base_url: format!("https://{}", domain),
+      default_headers: HeaderMap::new(),
+      rate_limit_retries: 0,
     }
   }
 
+  /// Send requests to `base_url` instead of `https://{domain}`, e.g. a local mock server.
+  pub fn base_url(mut self, base_url: &str) -> Self {
+    self.base_url = base_url.trim_end_matches('/').to_owned();
+    self
+  }
+
+  /// Add headers sent with every request, e.g. an `X-Request-ID` correlation ID.  They are
+  /// added when the request is sent, and only when the request builder did not set a
+  /// header of the same name.
+  pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+    self.default_headers.extend(headers);
+    self
+  }
+
+  /// Retry requests rejected with `429 Too Many Requests` up to `max_attempts` times,
+  /// waiting for the `Retry-After` duration, or until the limit resets, in between.  Waits
+  /// are capped at 60 seconds.  Disabled by default.
+  pub fn auto_retry_on_rate_limit(mut self, max_attempts: usize) -> Self {
+    self.rate_limit_retries = max_attempts;
+    self
+  }
+
   /// Send request with auth0 client.
   pub async fn send<R>(&self, req: RequestBuilder) -> Auth0Result<R>
   where
     R: DeserializeOwned,
   {
+    let res = self.execute(req).await?;
+
+    self.read(res).await
+  }
+
+  /// Send request with auth0 client, also returning the `rel="next"` URL of the
+  /// response's `Link` header, if any.
+  pub async fn send_paged<R>(
+    &self,
+    req: RequestBuilder,
+  ) -> Auth0Result<(R, Option<String>)>
+  where
+    R: DeserializeOwned,
+  {
+    let res = self.execute(req).await?;
+    let next = res
+      .headers()
+      .get(LINK)
+      .and_then(|link| link.to_str().ok())
+      .and_then(next_link);
+
+    Ok((self.read(res).await?, next))
+  }
+
+  /// Send request with auth0 client and report whether the resource exists.  A
+  /// `404 Not Found` response is `false` rather than an error, any other error status
+  /// is still returned as an error.
+  pub async fn exists(&self, req: RequestBuilder) -> Auth0Result<bool> {
+    let res = self.execute(req).await?;
+
+    if res.status() == StatusCode::NOT_FOUND {
+      return Ok(false);
+    }
+
+    self.read::<serde_json::Value>(res).await.map(|_| true)
+  }
+
+  /// Send an authenticated request, retrying when rate limited if enabled.  A
+  /// `429 Too Many Requests` response that is not retried is returned as
+  /// [Auth0Error::RateLimited].
+  async fn execute(&self, req: RequestBuilder) -> Auth0Result<Response> {
     let token = self.token.get_token().await?;
-    let res = req //
-      .bearer_auth(&token)
-      .send()
-      .await?;
+    let mut req = req.bearer_auth(&token).build()?;
+    self.apply_default_headers(&mut req);
+    let mut attempts = 0;
+
+    loop {
+      let retry = match req.try_clone() {
+        Some(retry) if attempts < self.rate_limit_retries => Some(retry),
+        _ => None,
+      };
+
+      let res = self.dispatch(req).await?;
+      if res.status() != StatusCode::TOO_MANY_REQUESTS {
+        return Ok(res);
+      }
+
+      let err = rate_limited(res.headers());
+      match (retry, &err) {
+        (
+          Some(retry),
+          Auth0Error::RateLimited {
+            retry_after, reset, ..
+          },
+        ) => {
+          tokio::time::sleep(retry_delay(*retry_after, *reset, Utc::now())).await;
+          attempts += 1;
+          req = retry;
+        }
+        _ => return Err(err),
+      }
+    }
+  }
+
+  /// Add the default headers the request builder did not set.
+  fn apply_default_headers(&self, req: &mut Request) {
+    for name in self.default_headers.keys() {
+      if !req.headers().contains_key(name) {
+        for value in self.default_headers.get_all(name) {
+          req.headers_mut().append(name, value.clone());
+        }
+      }
+    }
+  }
+
+  /// Send a single HTTP request.
+  #[cfg(not(feature = "tracing"))]
+  async fn dispatch(&self, req: Request) -> reqwest::Result<Response> {
+    self.client.execute(req).await
+  }
+
+  /// Send a single HTTP request inside an `auth0.request` span recording the method,
+  /// path, status code and duration.  Failures are logged as errors, except
+  /// `404 Not Found` and `429 Too Many Requests` which are expected in normal use (e.g.
+  /// existence checks and retried requests) and are logged at debug level.
+  #[cfg(feature = "tracing")]
+  async fn dispatch(&self, req: Request) -> reqwest::Result<Response> {
+    use tracing::field::Empty;
+    use tracing::Instrument;
+
+    let span = tracing::info_span!(
+      "auth0.request",
+      auth0.method = %req.method(),
+      auth0.path = req.url().path(),
+      auth0.status_code = Empty,
+      auth0.duration_ms = Empty,
+    );
+
+    let start = std::time::Instant::now();
+    let res = self.client.execute(req).instrument(span.clone()).await;
+    span.record("auth0.duration_ms", start.elapsed().as_millis() as u64);
+
+    match &res {
+      Ok(res) => {
+        span.record("auth0.status_code", res.status().as_u16());
+        let status = res.status();
+        if status == StatusCode::NOT_FOUND || status == StatusCode::TOO_MANY_REQUESTS {
+          tracing::debug!(parent: &span, status = status.as_u16(), "Auth0 request failed");
+        } else if !status.is_success() {
+          tracing::error!(parent: &span, status = status.as_u16(), "Auth0 request failed");
+        }
+      }
+      Err(err) => tracing::error!(parent: &span, error = %err, "Auth0 request failed"),
+    }
 
+    res
+  }
+
+  /// Read the body of a response, or the error it describes.
+  async fn read<R>(&self, res: Response) -> Auth0Result<R>
+  where
+    R: DeserializeOwned,
+  {
     if res.status().is_success() {
-      let body = res.rate_limit(&self.rate)?.bytes().await?;
+      // Rate limit headers are best effort, mock servers and proxies may not send them.
+      self.rate.read(&res).ok();
+      let body = res.bytes().await?;
       let body = body.to_vec();
       let body = std::str::from_utf8(&body).unwrap();
       let body = if body.is_empty() { "null" } else { body };
 
       Ok(serde_json::from_str::<R>(body)?)
     } else {
+      let status = res.status().as_u16();
+      let status_err = res.error_for_status_ref().err();
       let body = res.bytes().await?;
-      let body = body.to_vec();
-      let body = std::str::from_utf8(&body).unwrap();
 
-      let err = serde_json::from_str::<Auth0ErrorResponse>(body);
-      if let Ok(err) = err {
-        Err(Auth0Error::from(err))
-      } else {
-        Err(Auth0Error::Auth0(body.to_owned()))
+      match (
+        serde_json::from_slice::<Auth0ErrorResponse>(&body),
+        status_err,
+      ) {
+        (Ok(err), _) => Err(Auth0Error::from(err.with_status(status))),
+        (Err(_), Some(err)) => Err(Auth0Error::Http(err)),
+        (Err(_), None) => Err(Auth0Error::Auth0(
+          String::from_utf8_lossy(&body).into_owned(),
+        )),
+      }
+    }
+  }
+
+  /// Send multipart request with auth0 client.
+  ///
+  /// A multipart body cannot be cloned, so when retrying rate limited requests is enabled
+  /// the form is built again for every attempt.
+  pub async fn send_multipart<R, M>(&self, req: &M) -> Auth0Result<R>
+  where
+    R: DeserializeOwned,
+    M: MultipartAuth0RequestBuilder,
+  {
+    let mut attempts = 0;
+
+    loop {
+      let form = req.build_multipart()?;
+      let res = self
+        .send(self.begin(Method::POST, &req.path()).multipart(form))
+        .await;
+
+      match res {
+        Err(Auth0Error::RateLimited {
+          retry_after, reset, ..
+        }) if attempts < self.rate_limit_retries => {
+          tokio::time::sleep(retry_delay(retry_after, reset, Utc::now())).await;
+          attempts += 1;
+        }
+        res => return res,
       }
     }
   }
 
+  /// Whether the cached management API token has expired, or will expire within the
+  /// next 60 seconds.
+  pub fn is_token_expired(&self) -> bool {
+    self.token.is_expired(Duration::from_secs(60))
+  }
+
+  /// The Auth0 tenant domain.
+  pub fn domain(&self) -> &str {
+    &self.domain
+  }
+
+  /// Create auth0 request builder for a URL returned by the API, e.g. a `Link` header.
+  /// Relative URLs are resolved against the base URL.  Absolute URLs must share the
+  /// scheme, host and port of the base URL so that the access token is never sent to
+  /// another server.
+  pub fn begin_url(&self, method: Method, url: &str) -> Auth0Result<RequestBuilder> {
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+      return Ok(self.begin(method, url.trim_start_matches('/')));
+    }
+
+    let same_origin = match (Url::parse(&self.base_url), Url::parse(url)) {
+      (Ok(base), Ok(target)) => base.origin() == target.origin(),
+      _ => false,
+    };
+    if !same_origin {
+      return Err(Auth0Error::Auth0(format!(
+        "refusing to follow link to another origin: {}",
+        url
+      )));
+    }
+
+    Ok(self.client.request(method, url))
+  }
+
   /// Create auth0 request builder.
   /// # Arguments
   /// * `method` = The HTTP request method.
@@ -66,6 +307,6 @@ impl Auth0Client {
   pub fn begin(&self, method: Method, path: &str) -> RequestBuilder {
     self
       .client
-      .request(method, &format!("https://{}/{}", self.domain, path))
+      .request(method, format!("{}/{}", self.base_url, path))
   }
 }
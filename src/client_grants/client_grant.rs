@@ -0,0 +1,17 @@
+//! Client grant types.
+use serde::Deserialize;
+
+/// Grant allowing a client to request access tokens for an API with the client
+/// credentials flow.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClientGrant {
+  /// ID of the client grant.
+  pub id: String,
+  /// ID of the client the grant was given to.
+  pub client_id: String,
+  /// Audience of the API the grant is for.
+  pub audience: String,
+  /// Scopes included in the grant.
+  #[serde(default)]
+  pub scope: Vec<String>,
+}
@@ -0,0 +1,71 @@
+//! Retrieve client grants.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve client grants, e.g. to check whether a client already has a grant for an API
+/// before creating one.
+///
+/// Send as `Vec<ClientGrant>`.
+///
+/// # Scopes
+/// * `read:client_grants`
+#[derive(Serialize)]
+pub struct ClientGrantsList<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  client_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  audience: Option<String>,
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> ClientGrantsList<'a> {
+  /// Create list client grants request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      client_id: None,
+      audience: None,
+      page: Default::default(),
+    }
+  }
+
+  /// Only retrieve grants given to this client.
+  pub fn client_id(mut self, client_id: &str) -> Self {
+    self.client_id = Some(client_id.to_owned());
+    self
+  }
+
+  /// Only retrieve grants for this API audience.
+  pub fn audience(mut self, audience: &str) -> Self {
+    self.audience = Some(audience.to_owned());
+    self
+  }
+}
+
+impl<'a> AsMut<Page> for ClientGrantsList<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ClientGrantsList<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ClientGrantsList<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::GET, "api/v2/client-grants")
+      .query(&self)
+  }
+}
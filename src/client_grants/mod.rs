@@ -0,0 +1,31 @@
+#![warn(missing_docs)]
+//! Client grant request builders.
+
+#[doc(inline)]
+pub use client_grant::*;
+#[doc(inline)]
+pub use client_grants_list::*;
+
+use crate::Auth0Client;
+use std::sync::Arc;
+
+pub mod client_grant;
+pub mod client_grants_list;
+
+/// Client grants manager
+pub struct ClientGrantsManager(Arc<Auth0Client>);
+
+impl ClientGrantsManager {
+  /// Create client grants manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve client grants.
+  ///
+  /// # Scopes
+  /// * `read:client_grants`
+  pub fn list(&self) -> ClientGrantsList<'_> {
+    ClientGrantsList::new(&self.0)
+  }
+}
@@ -0,0 +1,54 @@
+//! Client
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::GrantType;
+
+/// Client (application).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Client {
+  /// ID of this client.
+  pub client_id: String,
+  /// Name of this client.
+  pub name: String,
+  /// Free text description of this client.
+  pub description: Option<String>,
+  /// Whether this is your global 'All Applications' client representing legacy tenant
+  /// settings (true) or a regular client (false).
+  #[serde(default)]
+  pub global: bool,
+  /// Client secret (which you must not make public).
+  pub client_secret: Option<String>,
+  /// Type of client.  Can be `native`, `spa`, `regular_web` or `non_interactive`.
+  pub app_type: Option<String>,
+  /// URL of the logo to display for this client.
+  pub logo_uri: Option<String>,
+  /// Whether this client a first party client (true) or not (false).
+  #[serde(default)]
+  pub is_first_party: bool,
+  /// Whether this client conforms to strict OIDC specifications (true) or uses legacy
+  /// features (false).
+  #[serde(default)]
+  pub oidc_conformant: bool,
+  /// URLs whitelisted for Auth0 to use as a callback to the client after authentication.
+  #[serde(default)]
+  pub callbacks: Vec<String>,
+  /// URLs allowed to make requests from JavaScript to Auth0 API.
+  #[serde(default)]
+  pub allowed_origins: Vec<String>,
+  /// Allowed origins for use with Cross-Origin Authentication, Device Flow, and web
+  /// message response mode.
+  #[serde(default)]
+  pub web_origins: Vec<String>,
+  /// URLs that are valid to redirect to after logout from Auth0.
+  #[serde(default)]
+  pub allowed_logout_urls: Vec<String>,
+  /// Types of grants that this client is authorized to use.
+  #[serde(default)]
+  pub grant_types: Vec<GrantType>,
+  /// Defines the requested authentication method for the token endpoint.
+  pub token_endpoint_auth_method: Option<String>,
+  /// Metadata associated with the client.
+  #[serde(default)]
+  pub client_metadata: HashMap<String, String>,
+}
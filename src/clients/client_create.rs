@@ -0,0 +1,115 @@
+//! Create a client.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, GrantType};
+
+/// Create a new client (application or SSO integration).
+///
+/// Send as [Client](crate::Client).
+///
+/// # Scopes
+/// * `create:clients`
+#[derive(Serialize)]
+pub struct ClientCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  description: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  app_type: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  logo_uri: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_first_party: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  oidc_conformant: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  grant_types: Option<Vec<GrantType>>,
+}
+
+impl<'a> ClientCreate<'a> {
+  /// Create create client request.
+  ///
+  /// # Arguments
+  /// * `name` - Name of this client.
+  pub fn new(client: &'a Auth0Client, name: &str) -> Self {
+    Self {
+      client,
+
+      name: name.to_owned(),
+      description: None,
+      app_type: None,
+      logo_uri: None,
+      is_first_party: None,
+      oidc_conformant: None,
+      grant_types: None,
+    }
+  }
+
+  /// Free text description of this client.
+  pub fn description(mut self, description: &str) -> Self {
+    self.description = Some(description.to_owned());
+    self
+  }
+
+  /// Type of client.  Can be `native`, `spa`, `regular_web` or `non_interactive`.
+  pub fn app_type(mut self, app_type: &str) -> Self {
+    self.app_type = Some(app_type.to_owned());
+    self
+  }
+
+  /// URL of the logo to display for this client.
+  pub fn logo_uri(mut self, logo_uri: &str) -> Self {
+    self.logo_uri = Some(logo_uri.to_owned());
+    self
+  }
+
+  /// Whether this client a first party client (true) or not (false).
+  pub fn is_first_party(mut self, is_first_party: bool) -> Self {
+    self.is_first_party = Some(is_first_party);
+    self
+  }
+
+  /// Whether this client conforms to strict OIDC specifications (true) or uses legacy
+  /// features (false).
+  ///
+  /// Enabling this on an existing application is a breaking change:
+  ///
+  /// * ID tokens only contain the claims of the standard OIDC scopes requested (`openid`,
+  ///   `profile`, `email`, ...).  Arbitrary profile attributes are no longer added, so
+  ///   custom claims must be namespaced and added by a rule or action.
+  /// * `/userinfo` returns the same standard claims as the ID token instead of the full
+  ///   user profile.
+  /// * `scope` is only used to request OIDC standard claims and API permissions; legacy
+  ///   scopes that requested profile attributes are ignored.
+  /// * Legacy flows are disabled: the implicit grant no longer returns access tokens for
+  ///   the Management API, `/oauth/ro`, `/delegation` and password grants without an
+  ///   audience stop working.
+  ///
+  /// See [OIDC-conformant authentication](https://auth0.com/docs/authenticate/login/oidc-conformant-authentication).
+  pub fn oidc_conformant(mut self, oidc_conformant: bool) -> Self {
+    self.oidc_conformant = Some(oidc_conformant);
+    self
+  }
+
+  /// Types of grants that this client is authorized to use.
+  pub fn grant_types(mut self, grant_types: Vec<GrantType>) -> Self {
+    self.grant_types = Some(grant_types);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ClientCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ClientCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::POST, "api/v2/clients").json(self)
+  }
+}
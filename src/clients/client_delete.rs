@@ -0,0 +1,30 @@
+//! Delete a client.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete a client by its ID.
+///
+/// # Scopes
+/// * `delete:clients`
+pub struct ClientDelete(String);
+
+impl ClientDelete {
+  /// Create delete client request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the client to delete.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for ClientDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/clients/{}", path_segment(&self.0)),
+    )
+  }
+}
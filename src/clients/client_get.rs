@@ -0,0 +1,45 @@
+//! Retrieve a client.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, Fields};
+
+/// Retrieve a client by its ID.
+///
+/// # Scopes
+/// * `read:clients`
+/// * `read:client_keys`
+pub struct ClientGet {
+  id: String,
+  fields: Fields,
+}
+
+impl ClientGet {
+  /// Create get client request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the client to retrieve.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self {
+      id: id.as_ref().to_string(),
+      fields: Default::default(),
+    }
+  }
+}
+
+impl AsMut<Fields> for ClientGet {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
+impl Auth0RequestBuilder for ClientGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::GET,
+        &format!("api/v2/clients/{}", path_segment(&self.id)),
+      )
+      .query(&self.fields)
+  }
+}
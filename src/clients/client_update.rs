@@ -0,0 +1,168 @@
+//! Update a client.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, GrantType};
+
+/// Update a client.  Fields that are not set are left unchanged.
+///
+/// Send as [Client](crate::Client).
+///
+/// # Scopes
+/// * `update:clients`
+#[derive(Serialize)]
+pub struct ClientUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  description: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  app_type: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  logo_uri: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_first_party: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  oidc_conformant: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  grant_types: Option<Vec<GrantType>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  callbacks: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  allowed_origins: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  web_origins: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  allowed_logout_urls: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  allowed_clients: Option<Vec<String>>,
+}
+
+impl<'a> ClientUpdate<'a> {
+  /// Create update client request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the client to update.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      name: None,
+      description: None,
+      app_type: None,
+      logo_uri: None,
+      is_first_party: None,
+      oidc_conformant: None,
+      grant_types: None,
+      callbacks: None,
+      allowed_origins: None,
+      web_origins: None,
+      allowed_logout_urls: None,
+      allowed_clients: None,
+    }
+  }
+
+  /// Name of this client.
+  pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
+    self
+  }
+
+  /// Free text description of this client.
+  pub fn description(mut self, description: &str) -> Self {
+    self.description = Some(description.to_owned());
+    self
+  }
+
+  /// Type of client.  Can be `native`, `spa`, `regular_web` or `non_interactive`.
+  pub fn app_type(mut self, app_type: &str) -> Self {
+    self.app_type = Some(app_type.to_owned());
+    self
+  }
+
+  /// URL of the logo to display for this client.
+  pub fn logo_uri(mut self, logo_uri: &str) -> Self {
+    self.logo_uri = Some(logo_uri.to_owned());
+    self
+  }
+
+  /// Whether this client a first party client (true) or not (false).
+  pub fn is_first_party(mut self, is_first_party: bool) -> Self {
+    self.is_first_party = Some(is_first_party);
+    self
+  }
+
+  /// Whether this client conforms to strict OIDC specifications (true) or uses legacy
+  /// features (false).  Enabling this on an existing application is a breaking change,
+  /// see [ClientCreate::oidc_conformant](crate::ClientCreate::oidc_conformant).
+  pub fn oidc_conformant(mut self, oidc_conformant: bool) -> Self {
+    self.oidc_conformant = Some(oidc_conformant);
+    self
+  }
+
+  /// Types of grants that this client is authorized to use.  Replaces the existing grant
+  /// types.
+  pub fn grant_types(mut self, grant_types: Vec<GrantType>) -> Self {
+    self.grant_types = Some(grant_types);
+    self
+  }
+
+  /// URLs whitelisted for Auth0 to use as a callback to the client after authentication.
+  /// Replaces the existing URLs.
+  pub fn callbacks(mut self, urls: Vec<String>) -> Self {
+    self.callbacks = Some(urls);
+    self
+  }
+
+  /// URLs allowed to make requests from JavaScript to Auth0 API.  Replaces the existing
+  /// URLs.
+  pub fn allowed_origins(mut self, urls: Vec<String>) -> Self {
+    self.allowed_origins = Some(urls);
+    self
+  }
+
+  /// Allowed origins for use with Cross-Origin Authentication, Device Flow, and web
+  /// message response mode.  Replaces the existing origins.
+  pub fn web_origins(mut self, urls: Vec<String>) -> Self {
+    self.web_origins = Some(urls);
+    self
+  }
+
+  /// URLs that are valid to redirect to after logout from Auth0.  Replaces the existing
+  /// URLs.
+  pub fn allowed_logout_urls(mut self, urls: Vec<String>) -> Self {
+    self.allowed_logout_urls = Some(urls);
+    self
+  }
+
+  /// IDs of the clients that are allowed to make delegation requests on behalf of this
+  /// client.  Replaces the existing IDs.
+  pub fn allowed_clients(mut self, ids: Vec<String>) -> Self {
+    self.allowed_clients = Some(ids);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ClientUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ClientUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!("api/v2/clients/{}", path_segment(&self.id)),
+      )
+      .json(self)
+  }
+}
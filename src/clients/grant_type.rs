@@ -0,0 +1,80 @@
+//! Client grant type.
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Grant type that a client is authorized to use.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum GrantType {
+  /// `authorization_code`
+  AuthorizationCode,
+  /// `implicit`
+  Implicit,
+  /// `refresh_token`
+  RefreshToken,
+  /// `client_credentials`
+  ClientCredentials,
+  /// `password`
+  Password,
+  /// `http://auth0.com/oauth/grant-type/mfa-oob`
+  MfaOob,
+  /// `http://auth0.com/oauth/grant-type/mfa-otp`
+  MfaOtp,
+  /// `http://auth0.com/oauth/grant-type/mfa-recovery-code`
+  MfaRecoveryCode,
+  /// `urn:ietf:params:oauth:grant-type:device_code`
+  DeviceCode,
+  /// `urn:openid:params:grant-type:ciba`
+  Ciba,
+  /// Any other grant type.
+  Unknown(String),
+}
+
+impl GrantType {
+  /// The grant type as sent to Auth0.
+  pub fn as_str(&self) -> &str {
+    match self {
+      GrantType::AuthorizationCode => "authorization_code",
+      GrantType::Implicit => "implicit",
+      GrantType::RefreshToken => "refresh_token",
+      GrantType::ClientCredentials => "client_credentials",
+      GrantType::Password => "password",
+      GrantType::MfaOob => "http://auth0.com/oauth/grant-type/mfa-oob",
+      GrantType::MfaOtp => "http://auth0.com/oauth/grant-type/mfa-otp",
+      GrantType::MfaRecoveryCode => "http://auth0.com/oauth/grant-type/mfa-recovery-code",
+      GrantType::DeviceCode => "urn:ietf:params:oauth:grant-type:device_code",
+      GrantType::Ciba => "urn:openid:params:grant-type:ciba",
+      GrantType::Unknown(grant_type) => grant_type,
+    }
+  }
+}
+
+impl From<String> for GrantType {
+  fn from(grant_type: String) -> Self {
+    match grant_type.as_str() {
+      "authorization_code" => GrantType::AuthorizationCode,
+      "implicit" => GrantType::Implicit,
+      "refresh_token" => GrantType::RefreshToken,
+      "client_credentials" => GrantType::ClientCredentials,
+      "password" => GrantType::Password,
+      "http://auth0.com/oauth/grant-type/mfa-oob" => GrantType::MfaOob,
+      "http://auth0.com/oauth/grant-type/mfa-otp" => GrantType::MfaOtp,
+      "http://auth0.com/oauth/grant-type/mfa-recovery-code" => GrantType::MfaRecoveryCode,
+      "urn:ietf:params:oauth:grant-type:device_code" => GrantType::DeviceCode,
+      "urn:openid:params:grant-type:ciba" => GrantType::Ciba,
+      _ => GrantType::Unknown(grant_type),
+    }
+  }
+}
+
+impl From<GrantType> for String {
+  fn from(grant_type: GrantType) -> Self {
+    grant_type.as_str().to_owned()
+  }
+}
+
+impl Display for GrantType {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
@@ -0,0 +1,76 @@
+#![warn(missing_docs)]
+//! Client request builders.
+
+#[doc(inline)]
+pub use client::*;
+#[doc(inline)]
+pub use client_create::*;
+#[doc(inline)]
+pub use client_delete::*;
+#[doc(inline)]
+pub use client_get::*;
+#[doc(inline)]
+pub use client_update::*;
+#[doc(inline)]
+pub use grant_type::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod client;
+pub mod client_create;
+pub mod client_delete;
+pub mod client_get;
+pub mod client_update;
+pub mod grant_type;
+
+/// Clients manager
+pub struct ClientsManager(Arc<Auth0Client>);
+
+impl ClientsManager {
+  /// Create clients manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a new client (application or SSO integration).
+  ///
+  /// # Arguments
+  /// * `name` - Name of this client.
+  /// # Scopes
+  /// * `create:clients`
+  pub fn create(&self, name: &str) -> ClientCreate<'_> {
+    ClientCreate::new(&self.0, name)
+  }
+
+  /// Delete a client and related configuration (rules, connections, etc).
+  ///
+  /// # Arguments
+  /// * `id` - ID of the client to delete.
+  /// # Scopes
+  /// * `delete:clients`
+  pub async fn delete<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    ClientDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve a client by its ID.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the client to retrieve.
+  /// # Scopes
+  /// * `read:clients`
+  /// * `read:client_keys`
+  pub async fn get<S: AsRef<str>>(&self, id: S) -> Auth0Result<Client> {
+    ClientGet::new(id).send_to(&self.0).await
+  }
+
+  /// Update a client.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the client to update.
+  /// # Scopes
+  /// * `update:clients`
+  pub fn update<S: AsRef<str>>(&self, id: S) -> ClientUpdate<'_> {
+    ClientUpdate::new(&self.0, id)
+  }
+}
@@ -0,0 +1,238 @@
+//! Create a connection.
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::{
+  AdfsConnectionOptions, DatabaseConnectionOptions, GoogleOAuth2ConnectionOptions,
+};
+use crate::{Auth0Client, Auth0Result, Connection, ConnectionStrategy};
+use crate::{OidcConnectionOptions, SamlpConnectionOptions, WaadConnectionOptions};
+
+/// Create a new connection according to the JSON object received in body.
+///
+/// # Scopes
+/// * `create:connections`
+#[derive(Serialize, Clone, Debug)]
+pub struct ConnectionCreate<'a, O> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  name: String,
+  strategy: ConnectionStrategy,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  display_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled_clients: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  realms: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_domain_connection: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  metadata: Option<HashMap<String, String>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  options: Option<O>,
+}
+
+/// Create an ADFS connection, see [ConnectionCreate::adfs].
+pub type AdfsConnectionCreate<'a> = ConnectionCreate<'a, AdfsConnectionOptions>;
+
+/// Create a database connection, see [ConnectionCreate::database].
+pub type DatabaseConnectionCreate<'a> = ConnectionCreate<'a, DatabaseConnectionOptions>;
+
+/// Create a Google OAuth2 connection, see [ConnectionCreate::google_oauth2].
+pub type GoogleOAuth2ConnectionCreate<'a> =
+  ConnectionCreate<'a, GoogleOAuth2ConnectionOptions>;
+
+/// Create an OpenID Connect connection, see [ConnectionCreate::oidc].
+pub type OidcConnectionCreate<'a> = ConnectionCreate<'a, OidcConnectionOptions>;
+
+/// Create a SAML connection, see [ConnectionCreate::samlp].
+pub type SamlpConnectionCreate<'a> = ConnectionCreate<'a, SamlpConnectionOptions>;
+
+/// Create an Azure AD connection, see [ConnectionCreate::waad].
+pub type WaadConnectionCreate<'a> = ConnectionCreate<'a, WaadConnectionOptions>;
+
+impl<'a> ConnectionCreate<'a, ()> {
+  /// Create create connection request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.  Must start and end with an alphanumeric
+  ///   character and can only contain alphanumeric characters and `-`.
+  /// * `strategy` - The identity provider of the connection, e.g.
+  ///   [ConnectionStrategy::Auth0].
+  pub fn new(client: &'a Auth0Client, name: &str, strategy: ConnectionStrategy) -> Self {
+    Self {
+      client,
+
+      name: name.to_owned(),
+      strategy,
+      display_name: None,
+      enabled_clients: None,
+      realms: None,
+      is_domain_connection: None,
+      metadata: None,
+
+      options: None,
+    }
+  }
+}
+
+impl<'a> AdfsConnectionCreate<'a> {
+  /// Create create ADFS connection request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The ADFS server configuration.
+  pub fn adfs(
+    client: &'a Auth0Client,
+    name: &str,
+    options: AdfsConnectionOptions,
+  ) -> Self {
+    ConnectionCreate::new(client, name, ConnectionStrategy::Adfs).options(options)
+  }
+}
+
+impl<'a> DatabaseConnectionCreate<'a> {
+  /// Create create database connection request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The database configuration.
+  pub fn database(
+    client: &'a Auth0Client,
+    name: &str,
+    options: DatabaseConnectionOptions,
+  ) -> Self {
+    ConnectionCreate::new(client, name, ConnectionStrategy::Auth0).options(options)
+  }
+}
+
+impl<'a> GoogleOAuth2ConnectionCreate<'a> {
+  /// Create create Google OAuth2 connection request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The Google OAuth client configuration.
+  pub fn google_oauth2(
+    client: &'a Auth0Client,
+    name: &str,
+    options: GoogleOAuth2ConnectionOptions,
+  ) -> Self {
+    ConnectionCreate::new(client, name, ConnectionStrategy::GoogleOAuth2).options(options)
+  }
+}
+
+impl<'a> OidcConnectionCreate<'a> {
+  /// Create create OpenID Connect connection request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The OpenID Connect provider configuration.
+  pub fn oidc(
+    client: &'a Auth0Client,
+    name: &str,
+    options: OidcConnectionOptions,
+  ) -> Self {
+    ConnectionCreate::new(client, name, ConnectionStrategy::Oidc).options(options)
+  }
+}
+
+impl<'a> SamlpConnectionCreate<'a> {
+  /// Create create SAML connection request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The SAML identity provider configuration.
+  pub fn samlp(
+    client: &'a Auth0Client,
+    name: &str,
+    options: SamlpConnectionOptions,
+  ) -> Self {
+    ConnectionCreate::new(client, name, ConnectionStrategy::Samlp).options(options)
+  }
+}
+
+impl<'a> WaadConnectionCreate<'a> {
+  /// Create create Azure AD connection request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The Azure AD application configuration.
+  pub fn waad(
+    client: &'a Auth0Client,
+    name: &str,
+    options: WaadConnectionOptions,
+  ) -> Self {
+    ConnectionCreate::new(client, name, ConnectionStrategy::Waad).options(options)
+  }
+}
+
+impl<'a, O> ConnectionCreate<'a, O> {
+  /// Connection name used in the new universal login experience.
+  pub fn display_name(mut self, display_name: &str) -> Self {
+    self.display_name = Some(display_name.to_owned());
+    self
+  }
+
+  /// The identifiers of the clients for which the connection is to be enabled.
+  pub fn enabled_clients(mut self, enabled_clients: &[&str]) -> Self {
+    self.enabled_clients = Some(enabled_clients.iter().map(|c| c.to_string()).collect());
+    self
+  }
+
+  /// Defines the realms for which the connection will be used (ie: email domains).
+  pub fn realms(mut self, realms: &[&str]) -> Self {
+    self.realms = Some(realms.iter().map(|r| r.to_string()).collect());
+    self
+  }
+
+  /// True promotes to a domain-level connection so that third-party applications can use
+  /// it.
+  pub fn is_domain_connection(mut self, is_domain_connection: bool) -> Self {
+    self.is_domain_connection = Some(is_domain_connection);
+    self
+  }
+
+  /// Metadata associated with the connection.
+  pub fn metadata(mut self, metadata: HashMap<String, String>) -> Self {
+    self.metadata = Some(metadata);
+    self
+  }
+
+  /// Options which depend on the connection strategy.
+  pub fn options<Options>(self, options: Options) -> ConnectionCreate<'a, Options> {
+    ConnectionCreate {
+      client: self.client,
+      name: self.name,
+      strategy: self.strategy,
+      display_name: self.display_name,
+      enabled_clients: self.enabled_clients,
+      realms: self.realms,
+      is_domain_connection: self.is_domain_connection,
+      metadata: self.metadata,
+      options: Some(options),
+    }
+  }
+}
+
+impl<'a, OIn> ConnectionCreate<'a, OIn> {
+  /// Send
+  pub async fn send<OOut>(&self) -> Auth0Result<Connection<OOut>>
+  where
+    OIn: Serialize,
+    OOut: DeserializeOwned,
+  {
+    self
+      .client
+      .send(
+        self
+          .client
+          .begin(Method::POST, "api/v2/connections")
+          .json(self),
+      )
+      .await
+  }
+}
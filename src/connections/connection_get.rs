@@ -0,0 +1,44 @@
+//! Retrieve a connection.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, Fields};
+
+/// Retrieve a connection by its ID.
+///
+/// # Scopes
+/// * `read:connections`
+pub struct ConnectionGet {
+  id: String,
+  fields: Fields,
+}
+
+impl ConnectionGet {
+  /// Create get connection request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection to retrieve.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self {
+      id: id.as_ref().to_string(),
+      fields: Default::default(),
+    }
+  }
+}
+
+impl AsMut<Fields> for ConnectionGet {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
+impl Auth0RequestBuilder for ConnectionGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::GET,
+        &format!("api/v2/connections/{}", path_segment(&self.id)),
+      )
+      .query(&self.fields)
+  }
+}
@@ -0,0 +1,120 @@
+//! Connection strategy.
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Identity provider strategy of a connection, e.g. `auth0` or `google-oauth2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ConnectionStrategy {
+  /// Active Directory / LDAP (`ad`).
+  Ad,
+  /// Active Directory Federation Services (`adfs`).
+  Adfs,
+  /// Sign in with Apple (`apple`).
+  Apple,
+  /// Auth0 database (`auth0`).
+  Auth0,
+  /// Passwordless email (`email`).
+  Email,
+  /// Facebook (`facebook`).
+  Facebook,
+  /// GitHub (`github`).
+  Github,
+  /// Google Workspace (`google-apps`).
+  GoogleApps,
+  /// Google (`google-oauth2`).
+  GoogleOAuth2,
+  /// LinkedIn (`linkedin`).
+  LinkedIn,
+  /// Custom OAuth2 provider (`oauth2`).
+  Oauth2,
+  /// Office 365 (`office365`).
+  Office365,
+  /// OpenID Connect (`oidc`).
+  Oidc,
+  /// Okta Workforce (`okta`).
+  Okta,
+  /// PingFederate (`pingfederate`).
+  PingFederate,
+  /// SAML (`samlp`).
+  Samlp,
+  /// Passwordless SMS (`sms`).
+  Sms,
+  /// Twitter (`twitter`).
+  Twitter,
+  /// Azure AD (`waad`).
+  Waad,
+  /// Microsoft account (`windowslive`).
+  WindowsLive,
+  /// Any other strategy.
+  Unknown(String),
+}
+
+impl ConnectionStrategy {
+  /// The strategy as used by the Auth0 API.
+  pub fn as_str(&self) -> &str {
+    match self {
+      ConnectionStrategy::Ad => "ad",
+      ConnectionStrategy::Adfs => "adfs",
+      ConnectionStrategy::Apple => "apple",
+      ConnectionStrategy::Auth0 => "auth0",
+      ConnectionStrategy::Email => "email",
+      ConnectionStrategy::Facebook => "facebook",
+      ConnectionStrategy::Github => "github",
+      ConnectionStrategy::GoogleApps => "google-apps",
+      ConnectionStrategy::GoogleOAuth2 => "google-oauth2",
+      ConnectionStrategy::LinkedIn => "linkedin",
+      ConnectionStrategy::Oauth2 => "oauth2",
+      ConnectionStrategy::Office365 => "office365",
+      ConnectionStrategy::Oidc => "oidc",
+      ConnectionStrategy::Okta => "okta",
+      ConnectionStrategy::PingFederate => "pingfederate",
+      ConnectionStrategy::Samlp => "samlp",
+      ConnectionStrategy::Sms => "sms",
+      ConnectionStrategy::Twitter => "twitter",
+      ConnectionStrategy::Waad => "waad",
+      ConnectionStrategy::WindowsLive => "windowslive",
+      ConnectionStrategy::Unknown(strategy) => strategy,
+    }
+  }
+}
+
+impl From<String> for ConnectionStrategy {
+  fn from(strategy: String) -> Self {
+    match strategy.as_str() {
+      "ad" => ConnectionStrategy::Ad,
+      "adfs" => ConnectionStrategy::Adfs,
+      "apple" => ConnectionStrategy::Apple,
+      "auth0" => ConnectionStrategy::Auth0,
+      "email" => ConnectionStrategy::Email,
+      "facebook" => ConnectionStrategy::Facebook,
+      "github" => ConnectionStrategy::Github,
+      "google-apps" => ConnectionStrategy::GoogleApps,
+      "google-oauth2" => ConnectionStrategy::GoogleOAuth2,
+      "linkedin" => ConnectionStrategy::LinkedIn,
+      "oauth2" => ConnectionStrategy::Oauth2,
+      "office365" => ConnectionStrategy::Office365,
+      "oidc" => ConnectionStrategy::Oidc,
+      "okta" => ConnectionStrategy::Okta,
+      "pingfederate" => ConnectionStrategy::PingFederate,
+      "samlp" => ConnectionStrategy::Samlp,
+      "sms" => ConnectionStrategy::Sms,
+      "twitter" => ConnectionStrategy::Twitter,
+      "waad" => ConnectionStrategy::Waad,
+      "windowslive" => ConnectionStrategy::WindowsLive,
+      _ => ConnectionStrategy::Unknown(strategy),
+    }
+  }
+}
+
+impl From<ConnectionStrategy> for String {
+  fn from(strategy: ConnectionStrategy) -> Self {
+    strategy.as_str().to_owned()
+  }
+}
+
+impl Display for ConnectionStrategy {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
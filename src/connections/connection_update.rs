@@ -0,0 +1,155 @@
+//! Update a connection.
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result, Connection, ConnectionGet};
+
+/// Update a connection.
+///
+/// **Note:** if you use the `options` parameter, the whole options object will be
+/// overridden, so ensure that all parameters are present.
+///
+/// # Scopes
+/// * `update:connections`
+#[derive(Serialize, Clone, Debug)]
+pub struct ConnectionUpdate<'a, O> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  display_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled_clients: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  realms: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_domain_connection: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  metadata: Option<HashMap<String, Option<String>>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  options: Option<O>,
+}
+
+impl<'a> ConnectionUpdate<'a, ()> {
+  /// Create update connection request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection to update.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      display_name: None,
+      enabled_clients: None,
+      realms: None,
+      is_domain_connection: None,
+      metadata: None,
+
+      options: None,
+    }
+  }
+}
+
+impl<'a> ConnectionUpdate<'a, Value> {
+  /// Fetch a connection and write its current options back unchanged.
+  ///
+  /// Nothing is modified, but the request fails unless the credentials are allowed to
+  /// update the connection, which makes it a cheap write-access check.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection to update.
+  pub async fn no_op<S: AsRef<str>>(
+    client: &'a Auth0Client,
+    id: S,
+  ) -> Auth0Result<Connection<Value>> {
+    let connection: Connection<Value> = ConnectionGet::new(&id).send_to(client).await?;
+
+    match connection.options {
+      Some(options) => {
+        ConnectionUpdate::new(client, id)
+          .options(options)
+          .send()
+          .await
+      }
+      None => ConnectionUpdate::new(client, id).send().await,
+    }
+  }
+}
+
+impl<'a, O> ConnectionUpdate<'a, O> {
+  /// Connection name used in the new universal login experience.
+  pub fn display_name(mut self, display_name: &str) -> Self {
+    self.display_name = Some(display_name.to_owned());
+    self
+  }
+
+  /// The identifiers of the clients for which the connection is to be enabled.
+  pub fn enabled_clients(mut self, enabled_clients: &[&str]) -> Self {
+    self.enabled_clients = Some(enabled_clients.iter().map(|c| c.to_string()).collect());
+    self
+  }
+
+  /// Defines the realms for which the connection will be used (ie: email domains).
+  pub fn realms(mut self, realms: &[&str]) -> Self {
+    self.realms = Some(realms.iter().map(|r| r.to_string()).collect());
+    self
+  }
+
+  /// True promotes to a domain-level connection so that third-party applications can use
+  /// it.
+  pub fn is_domain_connection(mut self, is_domain_connection: bool) -> Self {
+    self.is_domain_connection = Some(is_domain_connection);
+    self
+  }
+
+  /// Metadata associated with the connection.  Keys set to `None` are sent as `null`,
+  /// which removes them from the connection.
+  pub fn metadata(mut self, metadata: HashMap<String, Option<String>>) -> Self {
+    self.metadata = Some(metadata);
+    self
+  }
+
+  /// Options which depend on the connection strategy.  Replaces all existing options.
+  pub fn options<Options>(self, options: Options) -> ConnectionUpdate<'a, Options> {
+    ConnectionUpdate {
+      client: self.client,
+      id: self.id,
+      display_name: self.display_name,
+      enabled_clients: self.enabled_clients,
+      realms: self.realms,
+      is_domain_connection: self.is_domain_connection,
+      metadata: self.metadata,
+      options: Some(options),
+    }
+  }
+}
+
+impl<'a, OIn> ConnectionUpdate<'a, OIn> {
+  /// Send
+  pub async fn send<OOut>(&self) -> Auth0Result<Connection<OOut>>
+  where
+    OIn: Serialize,
+    OOut: DeserializeOwned,
+  {
+    self
+      .client
+      .send(
+        self
+          .client
+          .begin(
+            Method::PATCH,
+            &format!("api/v2/connections/{}", path_segment(&self.id)),
+          )
+          .json(self),
+      )
+      .await
+  }
+}
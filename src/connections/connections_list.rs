@@ -0,0 +1,119 @@
+//! Retrieve connections.
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::{Method, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::connections::ConnectionGet;
+use crate::{Auth0Client, Auth0RequestBuilder, Auth0RequestSimple, Auth0Result};
+use crate::{Connection, Fields, Page};
+
+/// Maximum number of connections requested at once by [ConnectionsList::ids].
+const IDS_CONCURRENCY: usize = 4;
+
+/// Retrieve connections.
+///
+/// # Scopes
+/// * `read:connections`
+#[derive(Serialize)]
+pub struct ConnectionsList<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(flatten)]
+  page: Page,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  strategy: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(flatten)]
+  fields: Fields,
+}
+
+impl<'a> ConnectionsList<'a> {
+  /// Create list connections request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      page: Default::default(),
+      strategy: None,
+      name: None,
+      fields: Default::default(),
+    }
+  }
+
+  /// Only retrieve connections of this strategy type (e.g. `auth0` or `google-oauth2`).
+  pub fn strategy(mut self, strategy: &str) -> Self {
+    self.strategy = Some(strategy.to_owned());
+    self
+  }
+
+  /// Only retrieve the connection with this name.
+  pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
+    self
+  }
+
+  /// Retrieve the connections with the given IDs.
+  ///
+  /// Auth0 has no endpoint to retrieve multiple connections by ID, so this sends one
+  /// request per ID, at most 4 at a time.  Each request counts towards the management API
+  /// rate limit.  Connections are returned in the order of `ids`.  Fails with the first
+  /// error encountered.
+  ///
+  /// The field projection of this request applies to every connection, but the
+  /// [strategy](Self::strategy) and [name](Self::name) filters do not.
+  ///
+  /// # Arguments
+  /// * `ids` - The IDs of the connections to retrieve.
+  pub async fn ids<O>(&self, ids: &[&str]) -> Auth0Result<Vec<Connection<O>>>
+  where
+    O: DeserializeOwned + Send + Sync,
+  {
+    let mut connections: Vec<(usize, Connection<O>)> =
+      stream::iter(ids.iter().enumerate())
+        .map(|(index, id)| async move {
+          let mut get = ConnectionGet::new(id);
+          *get.as_mut() = self.fields.clone();
+
+          let connection = get.send_to(self.client).await?;
+          Auth0Result::Ok((index, connection))
+        })
+        .buffer_unordered(IDS_CONCURRENCY)
+        .try_collect()
+        .await?;
+
+    connections.sort_by_key(|(index, _)| *index);
+    Ok(
+      connections
+        .into_iter()
+        .map(|(_, connection)| connection)
+        .collect(),
+    )
+  }
+}
+
+impl<'a> AsMut<Fields> for ConnectionsList<'a> {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
+impl<'a> AsMut<Page> for ConnectionsList<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ConnectionsList<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ConnectionsList<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/connections").query(&self)
+  }
+}
@@ -0,0 +1,266 @@
+#![warn(missing_docs)]
+//! Connection request builders.
+
+#[doc(inline)]
+pub use connection_create::*;
+#[doc(inline)]
+pub use connection_get::*;
+#[doc(inline)]
+pub use connection_strategy::*;
+#[doc(inline)]
+pub use connection_update::*;
+#[doc(inline)]
+pub use connections_list::*;
+#[doc(inline)]
+pub use options::*;
+#[doc(inline)]
+pub use scim::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result, Connection};
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+
+pub mod connection_create;
+pub mod connection_get;
+pub mod connection_strategy;
+pub mod connection_update;
+pub mod connections_list;
+pub mod options;
+pub mod scim;
+
+/// Connections manager
+pub struct ConnectionsManager(Arc<Auth0Client>);
+
+impl ConnectionsManager {
+  /// Create connections manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a new connection.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `strategy` - The identity provider of the connection, e.g.
+  ///   [ConnectionStrategy::Auth0].
+  /// # Scopes
+  /// * `create:connections`
+  pub fn create(
+    &self,
+    name: &str,
+    strategy: ConnectionStrategy,
+  ) -> ConnectionCreate<'_, ()> {
+    ConnectionCreate::new(&self.0, name, strategy)
+  }
+
+  /// Create a new ADFS connection.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The ADFS server configuration.
+  /// # Scopes
+  /// * `create:connections`
+  pub fn create_adfs(
+    &self,
+    name: &str,
+    options: AdfsConnectionOptions,
+  ) -> AdfsConnectionCreate<'_> {
+    ConnectionCreate::adfs(&self.0, name, options)
+  }
+
+  /// Create a new database connection.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The database configuration.
+  /// # Scopes
+  /// * `create:connections`
+  pub fn create_database(
+    &self,
+    name: &str,
+    options: DatabaseConnectionOptions,
+  ) -> DatabaseConnectionCreate<'_> {
+    ConnectionCreate::database(&self.0, name, options)
+  }
+
+  /// Create a new Google OAuth2 connection.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The Google OAuth client configuration.
+  /// # Scopes
+  /// * `create:connections`
+  pub fn create_google_oauth2(
+    &self,
+    name: &str,
+    options: GoogleOAuth2ConnectionOptions,
+  ) -> GoogleOAuth2ConnectionCreate<'_> {
+    ConnectionCreate::google_oauth2(&self.0, name, options)
+  }
+
+  /// Create a new OpenID Connect connection.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The OpenID Connect provider configuration.
+  /// # Scopes
+  /// * `create:connections`
+  pub fn create_oidc(
+    &self,
+    name: &str,
+    options: OidcConnectionOptions,
+  ) -> OidcConnectionCreate<'_> {
+    ConnectionCreate::oidc(&self.0, name, options)
+  }
+
+  /// Create a new SAML connection.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The SAML identity provider configuration.
+  /// # Scopes
+  /// * `create:connections`
+  pub fn create_samlp(
+    &self,
+    name: &str,
+    options: SamlpConnectionOptions,
+  ) -> SamlpConnectionCreate<'_> {
+    ConnectionCreate::samlp(&self.0, name, options)
+  }
+
+  /// Create a new Azure AD connection.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the connection.
+  /// * `options` - The Azure AD application configuration.
+  /// # Scopes
+  /// * `create:connections`
+  pub fn create_waad(
+    &self,
+    name: &str,
+    options: WaadConnectionOptions,
+  ) -> WaadConnectionCreate<'_> {
+    ConnectionCreate::waad(&self.0, name, options)
+  }
+
+  /// Retrieve details for a specified connection along with options that can be used for
+  /// identity provider configuration.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection to retrieve.
+  /// # Scopes
+  /// * `read:connections`
+  pub async fn get<O, S: AsRef<str>>(&self, id: S) -> Auth0Result<Connection<O>>
+  where
+    O: DeserializeOwned + Send + Sync,
+  {
+    ConnectionGet::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve detailed list of all connections that match the specified strategy.  If no
+  /// strategy is provided, all connections within your tenant are retrieved.
+  ///
+  /// # Scopes
+  /// * `read:connections`
+  pub fn list(&self) -> ConnectionsList<'_> {
+    ConnectionsList::new(&self.0)
+  }
+
+  /// Update a connection.  If `options` is set the whole options object is replaced, so
+  /// ensure that all parameters are present.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection to update.
+  /// # Scopes
+  /// * `update:connections`
+  pub fn update<S: AsRef<str>>(&self, id: S) -> ConnectionUpdate<'_, ()> {
+    ConnectionUpdate::new(&self.0, id)
+  }
+
+  /// Retrieve the SCIM provisioning configuration of a connection.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection.
+  /// # Scopes
+  /// * `read:scim_config`
+  pub async fn get_scim_configuration<S: AsRef<str>>(
+    &self,
+    id: S,
+  ) -> Auth0Result<ScimConfiguration> {
+    ScimConfigurationGet::new(id).send_to(&self.0).await
+  }
+
+  /// Enable SCIM provisioning for a connection.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection.
+  /// # Scopes
+  /// * `create:scim_config`
+  pub fn create_scim_configuration<S: AsRef<str>>(
+    &self,
+    id: S,
+  ) -> ScimConfigurationCreate<'_> {
+    ScimConfigurationCreate::new(&self.0, id)
+  }
+
+  /// Update the SCIM attribute mapping of a connection.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection.
+  /// # Scopes
+  /// * `update:scim_config`
+  pub fn update_scim_configuration<S: AsRef<str>>(
+    &self,
+    id: S,
+  ) -> ScimConfigurationUpdate<'_> {
+    ScimConfigurationUpdate::new(&self.0, id)
+  }
+
+  /// Delete the SCIM provisioning configuration of a connection.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection.
+  /// # Scopes
+  /// * `delete:scim_config`
+  pub async fn delete_scim_configuration<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    ScimConfigurationDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Create a SCIM token for a connection.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection.
+  /// # Scopes
+  /// * `create:scim_token`
+  pub fn create_scim_token<S: AsRef<str>>(&self, id: S) -> ScimTokenCreate<'_> {
+    ScimTokenCreate::new(&self.0, id)
+  }
+
+  /// Retrieve the SCIM tokens of a connection.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection.
+  /// # Scopes
+  /// * `read:scim_token`
+  pub async fn get_scim_tokens<S: AsRef<str>>(
+    &self,
+    id: S,
+  ) -> Auth0Result<Vec<ScimToken>> {
+    ScimTokensGet::new(id).send_to(&self.0).await
+  }
+
+  /// Delete a SCIM token of a connection.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the connection.
+  /// * `token_id` - The ID of the token.
+  /// # Scopes
+  /// * `delete:scim_token`
+  pub async fn delete_scim_token<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    token_id: T,
+  ) -> Auth0Result<()> {
+    ScimTokenDelete::new(id, token_id).send_to(&self.0).await
+  }
+}
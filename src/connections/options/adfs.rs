@@ -0,0 +1,29 @@
+//! ADFS connection options.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Options for connections using the `adfs` strategy.
+///
+/// Either `adfs_server`, the URL of the federation metadata, or `fed_metadata_xml`, the
+/// metadata document itself, must be set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdfsConnectionOptions {
+  /// URL of the ADFS federation metadata, e.g.
+  /// `https://adfs.example.com/FederationMetadata/2007-06/FederationMetadata.xml`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub adfs_server: Option<String>,
+  /// Thumbprints of the ADFS token signing certificates.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub thumbprints: Vec<String>,
+  /// Contents of the ADFS federation metadata document, for servers that are not
+  /// reachable from Auth0.
+  #[serde(rename = "fedMetadataXml")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fed_metadata_xml: Option<String>,
+  /// Email domains that are routed to this connection by home realm discovery.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub domain_aliases: Vec<String>,
+  /// Options not modeled above, kept so that they are sent back unchanged on update.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
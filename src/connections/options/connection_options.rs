@@ -0,0 +1,105 @@
+//! Connection options of any strategy.
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{
+  AdfsConnectionOptions, Connection, ConnectionStrategy, DatabaseConnectionOptions,
+  GoogleOAuth2ConnectionOptions, OidcConnectionOptions, SamlpConnectionOptions,
+  WaadConnectionOptions,
+};
+
+/// Options of a connection, typed by its strategy.
+///
+/// Auth0 does not include the strategy in the options object, so options are
+/// (de)serialized as the bare options of the variant.  Use [ConnectionOptions::from_value]
+/// or deserialize a `Connection<ConnectionOptions>` to pick the variant from the
+/// connection's `strategy`.
+#[derive(Debug, Clone)]
+pub enum ConnectionOptions {
+  /// Options of an `adfs` connection.
+  Adfs(AdfsConnectionOptions),
+  /// Options of an `auth0` (database) connection.
+  Database(DatabaseConnectionOptions),
+  /// Options of a `google-oauth2` connection.
+  GoogleOAuth2(GoogleOAuth2ConnectionOptions),
+  /// Options of an `oidc` connection.
+  Oidc(OidcConnectionOptions),
+  /// Options of a `samlp` connection.
+  Samlp(SamlpConnectionOptions),
+  /// Options of a `waad` (Azure AD) connection.
+  Waad(WaadConnectionOptions),
+  /// Options of any other strategy.
+  Other(Value),
+}
+
+impl ConnectionOptions {
+  /// Parse the options of a connection using `strategy`.
+  pub fn from_value(
+    strategy: &ConnectionStrategy,
+    value: Value,
+  ) -> serde_json::Result<Self> {
+    Ok(match strategy {
+      ConnectionStrategy::Adfs => Self::Adfs(serde_json::from_value(value)?),
+      ConnectionStrategy::Auth0 => Self::Database(serde_json::from_value(value)?),
+      ConnectionStrategy::GoogleOAuth2 => {
+        Self::GoogleOAuth2(serde_json::from_value(value)?)
+      }
+      ConnectionStrategy::Oidc => Self::Oidc(serde_json::from_value(value)?),
+      ConnectionStrategy::Samlp => Self::Samlp(serde_json::from_value(value)?),
+      ConnectionStrategy::Waad => Self::Waad(serde_json::from_value(value)?),
+      _ => Self::Other(value),
+    })
+  }
+
+  /// The strategy these options belong to, `None` for [ConnectionOptions::Other].
+  pub fn strategy(&self) -> Option<ConnectionStrategy> {
+    match self {
+      Self::Adfs(_) => Some(ConnectionStrategy::Adfs),
+      Self::Database(_) => Some(ConnectionStrategy::Auth0),
+      Self::GoogleOAuth2(_) => Some(ConnectionStrategy::GoogleOAuth2),
+      Self::Oidc(_) => Some(ConnectionStrategy::Oidc),
+      Self::Samlp(_) => Some(ConnectionStrategy::Samlp),
+      Self::Waad(_) => Some(ConnectionStrategy::Waad),
+      Self::Other(_) => None,
+    }
+  }
+}
+
+impl Serialize for ConnectionOptions {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      Self::Adfs(options) => options.serialize(serializer),
+      Self::Database(options) => options.serialize(serializer),
+      Self::GoogleOAuth2(options) => options.serialize(serializer),
+      Self::Oidc(options) => options.serialize(serializer),
+      Self::Samlp(options) => options.serialize(serializer),
+      Self::Waad(options) => options.serialize(serializer),
+      Self::Other(options) => options.serialize(serializer),
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for Connection<ConnectionOptions> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let connection = Connection::<Value>::deserialize(deserializer)?;
+    let strategy = &connection.strategy;
+    let options = connection
+      .options
+      .map(|options| ConnectionOptions::from_value(strategy, options))
+      .transpose()
+      .map_err(D::Error::custom)?;
+
+    Ok(Connection {
+      id: connection.id,
+      name: connection.name,
+      display_name: connection.display_name,
+      strategy: connection.strategy,
+      options,
+      enabled_clients: connection.enabled_clients,
+      realms: connection.realms,
+      is_domain_connection: connection.is_domain_connection,
+      metadata: connection.metadata,
+    })
+  }
+}
@@ -0,0 +1,116 @@
+//! Database connection options.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Strength of the passwords required by a database connection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum PasswordPolicy {
+  /// Any non empty password.
+  None,
+  /// At least 6 characters.
+  Low,
+  /// At least 8 characters with lower case, upper case and numbers.
+  Fair,
+  /// At least 8 characters with 3 of lower case, upper case, numbers and special
+  /// characters.
+  Good,
+  /// At least 10 characters with no more than 2 identical characters in a row.
+  Excellent,
+  /// Rules set in `password_complexity_options`.
+  Custom,
+  /// Any other policy.
+  Unknown(String),
+}
+
+impl PasswordPolicy {
+  /// The policy as used by the Auth0 API.
+  pub fn as_str(&self) -> &str {
+    match self {
+      PasswordPolicy::None => "none",
+      PasswordPolicy::Low => "low",
+      PasswordPolicy::Fair => "fair",
+      PasswordPolicy::Good => "good",
+      PasswordPolicy::Excellent => "excellent",
+      PasswordPolicy::Custom => "custom",
+      PasswordPolicy::Unknown(policy) => policy,
+    }
+  }
+}
+
+impl From<String> for PasswordPolicy {
+  fn from(policy: String) -> Self {
+    match policy.as_str() {
+      "none" => PasswordPolicy::None,
+      "low" => PasswordPolicy::Low,
+      "fair" => PasswordPolicy::Fair,
+      "good" => PasswordPolicy::Good,
+      "excellent" => PasswordPolicy::Excellent,
+      "custom" => PasswordPolicy::Custom,
+      _ => PasswordPolicy::Unknown(policy),
+    }
+  }
+}
+
+impl From<PasswordPolicy> for String {
+  fn from(policy: PasswordPolicy) -> Self {
+    policy.as_str().to_owned()
+  }
+}
+
+/// Allowed length of usernames.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct UsernameValidation {
+  /// Minimum length of a username.
+  pub min: u32,
+  /// Maximum length of a username.
+  pub max: u32,
+}
+
+/// Validation rules of a database connection.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseValidation {
+  /// Allowed length of usernames.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub username: Option<UsernameValidation>,
+}
+
+/// Options for connections using the `auth0` (database) strategy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseConnectionOptions {
+  /// Strength of the passwords required.
+  #[serde(rename = "passwordPolicy")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub password_policy: Option<PasswordPolicy>,
+  /// Whether to block an IP address after repeated failed logins to the same account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub brute_force_protection: Option<bool>,
+  /// Whether users are migrated from a custom database as they log in.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub import_mode: Option<bool>,
+  /// Whether public sign ups are disabled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub disable_signup: Option<bool>,
+  /// Whether users must provide a username in addition to their email address.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub requires_username: Option<bool>,
+  /// Validation rules of the connection.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub validation: Option<DatabaseValidation>,
+  /// Whether users are stored in a custom database through `custom_scripts`.
+  #[serde(rename = "enabledDatabaseCustomization")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enabled_database_customization: Option<bool>,
+  /// Custom database action scripts keyed by action, e.g. `login` or `get_user`.
+  #[serde(rename = "customScripts")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_scripts: Option<HashMap<String, String>>,
+  /// Settings available to the custom database scripts as `configuration`.  Values are
+  /// stored encrypted and are not returned when the connection is read.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub configuration: Option<HashMap<String, String>>,
+  /// Options not modeled above, kept so that they are sent back unchanged on update.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
@@ -0,0 +1,22 @@
+//! Google OAuth2 connection options.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Options for connections using the `google-oauth2` strategy.
+///
+/// Leave `client_id` and `client_secret` unset to use the Auth0 development keys.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GoogleOAuth2ConnectionOptions {
+  /// OAuth client ID from the Google Cloud console.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_id: Option<String>,
+  /// OAuth client secret from the Google Cloud console.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_secret: Option<String>,
+  /// Additional client IDs whose Google ID tokens are accepted, e.g. from native apps.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub allowed_audiences: Vec<String>,
+  /// Options not modeled above, kept so that they are sent back unchanged on update.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
@@ -0,0 +1,24 @@
+//! Strategy specific connection options.
+
+#[doc(inline)]
+pub use adfs::*;
+#[doc(inline)]
+pub use connection_options::*;
+#[doc(inline)]
+pub use database::*;
+#[doc(inline)]
+pub use google_oauth2::*;
+#[doc(inline)]
+pub use oidc::*;
+#[doc(inline)]
+pub use samlp::*;
+#[doc(inline)]
+pub use waad::*;
+
+pub mod adfs;
+pub mod connection_options;
+pub mod database;
+pub mod google_oauth2;
+pub mod oidc;
+pub mod samlp;
+pub mod waad;
@@ -0,0 +1,80 @@
+//! OpenID Connect connection options.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// How the OpenID Connect flow is performed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OidcConnectionType {
+  /// Authorization code flow, tokens are exchanged server side.
+  #[default]
+  BackChannel,
+  /// Implicit flow, tokens are returned in the front channel.
+  FrontChannel,
+}
+
+/// How provider claims are mapped to the user profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OidcMappingMode {
+  /// Map the standard profile claims only.
+  BasicProfile,
+  /// Map the claims listed in `attributes`.
+  UseMap,
+  /// Map all claims.
+  BindAll,
+}
+
+/// Mapping of provider claims to user profile attributes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcAttributeMap {
+  /// How claims are mapped.
+  pub mapping_mode: OidcMappingMode,
+  /// Scopes sent to the userinfo endpoint.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub userinfo_scope: Option<String>,
+  /// User profile attributes keyed by name, with the claim to map from as the value.
+  #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+  pub attributes: HashMap<String, Value>,
+}
+
+/// Options for connections using the `oidc` strategy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OidcConnectionOptions {
+  /// Client ID registered with the provider.
+  pub client_id: String,
+  /// Client secret registered with the provider, required for back channel connections.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_secret: Option<String>,
+  /// How the OpenID Connect flow is performed.
+  #[serde(rename = "type")]
+  pub kind: OidcConnectionType,
+  /// Issuer of the provider tokens.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub issuer: Option<String>,
+  /// URL of the provider discovery document, used to fill in the endpoints.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub discovery_url: Option<String>,
+  /// URL of the authorization endpoint.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub authorization_endpoint: Option<String>,
+  /// URL of the token endpoint.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub token_endpoint: Option<String>,
+  /// URL of the userinfo endpoint.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub userinfo_endpoint: Option<String>,
+  /// URL of the provider JSON Web Key Set.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jwks_uri: Option<String>,
+  /// Space separated scopes requested from the provider, e.g. `openid profile email`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub scope: Option<String>,
+  /// Mapping of provider claims to user profile attributes.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub attribute_map: Option<OidcAttributeMap>,
+  /// Options not modeled above, kept so that they are sent back unchanged on update.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
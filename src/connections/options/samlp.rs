@@ -0,0 +1,98 @@
+//! SAML connection options.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Algorithm used to sign SAML requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SamlSignatureAlgorithm {
+  /// RSA with SHA-1.
+  RsaSha1,
+  /// RSA with SHA-256.
+  RsaSha256,
+}
+
+/// Algorithm used to digest SAML requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SamlDigestAlgorithm {
+  /// SHA-1.
+  Sha1,
+  /// SHA-256.
+  Sha256,
+}
+
+/// HTTP binding used to send SAML requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SamlProtocolBinding {
+  /// HTTP POST binding.
+  #[serde(rename = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST")]
+  HttpPost,
+  /// HTTP Redirect binding.
+  #[serde(rename = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect")]
+  HttpRedirect,
+}
+
+/// Settings for logins started by the identity provider.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SamlIdpInitiated {
+  /// Whether IdP initiated logins are accepted.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enabled: Option<bool>,
+  /// Client the user is logged in to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_id: Option<String>,
+  /// Protocol used to log in to the client, e.g. `oauth2`, `samlp` or `wsfed`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_protocol: Option<String>,
+  /// Query string sent to the client, e.g. `response_type=code`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_authorizequery: Option<String>,
+}
+
+/// Options for connections using the `samlp` strategy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SamlpConnectionOptions {
+  /// Sign in URL of the identity provider.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sign_in_endpoint: Option<String>,
+  /// Sign out URL of the identity provider.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sign_out_endpoint: Option<String>,
+  /// HTTP binding used to send the sign in request.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub protocol_binding: Option<SamlProtocolBinding>,
+  /// Base64 encoded X.509 signing certificate of the identity provider, in PEM or CER
+  /// format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub signing_cert: Option<String>,
+  /// Whether to sign the SAML request.
+  #[serde(rename = "signSAMLRequest")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sign_saml_request: Option<bool>,
+  /// Algorithm used to digest the SAML request.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub digest_algorithm: Option<SamlDigestAlgorithm>,
+  /// Algorithm used to sign the SAML request.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub signature_algorithm: Option<SamlSignatureAlgorithm>,
+  /// Template of the SAML request, for identity providers that need a custom format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub request_template: Option<String>,
+  /// SAML attribute used as the user ID.
+  #[serde(rename = "user_id_attribute")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub user_id_attribute: Option<String>,
+  /// Mapping of user profile attributes to the SAML attributes, a single attribute name
+  /// or an array of them.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fields_map: Option<HashMap<String, Value>>,
+  /// Settings for logins started by the identity provider.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub idpinitiated: Option<SamlIdpInitiated>,
+  /// Options not modeled above, kept so that they are sent back unchanged on update.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
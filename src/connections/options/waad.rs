@@ -0,0 +1,57 @@
+//! Azure AD connection options.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Protocol used to talk to Azure AD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WaadProtocol {
+  /// OpenID Connect.
+  OpenidConnect,
+  /// WS-Federation.
+  WsFederation,
+}
+
+/// Azure AD API used to read user profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaadIdentityApi {
+  /// Microsoft Identity Platform (Microsoft Graph).
+  #[serde(rename = "microsoft-identity-platform-v2.0")]
+  MicrosoftIdentityPlatformV2,
+  /// Azure Active Directory Graph.
+  #[serde(rename = "azure-active-directory-v1.0")]
+  AzureActiveDirectoryV1,
+}
+
+/// Options for connections using the `waad` (Azure AD) strategy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WaadConnectionOptions {
+  /// Client ID of the Azure AD application.
+  pub client_id: String,
+  /// Client secret of the Azure AD application.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_secret: Option<String>,
+  /// Azure AD tenant domain, e.g. `example.onmicrosoft.com`.
+  pub tenant_domain: String,
+  /// Email domains that are routed to this connection by home realm discovery.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub domain_aliases: Vec<String>,
+  /// Whether to use WS-Federation instead of OpenID Connect.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub use_wsfed: Option<bool>,
+  /// Protocol used to talk to Azure AD.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub waad_protocol: Option<WaadProtocol>,
+  /// Whether users can be read from the directory by the Auth0 APIs.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub api_enable_users: Option<bool>,
+  /// Application ID URI of the Azure AD application.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub app_id: Option<String>,
+  /// Azure AD API used to read user profiles.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub identity_api: Option<WaadIdentityApi>,
+  /// Options not modeled above, kept so that they are sent back unchanged on update.
+  #[serde(flatten)]
+  pub extra: Map<String, Value>,
+}
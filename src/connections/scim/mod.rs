@@ -0,0 +1,19 @@
+//! SCIM provisioning request builders.
+
+pub use scim_configuration::*;
+pub use scim_configuration_create::*;
+pub use scim_configuration_delete::*;
+pub use scim_configuration_get::*;
+pub use scim_configuration_update::*;
+pub use scim_token_create::*;
+pub use scim_token_delete::*;
+pub use scim_tokens_get::*;
+
+pub mod scim_configuration;
+pub mod scim_configuration_create;
+pub mod scim_configuration_delete;
+pub mod scim_configuration_get;
+pub mod scim_configuration_update;
+pub mod scim_token_create;
+pub mod scim_token_delete;
+pub mod scim_tokens_get;
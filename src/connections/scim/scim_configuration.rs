@@ -0,0 +1,52 @@
+//! SCIM configuration types.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::ConnectionStrategy;
+
+/// Mapping between an Auth0 user attribute and a SCIM attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScimMapping {
+  /// Auth0 user attribute, e.g. `email`.
+  pub auth0: String,
+  /// SCIM attribute, e.g. `emails[primary eq true].value`.
+  pub scim: String,
+}
+
+/// SCIM provisioning configuration of a connection.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimConfiguration {
+  /// ID of the connection.
+  pub connection_id: String,
+  /// Name of the connection.
+  pub connection_name: String,
+  /// Strategy of the connection, e.g. `samlp`.
+  pub strategy: ConnectionStrategy,
+  /// Name of the tenant.
+  pub tenant_name: String,
+  /// SCIM attribute used as the user ID.
+  pub user_id_attribute: String,
+  /// Attribute mappings between Auth0 and SCIM.
+  #[serde(default)]
+  pub mapping: Vec<ScimMapping>,
+  /// Date and time the configuration was created.
+  pub created_at: Option<DateTime<Utc>>,
+  /// Date and time the configuration was last updated.
+  pub updated_on: Option<DateTime<Utc>>,
+}
+
+/// Bearer token used by an identity provider to call the SCIM endpoint of a connection.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimToken {
+  /// ID of the token.
+  pub token_id: String,
+  /// The token itself.  Only returned when the token is created.
+  pub token: Option<String>,
+  /// Scopes granted to the token.
+  #[serde(default)]
+  pub scopes: Vec<String>,
+  /// Date and time the token was created.
+  pub created_at: Option<DateTime<Utc>>,
+  /// Date and time the token expires.
+  pub valid_until: Option<DateTime<Utc>>,
+}
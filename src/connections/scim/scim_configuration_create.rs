@@ -0,0 +1,77 @@
+//! Create the SCIM configuration of a connection.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, ScimMapping};
+
+/// Enable SCIM provisioning for a connection.  Auth0 uses default attribute mappings for
+/// anything that is not set.
+///
+/// Send as [ScimConfiguration](crate::ScimConfiguration).
+///
+/// # Scopes
+/// * `create:scim_config`
+#[derive(Serialize)]
+pub struct ScimConfigurationCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  connection_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user_id_attribute: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  mapping: Option<Vec<ScimMapping>>,
+}
+
+impl<'a> ScimConfigurationCreate<'a> {
+  /// Create create SCIM configuration request.
+  ///
+  /// # Arguments
+  /// * `connection_id` - The ID of the connection.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, connection_id: S) -> Self {
+    Self {
+      client,
+
+      connection_id: connection_id.as_ref().to_string(),
+      user_id_attribute: None,
+      mapping: None,
+    }
+  }
+
+  /// SCIM attribute used as the user ID, e.g. `externalId`.
+  pub fn user_id_attribute(mut self, user_id_attribute: &str) -> Self {
+    self.user_id_attribute = Some(user_id_attribute.to_owned());
+    self
+  }
+
+  /// Map an Auth0 user attribute to a SCIM attribute.
+  pub fn mapping(mut self, auth0: &str, scim: &str) -> Self {
+    self.mapping.get_or_insert_with(Vec::new).push(ScimMapping {
+      auth0: auth0.to_owned(),
+      scim: scim.to_owned(),
+    });
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ScimConfigurationCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ScimConfigurationCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::POST,
+        &format!(
+          "api/v2/connections/{}/scim-configuration",
+          path_segment(&self.connection_id)
+        ),
+      )
+      .json(self)
+  }
+}
@@ -0,0 +1,33 @@
+//! Delete the SCIM configuration of a connection.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete the SCIM provisioning configuration of a connection, disabling SCIM for it.
+///
+/// # Scopes
+/// * `delete:scim_config`
+pub struct ScimConfigurationDelete(String);
+
+impl ScimConfigurationDelete {
+  /// Create delete SCIM configuration request.
+  ///
+  /// # Arguments
+  /// * `connection_id` - The ID of the connection.
+  pub fn new<S: AsRef<str>>(connection_id: S) -> Self {
+    Self(connection_id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for ScimConfigurationDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!(
+        "api/v2/connections/{}/scim-configuration",
+        path_segment(&self.0)
+      ),
+    )
+  }
+}
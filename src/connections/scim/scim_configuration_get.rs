@@ -0,0 +1,35 @@
+//! Retrieve the SCIM configuration of a connection.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the SCIM provisioning configuration of a connection.
+///
+/// Send as [ScimConfiguration](crate::ScimConfiguration).
+///
+/// # Scopes
+/// * `read:scim_config`
+pub struct ScimConfigurationGet(String);
+
+impl ScimConfigurationGet {
+  /// Create get SCIM configuration request.
+  ///
+  /// # Arguments
+  /// * `connection_id` - The ID of the connection.
+  pub fn new<S: AsRef<str>>(connection_id: S) -> Self {
+    Self(connection_id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for ScimConfigurationGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!(
+        "api/v2/connections/{}/scim-configuration",
+        path_segment(&self.0)
+      ),
+    )
+  }
+}
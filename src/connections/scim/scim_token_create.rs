@@ -0,0 +1,74 @@
+//! Create a SCIM token.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Create a bearer token for an identity provider to call the SCIM endpoint of a
+/// connection.  The token is only returned once, store it right away.
+///
+/// Send as [ScimToken](crate::ScimToken).
+///
+/// # Scopes
+/// * `create:scim_token`
+#[derive(Serialize)]
+pub struct ScimTokenCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  connection_id: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  scopes: Vec<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  token_lifetime: Option<u32>,
+}
+
+impl<'a> ScimTokenCreate<'a> {
+  /// Create create SCIM token request.
+  ///
+  /// # Arguments
+  /// * `connection_id` - The ID of the connection.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, connection_id: S) -> Self {
+    Self {
+      client,
+
+      connection_id: connection_id.as_ref().to_string(),
+      scopes: Vec::new(),
+      token_lifetime: None,
+    }
+  }
+
+  /// Add a scope to grant to the token, e.g. `get:users`.
+  pub fn scope(mut self, scope: &str) -> Self {
+    self.scopes.push(scope.to_owned());
+    self
+  }
+
+  /// Number of seconds the token is valid for.  Tokens never expire if unset.
+  pub fn token_lifetime(mut self, token_lifetime: u32) -> Self {
+    self.token_lifetime = Some(token_lifetime);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ScimTokenCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ScimTokenCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::POST,
+        &format!(
+          "api/v2/connections/{}/scim-configuration/tokens",
+          path_segment(&self.connection_id)
+        ),
+      )
+      .json(self)
+  }
+}
@@ -0,0 +1,41 @@
+//! Delete a SCIM token.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete a SCIM token.  The identity provider using it can no longer provision users.
+///
+/// # Scopes
+/// * `delete:scim_token`
+pub struct ScimTokenDelete {
+  connection_id: String,
+  token_id: String,
+}
+
+impl ScimTokenDelete {
+  /// Create delete SCIM token request.
+  ///
+  /// # Arguments
+  /// * `connection_id` - The ID of the connection.
+  /// * `token_id` - The ID of the token.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(connection_id: S, token_id: T) -> Self {
+    Self {
+      connection_id: connection_id.as_ref().to_string(),
+      token_id: token_id.as_ref().to_string(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for ScimTokenDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!(
+        "api/v2/connections/{}/scim-configuration/tokens/{}",
+        path_segment(&self.connection_id),
+        path_segment(&self.token_id)
+      ),
+    )
+  }
+}
@@ -0,0 +1,36 @@
+//! Retrieve SCIM tokens.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the SCIM tokens of a connection.  The token values themselves are not
+/// returned.
+///
+/// Send as `Vec<ScimToken>`.
+///
+/// # Scopes
+/// * `read:scim_token`
+pub struct ScimTokensGet(String);
+
+impl ScimTokensGet {
+  /// Create get SCIM tokens request.
+  ///
+  /// # Arguments
+  /// * `connection_id` - The ID of the connection.
+  pub fn new<S: AsRef<str>>(connection_id: S) -> Self {
+    Self(connection_id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for ScimTokensGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!(
+        "api/v2/connections/{}/scim-configuration/tokens",
+        path_segment(&self.0)
+      ),
+    )
+  }
+}
@@ -0,0 +1,171 @@
+//! Custom domain types.
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// How the TLS certificate of a custom domain is managed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum CustomDomainType {
+  /// Auth0 provisions and renews the certificate.
+  Auth0ManagedCerts,
+  /// The certificate is managed by a reverse proxy in front of Auth0.
+  SelfManagedCerts,
+  /// A certificate management type not known to this crate.
+  Unknown(String),
+}
+
+impl CustomDomainType {
+  /// The value as used by the Auth0 API.
+  pub fn as_str(&self) -> &str {
+    match self {
+      CustomDomainType::Auth0ManagedCerts => "auth0_managed_certs",
+      CustomDomainType::SelfManagedCerts => "self_managed_certs",
+      CustomDomainType::Unknown(value) => value,
+    }
+  }
+}
+
+impl From<String> for CustomDomainType {
+  fn from(value: String) -> Self {
+    match value.as_str() {
+      "auth0_managed_certs" => CustomDomainType::Auth0ManagedCerts,
+      "self_managed_certs" => CustomDomainType::SelfManagedCerts,
+      _ => CustomDomainType::Unknown(value),
+    }
+  }
+}
+
+impl From<CustomDomainType> for String {
+  fn from(value: CustomDomainType) -> Self {
+    value.as_str().to_owned()
+  }
+}
+
+impl Display for CustomDomainType {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Status of a custom domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum CustomDomainStatus {
+  /// Waiting for the domain ownership to be verified.
+  PendingVerification,
+  /// Verified and serving traffic.
+  Ready,
+  /// Stopped.
+  Stopped,
+  /// Disabled.
+  Disabled,
+  /// A status not known to this crate.
+  Unknown(String),
+}
+
+impl CustomDomainStatus {
+  /// The value as used by the Auth0 API.
+  pub fn as_str(&self) -> &str {
+    match self {
+      CustomDomainStatus::PendingVerification => "pending_verification",
+      CustomDomainStatus::Ready => "ready",
+      CustomDomainStatus::Stopped => "stopped",
+      CustomDomainStatus::Disabled => "disabled",
+      CustomDomainStatus::Unknown(value) => value,
+    }
+  }
+}
+
+impl From<String> for CustomDomainStatus {
+  fn from(value: String) -> Self {
+    match value.as_str() {
+      "pending_verification" => CustomDomainStatus::PendingVerification,
+      "ready" => CustomDomainStatus::Ready,
+      "stopped" => CustomDomainStatus::Stopped,
+      "disabled" => CustomDomainStatus::Disabled,
+      _ => CustomDomainStatus::Unknown(value),
+    }
+  }
+}
+
+impl From<CustomDomainStatus> for String {
+  fn from(value: CustomDomainStatus) -> Self {
+    value.as_str().to_owned()
+  }
+}
+
+impl Display for CustomDomainStatus {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// TLS policy of a custom domain with Auth0 managed certificates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TlsPolicy {
+  /// Supports TLS 1.2 and up with modern ciphers.
+  Recommended,
+  /// Also supports older TLS versions and ciphers for legacy clients.
+  Compatible,
+}
+
+/// DNS record used to verify ownership of a custom domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomDomainVerificationMethod {
+  /// Type of the record, e.g. `cname` or `txt`.
+  pub name: String,
+  /// Value the record must be set to.
+  pub record: String,
+  /// Domain name the record must be created for.
+  pub domain: Option<String>,
+}
+
+/// Verification details of a custom domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomDomainVerificationMethods {
+  /// DNS records that can be used to verify the domain.
+  #[serde(default)]
+  pub methods: Vec<CustomDomainVerificationMethod>,
+}
+
+/// Custom domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomDomain {
+  /// ID of the custom domain.
+  pub custom_domain_id: String,
+  /// Domain name.
+  pub domain: String,
+  /// Whether this is a primary domain (true) or not (false).
+  pub primary: bool,
+  /// Status of the custom domain.
+  pub status: CustomDomainStatus,
+  /// How the TLS certificate is managed.
+  #[serde(rename = "type")]
+  pub kind: CustomDomainType,
+  /// Verification details, present until the domain is verified.
+  pub verification: Option<CustomDomainVerificationMethods>,
+  /// CNAME API key header, only for self managed certificates.
+  pub cname_api_key: Option<String>,
+  /// Intermediate address.
+  pub origin_domain_name: Option<String>,
+  /// TLS policy of the domain.
+  pub tls_policy: Option<TlsPolicy>,
+  /// HTTP header to fetch the client IP address from.
+  pub custom_client_ip_header: Option<String>,
+}
+
+/// Result of a custom domain verification.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomDomainVerification {
+  /// ID of the custom domain.
+  pub custom_domain_id: String,
+  /// Domain name.
+  pub domain: String,
+  /// Status of the custom domain after verification.
+  pub status: CustomDomainStatus,
+  /// CNAME API key header, only for self managed certificates.
+  pub cname_api_key: Option<String>,
+  /// Verification details, present while verification is pending.
+  pub verification: Option<CustomDomainVerificationMethods>,
+}
@@ -0,0 +1,70 @@
+//! Create a custom domain.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, CustomDomainType, TlsPolicy};
+
+/// Create a new custom domain.  The domain must then be verified with
+/// [CustomDomainVerify](crate::CustomDomainVerify) before it is used.
+///
+/// Send as [CustomDomain](crate::CustomDomain).
+///
+/// # Scopes
+/// * `create:custom_domains`
+#[derive(Serialize)]
+pub struct CustomDomainCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  domain: String,
+  #[serde(rename = "type")]
+  kind: CustomDomainType,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  tls_policy: Option<TlsPolicy>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  custom_client_ip_header: Option<String>,
+}
+
+impl<'a> CustomDomainCreate<'a> {
+  /// Create create custom domain request.
+  ///
+  /// # Arguments
+  /// * `domain` - Domain name.
+  /// * `kind` - How the TLS certificate is managed.
+  pub fn new(client: &'a Auth0Client, domain: &str, kind: CustomDomainType) -> Self {
+    Self {
+      client,
+
+      domain: domain.to_owned(),
+      kind,
+      tls_policy: None,
+      custom_client_ip_header: None,
+    }
+  }
+
+  /// TLS policy of the domain.
+  pub fn tls_policy(mut self, tls_policy: TlsPolicy) -> Self {
+    self.tls_policy = Some(tls_policy);
+    self
+  }
+
+  /// HTTP header to fetch the client IP address from, e.g. `cf-connecting-ip`.
+  pub fn custom_client_ip_header(mut self, custom_client_ip_header: &str) -> Self {
+    self.custom_client_ip_header = Some(custom_client_ip_header.to_owned());
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for CustomDomainCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for CustomDomainCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/custom-domains")
+      .json(self)
+  }
+}
@@ -0,0 +1,30 @@
+//! Delete a custom domain.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete a custom domain and stop serving requests for it.
+///
+/// # Scopes
+/// * `delete:custom_domains`
+pub struct CustomDomainDelete(String);
+
+impl CustomDomainDelete {
+  /// Create delete custom domain request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the domain to delete.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for CustomDomainDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/custom-domains/{}", path_segment(&self.0)),
+    )
+  }
+}
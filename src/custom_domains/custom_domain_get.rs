@@ -0,0 +1,32 @@
+//! Retrieve a custom domain.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve a custom domain configuration and status.
+///
+/// Send as [CustomDomain](crate::CustomDomain).
+///
+/// # Scopes
+/// * `read:custom_domains`
+pub struct CustomDomainGet(String);
+
+impl CustomDomainGet {
+  /// Create get custom domain request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the domain to retrieve.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for CustomDomainGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/custom-domains/{}", path_segment(&self.0)),
+    )
+  }
+}
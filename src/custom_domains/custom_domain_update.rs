@@ -0,0 +1,71 @@
+//! Update a custom domain.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, TlsPolicy};
+
+/// Update the TLS policy or client IP header of a custom domain, e.g. to move a verified
+/// domain from the `compatible` to the `recommended` TLS policy.
+///
+/// Send as [CustomDomain](crate::CustomDomain).
+///
+/// # Scopes
+/// * `update:custom_domains`
+#[derive(Serialize)]
+pub struct CustomDomainUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  tls_policy: Option<TlsPolicy>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  custom_client_ip_header: Option<String>,
+}
+
+impl<'a> CustomDomainUpdate<'a> {
+  /// Create update custom domain request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the domain to update.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      tls_policy: None,
+      custom_client_ip_header: None,
+    }
+  }
+
+  /// TLS policy of the domain.
+  pub fn tls_policy(mut self, tls_policy: TlsPolicy) -> Self {
+    self.tls_policy = Some(tls_policy);
+    self
+  }
+
+  /// HTTP header to fetch the client IP address from, e.g. `cf-connecting-ip`.
+  pub fn custom_client_ip_header(mut self, custom_client_ip_header: &str) -> Self {
+    self.custom_client_ip_header = Some(custom_client_ip_header.to_owned());
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for CustomDomainUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for CustomDomainUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!("api/v2/custom-domains/{}", path_segment(&self.id)),
+      )
+      .json(self)
+  }
+}
@@ -0,0 +1,33 @@
+//! Verify a custom domain.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Run the verification process on a custom domain.  Check the `status` field to see
+/// whether verification succeeded, and retry if it is still pending.
+///
+/// Send as [CustomDomainVerification](crate::CustomDomainVerification).
+///
+/// # Scopes
+/// * `create:custom_domains`
+pub struct CustomDomainVerify(String);
+
+impl CustomDomainVerify {
+  /// Create verify custom domain request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the domain to verify.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for CustomDomainVerify {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::POST,
+      &format!("api/v2/custom-domains/{}/verify", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,26 @@
+//! Retrieve custom domains.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve details on custom domains.
+///
+/// Send as `Vec<CustomDomain>`.
+///
+/// # Scopes
+/// * `read:custom_domains`
+#[derive(Default)]
+pub struct CustomDomainsList;
+
+impl CustomDomainsList {
+  /// Create list custom domains request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for CustomDomainsList {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/custom-domains")
+  }
+}
@@ -0,0 +1,100 @@
+#![warn(missing_docs)]
+//! Custom domain request builders.
+
+#[doc(inline)]
+pub use custom_domain::*;
+#[doc(inline)]
+pub use custom_domain_create::*;
+#[doc(inline)]
+pub use custom_domain_delete::*;
+#[doc(inline)]
+pub use custom_domain_get::*;
+#[doc(inline)]
+pub use custom_domain_update::*;
+#[doc(inline)]
+pub use custom_domain_verify::*;
+#[doc(inline)]
+pub use custom_domains_list::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod custom_domain;
+pub mod custom_domain_create;
+pub mod custom_domain_delete;
+pub mod custom_domain_get;
+pub mod custom_domain_update;
+pub mod custom_domain_verify;
+pub mod custom_domains_list;
+
+/// Custom domains manager
+pub struct CustomDomainsManager(Arc<Auth0Client>);
+
+impl CustomDomainsManager {
+  /// Create custom domains manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a new custom domain.
+  ///
+  /// # Arguments
+  /// * `domain` - Domain name.
+  /// * `kind` - How the TLS certificate is managed.
+  /// # Scopes
+  /// * `create:custom_domains`
+  pub fn create(&self, domain: &str, kind: CustomDomainType) -> CustomDomainCreate<'_> {
+    CustomDomainCreate::new(&self.0, domain, kind)
+  }
+
+  /// Delete a custom domain and stop serving requests for it.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the domain to delete.
+  /// # Scopes
+  /// * `delete:custom_domains`
+  pub async fn delete<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    CustomDomainDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve a custom domain configuration and status.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the domain to retrieve.
+  /// # Scopes
+  /// * `read:custom_domains`
+  pub async fn get<S: AsRef<str>>(&self, id: S) -> Auth0Result<CustomDomain> {
+    CustomDomainGet::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve details on custom domains.
+  ///
+  /// # Scopes
+  /// * `read:custom_domains`
+  pub async fn list(&self) -> Auth0Result<Vec<CustomDomain>> {
+    CustomDomainsList::new().send_to(&self.0).await
+  }
+
+  /// Update a custom domain.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the domain to update.
+  /// # Scopes
+  /// * `update:custom_domains`
+  pub fn update<S: AsRef<str>>(&self, id: S) -> CustomDomainUpdate<'_> {
+    CustomDomainUpdate::new(&self.0, id)
+  }
+
+  /// Run the verification process on a custom domain.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the domain to verify.
+  /// # Scopes
+  /// * `create:custom_domains`
+  pub async fn verify<S: AsRef<str>>(
+    &self,
+    id: S,
+  ) -> Auth0Result<CustomDomainVerification> {
+    CustomDomainVerify::new(id).send_to(&self.0).await
+  }
+}
@@ -0,0 +1,75 @@
+//! Device credential types.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Type of a device credential.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum DeviceCredentialType {
+  /// Public key used to authenticate the device.
+  PublicKey,
+  /// Refresh token issued to the device.
+  RefreshToken,
+  /// Rotating refresh token issued to the device.
+  RotatingRefreshToken,
+  /// A credential type not known to this crate.
+  Unknown(String),
+}
+
+impl DeviceCredentialType {
+  /// The value as used by the Auth0 API.
+  pub fn as_str(&self) -> &str {
+    match self {
+      DeviceCredentialType::PublicKey => "public_key",
+      DeviceCredentialType::RefreshToken => "refresh_token",
+      DeviceCredentialType::RotatingRefreshToken => "rotating_refresh_token",
+      DeviceCredentialType::Unknown(value) => value,
+    }
+  }
+}
+
+impl From<String> for DeviceCredentialType {
+  fn from(value: String) -> Self {
+    match value.as_str() {
+      "public_key" => DeviceCredentialType::PublicKey,
+      "refresh_token" => DeviceCredentialType::RefreshToken,
+      "rotating_refresh_token" => DeviceCredentialType::RotatingRefreshToken,
+      _ => DeviceCredentialType::Unknown(value),
+    }
+  }
+}
+
+impl From<DeviceCredentialType> for String {
+  fn from(value: DeviceCredentialType) -> Self {
+    value.as_str().to_owned()
+  }
+}
+
+impl Display for DeviceCredentialType {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Device credential.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceCredential {
+  /// ID of this device credential.
+  pub id: String,
+  /// User agent for this device.
+  pub device_name: String,
+  /// Unique identifier for the device.
+  pub device_id: String,
+  /// Type of this credential.
+  #[serde(rename = "type")]
+  pub kind: DeviceCredentialType,
+  /// User ID this credential is associated with.
+  pub user_id: Option<String>,
+  /// Client ID of the application this credential belongs to.
+  pub client_id: Option<String>,
+  /// The time when the credential was created.
+  pub created_at: Option<DateTime<Utc>>,
+  /// The time when the credential was last used.
+  pub last_used: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,80 @@
+//! Create a device public key credential.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, DeviceCredentialType};
+
+/// Create a device credential public key to manage refresh token rotation for a given
+/// user.
+///
+/// Send as [DeviceCredential](crate::DeviceCredential).
+///
+/// # Scopes
+/// * `create:current_user_device_credentials`
+#[derive(Serialize)]
+pub struct DeviceCredentialCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  device_name: String,
+  #[serde(rename = "type")]
+  kind: DeviceCredentialType,
+  value: String,
+  device_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  client_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user_id: Option<String>,
+}
+
+impl<'a> DeviceCredentialCreate<'a> {
+  /// Create create device public key credential request.
+  ///
+  /// # Arguments
+  /// * `device_name` - Name for this device easily recognized by the owner.
+  /// * `device_id` - Unique identifier for the device.
+  /// * `value` - Base64 encoded string containing the credential.
+  pub fn new(
+    client: &'a Auth0Client,
+    device_name: &str,
+    device_id: &str,
+    value: &str,
+  ) -> Self {
+    Self {
+      client,
+
+      device_name: device_name.to_owned(),
+      kind: DeviceCredentialType::PublicKey,
+      value: value.to_owned(),
+      device_id: device_id.to_owned(),
+      client_id: None,
+      user_id: None,
+    }
+  }
+
+  /// Client ID of the application the credential is created for.
+  pub fn client_id(mut self, client_id: &str) -> Self {
+    self.client_id = Some(client_id.to_owned());
+    self
+  }
+
+  /// User ID the credential is created for.
+  pub fn user_id(mut self, user_id: &str) -> Self {
+    self.user_id = Some(user_id.to_owned());
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for DeviceCredentialCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for DeviceCredentialCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/device-credentials")
+      .json(self)
+  }
+}
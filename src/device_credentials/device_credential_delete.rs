@@ -0,0 +1,31 @@
+//! Delete a device credential.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Permanently delete a device credential (such as a refresh token or public key) with
+/// the given ID.
+///
+/// # Scopes
+/// * `delete:device_credentials`
+pub struct DeviceCredentialDelete(String);
+
+impl DeviceCredentialDelete {
+  /// Create delete device credential request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the credential to delete.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for DeviceCredentialDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/device-credentials/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,77 @@
+//! Retrieve device credentials.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::Fields;
+use crate::{Auth0Client, Auth0RequestBuilder, DeviceCredentialType};
+
+/// Retrieve device credential information (`public_key`, `refresh_token`) associated
+/// with a specific user.
+///
+/// Send as `Vec<DeviceCredential>`.
+///
+/// # Scopes
+/// * `read:device_credentials`
+#[derive(Serialize)]
+pub struct DeviceCredentialsGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  user_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  client_id: Option<String>,
+  #[serde(rename = "type")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  kind: Option<DeviceCredentialType>,
+  #[serde(flatten)]
+  fields: Fields,
+}
+
+impl<'a> DeviceCredentialsGet<'a> {
+  /// Create get device credentials request.
+  ///
+  /// # Arguments
+  /// * `user_id` - The ID of the user whose credentials to retrieve.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, user_id: S) -> Self {
+    Self {
+      client,
+
+      user_id: user_id.as_ref().to_string(),
+      client_id: None,
+      kind: None,
+      fields: Default::default(),
+    }
+  }
+
+  /// Only retrieve credentials issued to this application.
+  pub fn client_id(mut self, client_id: &str) -> Self {
+    self.client_id = Some(client_id.to_owned());
+    self
+  }
+
+  /// Only retrieve credentials of this type.
+  pub fn kind(mut self, kind: DeviceCredentialType) -> Self {
+    self.kind = Some(kind);
+    self
+  }
+}
+
+impl<'a> AsMut<Fields> for DeviceCredentialsGet<'a> {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for DeviceCredentialsGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for DeviceCredentialsGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::GET, "api/v2/device-credentials")
+      .query(&self)
+  }
+}
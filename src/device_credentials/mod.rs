@@ -0,0 +1,67 @@
+#![warn(missing_docs)]
+//! Device credential request builders.
+
+#[doc(inline)]
+pub use device_credential::*;
+#[doc(inline)]
+pub use device_credential_create::*;
+#[doc(inline)]
+pub use device_credential_delete::*;
+#[doc(inline)]
+pub use device_credentials_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod device_credential;
+pub mod device_credential_create;
+pub mod device_credential_delete;
+pub mod device_credentials_get;
+
+/// Device credentials manager
+pub struct DeviceCredentialsManager(Arc<Auth0Client>);
+
+impl DeviceCredentialsManager {
+  /// Create device credentials manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a device credential public key to manage refresh token rotation for a given
+  /// user.
+  ///
+  /// # Arguments
+  /// * `device_name` - Name for this device easily recognized by the owner.
+  /// * `device_id` - Unique identifier for the device.
+  /// * `value` - Base64 encoded string containing the credential.
+  /// # Scopes
+  /// * `create:current_user_device_credentials`
+  pub fn create(
+    &self,
+    device_name: &str,
+    device_id: &str,
+    value: &str,
+  ) -> DeviceCredentialCreate<'_> {
+    DeviceCredentialCreate::new(&self.0, device_name, device_id, value)
+  }
+
+  /// Permanently delete a device credential.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the credential to delete.
+  /// # Scopes
+  /// * `delete:device_credentials`
+  pub async fn delete<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    DeviceCredentialDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve device credential information associated with a specific user.
+  ///
+  /// # Arguments
+  /// * `user_id` - The ID of the user whose credentials to retrieve.
+  /// # Scopes
+  /// * `read:device_credentials`
+  pub fn list<S: AsRef<str>>(&self, user_id: S) -> DeviceCredentialsGet<'_> {
+    DeviceCredentialsGet::new(&self.0, user_id)
+  }
+}
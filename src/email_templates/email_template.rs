@@ -0,0 +1,118 @@
+//! Email template
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Name of an email template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum EmailTemplateName {
+  /// Verification email with a link.
+  VerifyEmail,
+  /// Verification email with a code.
+  VerifyEmailByCode,
+  /// Change password email.
+  ResetEmail,
+  /// Welcome email.
+  WelcomeEmail,
+  /// Blocked account email.
+  BlockedAccount,
+  /// Password breach alert email.
+  StolenCredentials,
+  /// Multi-factor enrollment email.
+  EnrollmentEmail,
+  /// Multi-factor verification code email.
+  MfaOobCode,
+  /// Organization invitation email.
+  UserInvitation,
+  /// Change password email (legacy).
+  ChangePassword,
+  /// Password reset email (legacy).
+  PasswordReset,
+  /// A template not known to this crate.
+  Unknown(String),
+}
+
+impl EmailTemplateName {
+  /// Template name as used by the Auth0 API.
+  pub fn as_str(&self) -> &str {
+    match self {
+      Self::VerifyEmail => "verify_email",
+      Self::VerifyEmailByCode => "verify_email_by_code",
+      Self::ResetEmail => "reset_email",
+      Self::WelcomeEmail => "welcome_email",
+      Self::BlockedAccount => "blocked_account",
+      Self::StolenCredentials => "stolen_credentials",
+      Self::EnrollmentEmail => "enrollment_email",
+      Self::MfaOobCode => "mfa_oob_code",
+      Self::UserInvitation => "user_invitation",
+      Self::ChangePassword => "change_password",
+      Self::PasswordReset => "password_reset",
+      Self::Unknown(name) => name,
+    }
+  }
+}
+
+impl From<String> for EmailTemplateName {
+  fn from(name: String) -> Self {
+    match name.as_str() {
+      "verify_email" => Self::VerifyEmail,
+      "verify_email_by_code" => Self::VerifyEmailByCode,
+      "reset_email" => Self::ResetEmail,
+      "welcome_email" => Self::WelcomeEmail,
+      "blocked_account" => Self::BlockedAccount,
+      "stolen_credentials" => Self::StolenCredentials,
+      "enrollment_email" => Self::EnrollmentEmail,
+      "mfa_oob_code" => Self::MfaOobCode,
+      "user_invitation" => Self::UserInvitation,
+      "change_password" => Self::ChangePassword,
+      "password_reset" => Self::PasswordReset,
+      _ => Self::Unknown(name),
+    }
+  }
+}
+
+impl From<EmailTemplateName> for String {
+  fn from(name: EmailTemplateName) -> Self {
+    name.as_str().to_owned()
+  }
+}
+
+impl Display for EmailTemplateName {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Syntax of an email template body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailTemplateSyntax {
+  /// [Liquid](https://shopify.github.io/liquid/) syntax.
+  Liquid,
+  /// [Mustache](https://mustache.github.io/) syntax.
+  Mustache,
+}
+
+/// Email template.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailTemplate {
+  /// Template name.
+  pub template: EmailTemplateName,
+  /// Body of the email template.
+  pub body: Option<String>,
+  /// Senders `from` email address.
+  pub from: Option<String>,
+  /// URL to redirect the user to after a successful action.
+  #[serde(rename = "resultUrl")]
+  pub result_url: Option<String>,
+  /// Subject line of the email.
+  pub subject: Option<String>,
+  /// Syntax of the template body.
+  pub syntax: Option<EmailTemplateSyntax>,
+  /// Lifetime in seconds that the link within the email will be valid for.
+  #[serde(rename = "urlLifetimeInSeconds")]
+  pub url_lifetime_in_seconds: Option<u32>,
+  /// Whether the template is enabled (true) or disabled (false).
+  #[serde(default)]
+  pub enabled: bool,
+}
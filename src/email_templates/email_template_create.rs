@@ -0,0 +1,112 @@
+//! Create an email template.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, EmailTemplateName, EmailTemplateSyntax};
+
+/// Create an email template.
+///
+/// Send as [EmailTemplate](crate::EmailTemplate).
+///
+/// # Scopes
+/// * `create:email_templates`
+#[derive(Serialize)]
+pub struct EmailTemplateCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  template: EmailTemplateName,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  body: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  from: Option<String>,
+  #[serde(rename = "resultUrl")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  result_url: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  subject: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  syntax: Option<EmailTemplateSyntax>,
+  #[serde(rename = "urlLifetimeInSeconds")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  url_lifetime_in_seconds: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled: Option<bool>,
+}
+
+impl<'a> EmailTemplateCreate<'a> {
+  /// Create create email template request.
+  ///
+  /// # Arguments
+  /// * `template` - Template name.
+  pub fn new(client: &'a Auth0Client, template: EmailTemplateName) -> Self {
+    Self {
+      client,
+
+      template,
+      body: None,
+      from: None,
+      result_url: None,
+      subject: None,
+      syntax: None,
+      url_lifetime_in_seconds: None,
+      enabled: None,
+    }
+  }
+
+  /// Body of the email template.
+  pub fn body(mut self, body: &str) -> Self {
+    self.body = Some(body.to_owned());
+    self
+  }
+
+  /// Senders `from` email address.
+  pub fn from(mut self, from: &str) -> Self {
+    self.from = Some(from.to_owned());
+    self
+  }
+
+  /// URL to redirect the user to after a successful action.
+  pub fn result_url(mut self, result_url: &str) -> Self {
+    self.result_url = Some(result_url.to_owned());
+    self
+  }
+
+  /// Subject line of the email.
+  pub fn subject(mut self, subject: &str) -> Self {
+    self.subject = Some(subject.to_owned());
+    self
+  }
+
+  /// Syntax of the template body.
+  pub fn syntax(mut self, syntax: EmailTemplateSyntax) -> Self {
+    self.syntax = Some(syntax);
+    self
+  }
+
+  /// Lifetime in seconds that the link within the email will be valid for.
+  pub fn url_lifetime_in_seconds(mut self, url_lifetime_in_seconds: u32) -> Self {
+    self.url_lifetime_in_seconds = Some(url_lifetime_in_seconds);
+    self
+  }
+
+  /// Whether the template is enabled (true) or disabled (false).
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = Some(enabled);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for EmailTemplateCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for EmailTemplateCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/email-templates")
+      .json(self)
+  }
+}
@@ -0,0 +1,30 @@
+//! Retrieve an email template.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, EmailTemplateName};
+
+/// Retrieve an email template by pre-defined name.
+///
+/// # Scopes
+/// * `read:email_templates`
+pub struct EmailTemplateGet(EmailTemplateName);
+
+impl EmailTemplateGet {
+  /// Create get email template request.
+  ///
+  /// # Arguments
+  /// * `template` - Template name.
+  pub fn new(template: EmailTemplateName) -> Self {
+    Self(template)
+  }
+}
+
+impl Auth0RequestBuilder for EmailTemplateGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/email-templates/{}", path_segment(self.0.as_str())),
+    )
+  }
+}
@@ -0,0 +1,59 @@
+#![warn(missing_docs)]
+//! Email template request builders.
+
+#[doc(inline)]
+pub use email_template::*;
+#[doc(inline)]
+pub use email_template_create::*;
+#[doc(inline)]
+pub use email_template_get::*;
+#[doc(inline)]
+pub use email_template_patch::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod email_template;
+pub mod email_template_create;
+pub mod email_template_get;
+pub mod email_template_patch;
+
+/// Email templates manager
+pub struct EmailTemplatesManager(Arc<Auth0Client>);
+
+impl EmailTemplatesManager {
+  /// Create email templates manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create an email template.
+  ///
+  /// # Arguments
+  /// * `template` - Template name.
+  /// # Scopes
+  /// * `create:email_templates`
+  pub fn create(&self, template: EmailTemplateName) -> EmailTemplateCreate<'_> {
+    EmailTemplateCreate::new(&self.0, template)
+  }
+
+  /// Retrieve an email template by pre-defined name.
+  ///
+  /// # Arguments
+  /// * `template` - Template name.
+  /// # Scopes
+  /// * `read:email_templates`
+  pub async fn get(&self, template: EmailTemplateName) -> Auth0Result<EmailTemplate> {
+    EmailTemplateGet::new(template).send_to(&self.0).await
+  }
+
+  /// Modify an email template.
+  ///
+  /// # Arguments
+  /// * `template` - Template name.
+  /// # Scopes
+  /// * `update:email_templates`
+  pub fn patch(&self, template: EmailTemplateName) -> EmailTemplatePatch<'_> {
+    EmailTemplatePatch::new(&self.0, template)
+  }
+}
@@ -0,0 +1,100 @@
+//! Email provider
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+
+/// Name of an email provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum EmailProviderName {
+  /// SMTP server.
+  Smtp,
+  /// Mandrill.
+  Mandrill,
+  /// Mailgun.
+  Mailgun,
+  /// SendGrid.
+  Sendgrid,
+  /// SparkPost.
+  Sparkpost,
+  /// Amazon SES.
+  Ses,
+  /// Azure Communication Services.
+  AzureCs,
+  /// Microsoft 365.
+  Ms365,
+  /// Resend.
+  Resend,
+  /// Custom email provider implemented with an action.
+  Custom,
+  /// An email provider not known to this crate.
+  Unknown(String),
+}
+
+impl EmailProviderName {
+  /// The value as used by the Auth0 API.
+  pub fn as_str(&self) -> &str {
+    match self {
+      EmailProviderName::Smtp => "smtp",
+      EmailProviderName::Mandrill => "mandrill",
+      EmailProviderName::Mailgun => "mailgun",
+      EmailProviderName::Sendgrid => "sendgrid",
+      EmailProviderName::Sparkpost => "sparkpost",
+      EmailProviderName::Ses => "ses",
+      EmailProviderName::AzureCs => "azure_cs",
+      EmailProviderName::Ms365 => "ms365",
+      EmailProviderName::Resend => "resend",
+      EmailProviderName::Custom => "custom",
+      EmailProviderName::Unknown(value) => value,
+    }
+  }
+}
+
+impl From<String> for EmailProviderName {
+  fn from(value: String) -> Self {
+    match value.as_str() {
+      "smtp" => EmailProviderName::Smtp,
+      "mandrill" => EmailProviderName::Mandrill,
+      "mailgun" => EmailProviderName::Mailgun,
+      "sendgrid" => EmailProviderName::Sendgrid,
+      "sparkpost" => EmailProviderName::Sparkpost,
+      "ses" => EmailProviderName::Ses,
+      "azure_cs" => EmailProviderName::AzureCs,
+      "ms365" => EmailProviderName::Ms365,
+      "resend" => EmailProviderName::Resend,
+      "custom" => EmailProviderName::Custom,
+      _ => EmailProviderName::Unknown(value),
+    }
+  }
+}
+
+impl From<EmailProviderName> for String {
+  fn from(value: EmailProviderName) -> Self {
+    value.as_str().to_owned()
+  }
+}
+
+impl Display for EmailProviderName {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Email provider.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailProvider {
+  /// Name of the email provider.
+  pub name: EmailProviderName,
+  /// Whether the provider is enabled (true) or disabled (false).
+  #[serde(default)]
+  pub enabled: bool,
+  /// Email address to use as "from" when no other address specified.
+  #[serde(default)]
+  pub default_from_address: String,
+  /// Provider specific credentials, e.g. `api_key` and `domain` for Mailgun.
+  #[serde(default)]
+  pub credentials: Value,
+  /// Provider specific settings.
+  #[serde(default)]
+  pub settings: Value,
+}
@@ -0,0 +1,25 @@
+//! Delete the email provider.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete the email provider configuration, resetting the tenant to Auth0's built-in
+/// email provider.
+///
+/// # Scopes
+/// * `delete:email_provider`
+#[derive(Default)]
+pub struct EmailProviderDelete;
+
+impl EmailProviderDelete {
+  /// Create delete email provider request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for EmailProviderDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::DELETE, "api/v2/emails/provider")
+  }
+}
@@ -0,0 +1,24 @@
+//! Retrieve the email provider.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve details of the email provider configuration in your tenant.
+///
+/// # Scopes
+/// * `read:email_provider`
+#[derive(Default)]
+pub struct EmailProviderGet;
+
+impl EmailProviderGet {
+  /// Create get email provider request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for EmailProviderGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/emails/provider")
+  }
+}
@@ -0,0 +1,88 @@
+//! Update the email provider.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{Auth0Client, Auth0RequestBuilder, EmailProviderName};
+
+/// Update the email provider configuration.
+///
+/// Send as [EmailProvider](crate::EmailProvider).
+///
+/// # Scopes
+/// * `update:email_provider`
+#[derive(Serialize)]
+pub struct EmailProviderUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<EmailProviderName>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  default_from_address: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  credentials: Option<Value>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  settings: Option<Value>,
+}
+
+impl<'a> EmailProviderUpdate<'a> {
+  /// Create update email provider request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      name: None,
+      enabled: None,
+      default_from_address: None,
+      credentials: None,
+      settings: None,
+    }
+  }
+
+  /// Name of the email provider.
+  pub fn name(mut self, name: EmailProviderName) -> Self {
+    self.name = Some(name);
+    self
+  }
+
+  /// Whether the provider is enabled (true) or disabled (false).
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = Some(enabled);
+    self
+  }
+
+  /// Email address to use as "from" when no other address specified.
+  pub fn default_from_address(mut self, default_from_address: &str) -> Self {
+    self.default_from_address = Some(default_from_address.to_owned());
+    self
+  }
+
+  /// Provider specific credentials, e.g. `{"api_key": "..."}` for Mandrill.
+  pub fn credentials(mut self, credentials: Value) -> Self {
+    self.credentials = Some(credentials);
+    self
+  }
+
+  /// Provider specific settings.
+  pub fn settings(mut self, settings: Value) -> Self {
+    self.settings = Some(settings);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for EmailProviderUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for EmailProviderUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::PATCH, "api/v2/emails/provider")
+      .json(self)
+  }
+}
@@ -0,0 +1,54 @@
+#![warn(missing_docs)]
+//! Email provider request builders.
+
+#[doc(inline)]
+pub use email_provider::*;
+#[doc(inline)]
+pub use email_provider_delete::*;
+#[doc(inline)]
+pub use email_provider_get::*;
+#[doc(inline)]
+pub use email_provider_update::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod email_provider;
+pub mod email_provider_delete;
+pub mod email_provider_get;
+pub mod email_provider_update;
+
+/// Emails manager
+pub struct EmailsManager(Arc<Auth0Client>);
+
+impl EmailsManager {
+  /// Create emails manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Delete the email provider configuration, resetting the tenant to Auth0's built-in
+  /// email provider.
+  ///
+  /// # Scopes
+  /// * `delete:email_provider`
+  pub async fn delete_provider(&self) -> Auth0Result<()> {
+    EmailProviderDelete::new().send_to(&self.0).await
+  }
+
+  /// Retrieve details of the email provider configuration in your tenant.
+  ///
+  /// # Scopes
+  /// * `read:email_provider`
+  pub async fn get_provider(&self) -> Auth0Result<EmailProvider> {
+    EmailProviderGet::new().send_to(&self.0).await
+  }
+
+  /// Update the email provider configuration.
+  ///
+  /// # Scopes
+  /// * `update:email_provider`
+  pub fn update_provider(&self) -> EmailProviderUpdate<'_> {
+    EmailProviderUpdate::new(&self.0)
+  }
+}
@@ -1,7 +1,9 @@
 //! Error type for auth0 requests.
+use chrono::{DateTime, Utc};
 use serde_json::Error as JsonError;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
 use crate::rate::RateLimitError;
 use crate::token::TokenError;
@@ -13,16 +15,58 @@ pub type Auth0Result<T> = Result<T, Auth0Error>;
 /// The error returned when querying Auth0.
 #[derive(Debug)]
 pub enum Auth0Error {
-  /// Json error
+  /// Json error, e.g. a response body that could not be deserialized.
   Json(JsonError),
   /// Generic http error.
   Http(reqwest::Error),
+  /// IO error, e.g. while writing an export.
+  Io(std::io::Error),
   /// Authentication token error.
   Token(TokenError),
   /// Auth0 server side error.
   Auth0(String),
+  /// Error response returned by the Auth0 API.
+  Api {
+    /// HTTP status code.
+    status: u16,
+    /// HTTP status text, e.g. `Bad Request`.
+    error: String,
+    /// Description of the error.
+    message: String,
+    /// Machine readable error code, when Auth0 provides one.
+    code: Option<ErrorCode>,
+  },
   /// Auth0 rate limit error.
   RateLimit(RateLimitError),
+  /// Auth0 rejected the request with `429 Too Many Requests`.
+  RateLimited {
+    /// How long to wait before retrying, from the `Retry-After` header.
+    retry_after: Option<Duration>,
+    /// Maximum number of requests allowed, from the `X-RateLimit-Limit` header.
+    limit: Option<u64>,
+    /// When the limit resets, from the `X-RateLimit-Reset` header.
+    reset: Option<DateTime<Utc>>,
+  },
+}
+
+impl Auth0Error {
+  /// The Auth0 error code of an [Auth0Error::Api] error.
+  pub fn error_code(&self) -> Option<&ErrorCode> {
+    match self {
+      Auth0Error::Api { code, .. } => code.as_ref(),
+      _ => None,
+    }
+  }
+
+  /// Whether the API reported that the user does not exist.
+  pub fn is_user_not_found(&self) -> bool {
+    self.error_code() == Some(&ErrorCode::UserNotFound)
+  }
+
+  /// Whether the management API token lacks a scope required by the request.
+  pub fn is_insufficient_scope(&self) -> bool {
+    self.error_code() == Some(&ErrorCode::InsufficientScope)
+  }
 }
 
 impl Display for Auth0Error {
@@ -39,6 +83,12 @@ impl From<JsonError> for Auth0Error {
   }
 }
 
+impl From<std::io::Error> for Auth0Error {
+  fn from(inner: std::io::Error) -> Self {
+    Auth0Error::Io(inner)
+  }
+}
+
 impl From<TokenError> for Auth0Error {
   fn from(inner: TokenError) -> Self {
     Auth0Error::Token(inner)
@@ -57,14 +107,103 @@ impl From<RateLimitError> for Auth0Error {
   }
 }
 
-/// Auth0 error response.
+/// Auth0 error response, e.g.
+/// `{"statusCode": 400, "error": "Bad Request", "message": "Invalid strategy"}`.
+///
+/// Some endpoints omit `statusCode` or send `error_description` instead of `message`,
+/// both are optional.
 #[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Auth0ErrorResponse {
-  message: Option<String>,
+  #[serde(default)]
+  status_code: u16,
+  #[serde(default)]
+  error: String,
+  #[serde(default)]
+  message: String,
+  #[serde(default, rename = "error_description")]
+  error_description: String,
+  error_code: Option<ErrorCode>,
+}
+
+impl Auth0ErrorResponse {
+  /// Use `status` when the response body did not include a status code.
+  pub(crate) fn with_status(mut self, status: u16) -> Self {
+    if self.status_code == 0 {
+      self.status_code = status;
+    }
+    self
+  }
 }
 
 impl From<Auth0ErrorResponse> for Auth0Error {
   fn from(inner: Auth0ErrorResponse) -> Self {
-    Auth0Error::Auth0(inner.message.unwrap_or_else(|| "".to_owned()))
+    let message = if inner.message.is_empty() {
+      inner.error_description
+    } else {
+      inner.message
+    };
+
+    Auth0Error::Api {
+      status: inner.status_code,
+      error: inner.error,
+      message,
+      code: inner.error_code,
+    }
+  }
+}
+
+/// Auth0 API error code, the `errorCode` field of an error response.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum ErrorCode {
+  /// `inexistent_user`, the user does not exist.
+  UserNotFound,
+  /// `invalid_body`, the request body failed validation.
+  InvalidBody,
+  /// `invalid_query_string`, the query string failed validation.
+  InvalidQueryString,
+  /// `insufficient_scope`, the token lacks a required scope.
+  InsufficientScope,
+  /// `too_many_requests`, the rate limit was exceeded.
+  TooManyRequests,
+  /// `operation_not_supported`, the operation is not supported, e.g. on this connection.
+  OperationNotSupported,
+  /// Any other error code.
+  Unknown(String),
+}
+
+impl ErrorCode {
+  /// The error code as sent by Auth0.
+  pub fn as_str(&self) -> &str {
+    match self {
+      ErrorCode::UserNotFound => "inexistent_user",
+      ErrorCode::InvalidBody => "invalid_body",
+      ErrorCode::InvalidQueryString => "invalid_query_string",
+      ErrorCode::InsufficientScope => "insufficient_scope",
+      ErrorCode::TooManyRequests => "too_many_requests",
+      ErrorCode::OperationNotSupported => "operation_not_supported",
+      ErrorCode::Unknown(code) => code,
+    }
+  }
+}
+
+impl From<String> for ErrorCode {
+  fn from(code: String) -> Self {
+    match code.as_str() {
+      "inexistent_user" | "user_not_found" => ErrorCode::UserNotFound,
+      "invalid_body" => ErrorCode::InvalidBody,
+      "invalid_query_string" => ErrorCode::InvalidQueryString,
+      "insufficient_scope" => ErrorCode::InsufficientScope,
+      "too_many_requests" => ErrorCode::TooManyRequests,
+      "operation_not_supported" => ErrorCode::OperationNotSupported,
+      _ => ErrorCode::Unknown(code),
+    }
+  }
+}
+
+impl Display for ErrorCode {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
   }
 }
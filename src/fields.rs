@@ -0,0 +1,41 @@
+//! Field projection helper.
+use serde::Serialize;
+
+/// Provides serializable field projection parameters.
+#[derive(Serialize, Clone, Default)]
+pub struct Fields {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  fields: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  include_fields: Option<bool>,
+}
+
+impl Fields {
+  /// Create field projection instance.
+  pub fn new() -> Self {
+    Default::default()
+  }
+}
+
+/// Build field projection parameters.
+pub trait Projectable {
+  /// Fields to include or exclude (based on `include_fields`) in the result.  Leave empty
+  /// to retrieve all fields.
+  fn fields(self, fields: &[&str]) -> Self;
+  /// Whether specified fields are to be included (true) or excluded (false).  Defaults to
+  /// true.
+  fn include_fields(self, include_fields: bool) -> Self;
+}
+
+impl<F: AsMut<Fields>> Projectable for F {
+  fn fields(mut self, fields: &[&str]) -> Self {
+    // Auth0 expects a single comma separated value rather than a repeated parameter.
+    self.as_mut().fields = Some(fields.join(","));
+    self
+  }
+
+  fn include_fields(mut self, include_fields: bool) -> Self {
+    self.as_mut().include_fields = Some(include_fields);
+    self
+  }
+}
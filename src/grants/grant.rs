@@ -0,0 +1,22 @@
+//! Grant types.
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Scopes a user consented to grant an application for an API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Grant {
+  /// ID of the grant.
+  pub id: String,
+  /// ID of the client the grant was given to.
+  #[serde(rename = "clientID")]
+  pub client_id: String,
+  /// ID of the user who gave the grant.
+  pub user_id: String,
+  /// Audience of the API the grant is for.
+  pub audience: String,
+  /// Scopes included in the grant.
+  #[serde(default)]
+  pub scope: Vec<String>,
+  /// The time when the grant was given.
+  pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,30 @@
+//! Delete a grant.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete a grant, revoking the consent a user gave to an application.
+///
+/// # Scopes
+/// * `delete:grants`
+pub struct GrantDelete(String);
+
+impl GrantDelete {
+  /// Create delete grant request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the grant to delete.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for GrantDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/grants/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,73 @@
+//! Retrieve grants.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the grants given by a user.
+///
+/// Send as `Vec<Grant>`.
+///
+/// # Scopes
+/// * `read:grants`
+#[derive(Serialize)]
+pub struct GrantsGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  user_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  client_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  audience: Option<String>,
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> GrantsGet<'a> {
+  /// Create get grants request.
+  ///
+  /// # Arguments
+  /// * `user_id` - The ID of the user whose grants to retrieve.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, user_id: S) -> Self {
+    Self {
+      client,
+
+      user_id: user_id.as_ref().to_string(),
+      client_id: None,
+      audience: None,
+      page: Default::default(),
+    }
+  }
+
+  /// Only retrieve grants given to this client.
+  pub fn client_id(mut self, client_id: &str) -> Self {
+    self.client_id = Some(client_id.to_owned());
+    self
+  }
+
+  /// Only retrieve grants for this API audience.
+  pub fn audience(mut self, audience: &str) -> Self {
+    self.audience = Some(audience.to_owned());
+    self
+  }
+}
+
+impl<'a> AsMut<Page> for GrantsGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for GrantsGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for GrantsGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/grants").query(&self)
+  }
+}
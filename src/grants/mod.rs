@@ -0,0 +1,46 @@
+#![warn(missing_docs)]
+//! Grant request builders.
+
+#[doc(inline)]
+pub use grant::*;
+#[doc(inline)]
+pub use grant_delete::*;
+#[doc(inline)]
+pub use grants_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod grant;
+pub mod grant_delete;
+pub mod grants_get;
+
+/// Grants manager
+pub struct GrantsManager(Arc<Auth0Client>);
+
+impl GrantsManager {
+  /// Create grants manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Delete a grant, revoking the consent a user gave to an application.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the grant to delete.
+  /// # Scopes
+  /// * `delete:grants`
+  pub async fn delete<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    GrantDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve the grants given by a user.
+  ///
+  /// # Arguments
+  /// * `user_id` - The ID of the user whose grants to retrieve.
+  /// # Scopes
+  /// * `read:grants`
+  pub fn list<S: AsRef<str>>(&self, user_id: S) -> GrantsGet<'_> {
+    GrantsGet::new(&self.0, user_id)
+  }
+}
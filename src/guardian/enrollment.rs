@@ -0,0 +1,57 @@
+//! Multi-factor enrollment.
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Status of a multi-factor enrollment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EnrollmentStatus {
+  /// Enrollment has been started but not completed.
+  Pending,
+  /// Enrollment has been completed.
+  Confirmed,
+}
+
+/// Type of a multi-factor enrollment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EnrollmentType {
+  /// SMS enrollment.
+  Sms,
+  /// Push notification enrollment.
+  Push,
+  /// Email enrollment.
+  Email,
+  /// One-time password enrollment.
+  Otp,
+  /// Recovery code enrollment.
+  RecoveryCode,
+  /// An enrollment type not known to this crate.
+  #[serde(other)]
+  Unknown,
+}
+
+/// Multi-factor enrollment.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Enrollment {
+  /// ID of this enrollment.
+  pub id: String,
+  /// Status of this enrollment.
+  pub status: EnrollmentStatus,
+  /// Type of enrollment.
+  #[serde(rename = "type")]
+  pub kind: EnrollmentType,
+  /// Name of enrollment (usually phone number).
+  pub name: Option<String>,
+  /// Device identifier (usually phone identifier) of this enrollment.
+  pub identifier: Option<String>,
+  /// Phone number for this enrollment.
+  pub phone_number: Option<String>,
+  /// Authentication method for this enrollment. Can be `authentication`, `guardian`, or
+  /// `sms`.
+  pub auth_method: Option<String>,
+  /// Start date and time of this enrollment.
+  pub enrolled_at: Option<DateTime<Utc>>,
+  /// Last authentication date and time of this enrollment.
+  pub last_auth: Option<DateTime<Utc>>,
+}
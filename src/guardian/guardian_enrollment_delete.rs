@@ -0,0 +1,27 @@
+//! Delete a multi-factor enrollment.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete an enrollment to allow the user to enroll with multi-factor authentication again.
+///
+/// # Scopes
+/// * `delete:guardian_enrollments`
+pub struct GuardianEnrollmentDelete(String);
+
+impl GuardianEnrollmentDelete {
+  /// Create delete enrollment request.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for GuardianEnrollmentDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/guardian/enrollments/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,57 @@
+//! Retrieve a multi-factor enrollment.
+use chrono::{DateTime, Utc};
+use reqwest::{Method, RequestBuilder};
+use serde::Deserialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, EnrollmentStatus};
+
+/// Multi-factor enrollment fetched by its own ID.
+///
+/// Unlike [Enrollment](crate::Enrollment), which is listed per user, this does not
+/// include the enrollment type or authentication method.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuardianEnrollment {
+  /// ID of this enrollment.
+  pub id: String,
+  /// Status of this enrollment.
+  pub status: EnrollmentStatus,
+  /// Name of enrollment (usually phone number).
+  pub name: Option<String>,
+  /// Device identifier (usually phone identifier) of this enrollment.
+  pub identifier: Option<String>,
+  /// Phone number for this enrollment.
+  pub phone_number: Option<String>,
+  /// Start date and time of this enrollment.
+  pub enrolled_at: Option<DateTime<Utc>>,
+  /// Last authentication date and time of this enrollment.
+  pub last_auth: Option<DateTime<Utc>>,
+}
+
+/// Retrieve an enrollment (including its status) by enrollment ID.  Useful for
+/// helpdesk tools that store enrollment IDs rather than user IDs.
+///
+/// Send as [GuardianEnrollment].
+///
+/// # Scopes
+/// * `read:guardian_enrollments`
+pub struct GuardianEnrollmentGet(String);
+
+impl GuardianEnrollmentGet {
+  /// Create get enrollment request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the enrollment.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for GuardianEnrollmentGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/guardian/enrollments/{}", path_segment(&self.0)),
+    )
+  }
+}
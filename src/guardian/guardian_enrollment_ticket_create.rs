@@ -0,0 +1,65 @@
+//! Create a multi-factor enrollment ticket.
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Multi-factor enrollment ticket.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuardianTicket {
+  /// The ticket ID.
+  pub ticket_id: String,
+  /// The URL the user should visit to enroll.
+  pub ticket_url: String,
+}
+
+/// Create a multi-factor authentication enrollment ticket for a user.  The returned
+/// `ticket_url` can be sent to the user in a custom email or returned from your own API.
+///
+/// # Scopes
+/// * `create:guardian_enrollment_tickets`
+#[derive(Serialize)]
+pub struct GuardianEnrollmentTicketCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  user_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  email: Option<String>,
+}
+
+impl<'a> GuardianEnrollmentTicketCreate<'a> {
+  /// Create enrollment ticket request.
+  ///
+  /// # Arguments
+  /// * `user_id` - ID of the user the ticket is created for.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, user_id: S) -> Self {
+    Self {
+      client,
+
+      user_id: user_id.as_ref().to_string(),
+      email: None,
+    }
+  }
+
+  /// Alternate email to which the enrollment email will be sent.  Defaults to the user's
+  /// email.
+  pub fn email(mut self, email: &str) -> Self {
+    self.email = Some(email.to_owned());
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for GuardianEnrollmentTicketCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for GuardianEnrollmentTicketCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/guardian/enrollments/ticket")
+      .json(self)
+  }
+}
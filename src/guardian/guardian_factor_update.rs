@@ -0,0 +1,49 @@
+//! Update a multi-factor authentication factor.
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, FactorName};
+
+/// Status of a multi-factor authentication factor.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuardianFactorStatus {
+  /// Whether this factor is enabled.
+  pub enabled: bool,
+}
+
+/// Enable or disable a multi-factor authentication factor.
+///
+/// # Scopes
+/// * `update:guardian_factors`
+#[derive(Serialize)]
+pub struct GuardianFactorUpdate {
+  #[serde(skip_serializing)]
+  name: FactorName,
+  enabled: bool,
+}
+
+impl GuardianFactorUpdate {
+  /// Create update factor request.
+  ///
+  /// # Arguments
+  /// * `name` - Factor to update.
+  /// * `enabled` - Whether the factor should be enabled.
+  pub fn new(name: FactorName, enabled: bool) -> Self {
+    Self { name, enabled }
+  }
+}
+
+impl Auth0RequestBuilder for GuardianFactorUpdate {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PUT,
+        &format!(
+          "api/v2/guardian/factors/{}",
+          path_segment(self.name.as_str())
+        ),
+      )
+      .json(self)
+  }
+}
@@ -0,0 +1,82 @@
+//! Retrieve multi-factor authentication factors.
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Name of a multi-factor authentication factor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FactorName {
+  /// SMS one-time codes.
+  Sms,
+  /// Push notifications via Guardian.
+  PushNotification,
+  /// One-time passwords from an authenticator app.
+  Otp,
+  /// Email one-time codes.
+  Email,
+  /// Duo Security.
+  Duo,
+  /// WebAuthn with security keys.
+  WebauthnRoaming,
+  /// WebAuthn with device biometrics.
+  WebauthnPlatform,
+  /// Recovery codes.
+  RecoveryCode,
+}
+
+impl FactorName {
+  /// Factor name as used by the Auth0 API.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Sms => "sms",
+      Self::PushNotification => "push-notification",
+      Self::Otp => "otp",
+      Self::Email => "email",
+      Self::Duo => "duo",
+      Self::WebauthnRoaming => "webauthn-roaming",
+      Self::WebauthnPlatform => "webauthn-platform",
+      Self::RecoveryCode => "recovery-code",
+    }
+  }
+}
+
+impl Display for FactorName {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Multi-factor authentication factor.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuardianFactor {
+  /// Factor name.
+  pub name: FactorName,
+  /// Whether this factor is enabled.
+  pub enabled: bool,
+  /// Whether trial limits have been exceeded.
+  pub trial_expired: Option<bool>,
+}
+
+/// Retrieve details of all multi-factor authentication factors associated with your
+/// tenant.
+///
+/// # Scopes
+/// * `read:guardian_factors`
+#[derive(Default)]
+pub struct GuardianFactorsGet;
+
+impl GuardianFactorsGet {
+  /// Create get factors request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for GuardianFactorsGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/guardian/factors")
+  }
+}
@@ -0,0 +1,98 @@
+#![warn(missing_docs)]
+//! Guardian request builders.
+
+#[doc(inline)]
+pub use enrollment::*;
+#[doc(inline)]
+pub use guardian_enrollment_delete::*;
+#[doc(inline)]
+pub use guardian_enrollment_get::*;
+#[doc(inline)]
+pub use guardian_enrollment_ticket_create::*;
+#[doc(inline)]
+pub use guardian_factor_update::*;
+#[doc(inline)]
+pub use guardian_factors_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod enrollment;
+pub mod guardian_enrollment_delete;
+pub mod guardian_enrollment_get;
+pub mod guardian_enrollment_ticket_create;
+pub mod guardian_factor_update;
+pub mod guardian_factors_get;
+
+/// Guardian manager
+pub struct GuardianManager(Arc<Auth0Client>);
+
+impl GuardianManager {
+  /// Create guardian manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a multi-factor authentication enrollment ticket for a user.
+  ///
+  /// # Arguments
+  /// * `user_id` - ID of the user the ticket is created for.
+  /// # Scopes
+  /// * `create:guardian_enrollment_tickets`
+  pub fn create_enrollment_ticket<S: AsRef<str>>(
+    &self,
+    user_id: S,
+  ) -> GuardianEnrollmentTicketCreate<'_> {
+    GuardianEnrollmentTicketCreate::new(&self.0, user_id)
+  }
+
+  /// Delete an enrollment to allow the user to enroll with multi-factor authentication
+  /// again.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the enrollment to be deleted.
+  /// # Scopes
+  /// * `delete:guardian_enrollments`
+  pub async fn delete_enrollment<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    GuardianEnrollmentDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve an enrollment (including its status) by enrollment ID.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the enrollment to be retrieved.
+  /// # Scopes
+  /// * `read:guardian_enrollments`
+  pub async fn get_enrollment<S: AsRef<str>>(
+    &self,
+    id: S,
+  ) -> Auth0Result<GuardianEnrollment> {
+    GuardianEnrollmentGet::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve details of all multi-factor authentication factors associated with your
+  /// tenant.
+  ///
+  /// # Scopes
+  /// * `read:guardian_factors`
+  pub async fn get_factors(&self) -> Auth0Result<Vec<GuardianFactor>> {
+    GuardianFactorsGet::new().send_to(&self.0).await
+  }
+
+  /// Enable or disable a multi-factor authentication factor.
+  ///
+  /// # Arguments
+  /// * `name` - Factor to update.
+  /// * `enabled` - Whether the factor should be enabled.
+  /// # Scopes
+  /// * `update:guardian_factors`
+  pub async fn update_factor(
+    &self,
+    name: FactorName,
+    enabled: bool,
+  ) -> Auth0Result<GuardianFactorStatus> {
+    GuardianFactorUpdate::new(name, enabled)
+      .send_to(&self.0)
+      .await
+  }
+}
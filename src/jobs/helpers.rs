@@ -0,0 +1,57 @@
+//! High-level helpers built on top of the job request builders.
+//!
+//! # Exporting to S3
+//! Auth0 cannot write an export directly to an S3 bucket.  A finished export job is
+//! stored by Auth0 and [Job::location](crate::Job::location) holds a short-lived signed
+//! URL to a gzipped file.  To land an export at `s3://<bucket>/<key>`, run
+//! [export_users] and stream the file at `location` into the bucket with the S3 client of
+//! your choice before the URL expires.
+use std::time::Duration;
+
+use crate::{Auth0, Auth0Error, Auth0Request, Auth0Result, ExportField, Job, JobGet};
+
+/// How long to wait between polls of a running export job.
+const EXPORT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long to wait for an export job to finish before giving up.
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Export the users of a connection and wait for the export job to complete.
+///
+/// Returns the completed job, whose [location](crate::Job::location) points at the
+/// exported file.  Returns an error if the job fails or is still running after an hour.
+///
+/// # Arguments
+/// * `auth0` - The management client.
+/// * `connection_id` - ID of the connection to export users from.
+/// * `fields` - User fields to include in the export, all fields are exported when empty.
+/// # Scopes
+/// * `read:users`
+pub async fn export_users(
+  auth0: &Auth0,
+  connection_id: &str,
+  fields: &[ExportField],
+) -> Auth0Result<Job> {
+  let mut export = auth0.jobs.export_users().connection_id(connection_id);
+
+  for field in fields {
+    export = match &field.export_as {
+      Some(export_as) => export.field_as(&field.name, export_as),
+      None => export.field(&field.name),
+    };
+  }
+
+  let job: Job = export.send().await?;
+  let job = JobGet::new(&job.id)
+    .wait_until_done(&auth0.client, EXPORT_POLL_INTERVAL, EXPORT_TIMEOUT)
+    .await?;
+
+  if job.is_successful() {
+    Ok(job)
+  } else {
+    Err(Auth0Error::Auth0(format!(
+      "Users export job {} failed.",
+      job.id
+    )))
+  }
+}
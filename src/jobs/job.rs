@@ -0,0 +1,70 @@
+//! Job
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer};
+
+/// Status of a job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobStatus {
+  /// Job is queued.
+  Pending,
+  /// Job is running.
+  Processing,
+  /// Job finished successfully.
+  Completed,
+  /// Job failed.
+  Failed,
+  /// A status not known to this crate.
+  Unknown(String),
+}
+
+impl<'de> Deserialize<'de> for JobStatus {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let status = String::deserialize(deserializer)?;
+
+    Ok(match status.as_str() {
+      "pending" => Self::Pending,
+      "processing" => Self::Processing,
+      "completed" => Self::Completed,
+      "failed" => Self::Failed,
+      _ => Self::Unknown(status),
+    })
+  }
+}
+
+/// Bulk user import or export job.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Job {
+  /// ID of the job.
+  pub id: String,
+  /// Type of job this is, e.g. `users_export` or `users_import`.
+  #[serde(rename = "type")]
+  pub kind: String,
+  /// Status of this job.
+  pub status: JobStatus,
+  /// When this job was created.
+  pub created_at: DateTime<Utc>,
+  /// When this job started processing.
+  pub started_at: Option<DateTime<Utc>>,
+  /// When this job finished.
+  pub completed_at: Option<DateTime<Utc>>,
+  /// ID of the connection this job uses.
+  pub connection_id: Option<String>,
+  /// URL to download the result of an export job.
+  pub location: Option<String>,
+  /// Completion percentage of an export job.
+  pub percentage_done: Option<u32>,
+  /// Estimated time remaining before the job completes.
+  pub time_left_seconds: Option<u32>,
+}
+
+impl Job {
+  /// True once the job has stopped running, whether it succeeded or not.
+  pub fn is_terminal(&self) -> bool {
+    matches!(self.status, JobStatus::Completed | JobStatus::Failed)
+  }
+
+  /// True if the job completed successfully.
+  pub fn is_successful(&self) -> bool {
+    self.status == JobStatus::Completed
+  }
+}
@@ -0,0 +1,55 @@
+//! Retrieve the errors of a job.
+use reqwest::{Method, RequestBuilder};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Validation error for a single field of an imported user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobErrorDetail {
+  /// Error code.
+  pub code: String,
+  /// Description of the error.
+  pub message: String,
+  /// Path of the field the error relates to.
+  pub path: Option<String>,
+}
+
+/// User record rejected by an import job.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobError {
+  /// The user record as submitted.  May not conform to any typed schema.
+  pub user: Value,
+  /// Reasons the record was rejected.
+  pub errors: Vec<JobErrorDetail>,
+}
+
+/// Retrieve error details of a failed job.
+///
+/// Send as `Option<Vec<JobError>>`; Auth0 returns no body when the job has no errors.
+///
+/// # Scopes
+/// * `create:users`
+/// * `read:users`
+pub struct JobErrorsGet(String);
+
+impl JobErrorsGet {
+  /// Create get job errors request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the job.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for JobErrorsGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/jobs/{}/errors", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,69 @@
+//! Retrieve a job.
+use std::time::Duration;
+
+use reqwest::{Method, RequestBuilder};
+use tokio::time::Instant;
+
+use crate::client::path_segment;
+use crate::{
+  Auth0Client, Auth0Error, Auth0RequestBuilder, Auth0RequestSimple, Auth0Result, Job,
+};
+
+/// Retrieves a job.  Useful to check its status.
+///
+/// # Scopes
+/// * `create:users`
+/// * `read:users`
+pub struct JobGet(String);
+
+impl JobGet {
+  /// Create get job request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the job.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+
+  /// Poll the job every `interval` until it has either completed or failed, then return
+  /// the final job.  A failed job is returned as-is, check
+  /// [Job::is_successful](crate::Job::is_successful) to tell the two apart.  Returns an
+  /// error if the job is still running after `timeout`.
+  ///
+  /// # Arguments
+  /// * `client` - The client used to poll the job.
+  /// * `interval` - How long to wait between polls.
+  /// * `timeout` - How long to wait for the job to finish.
+  pub async fn wait_until_done(
+    &self,
+    client: &Auth0Client,
+    interval: Duration,
+    timeout: Duration,
+  ) -> Auth0Result<Job> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+      let job: Job = self.send_to(client).await?;
+      if job.is_terminal() {
+        return Ok(job);
+      }
+      if Instant::now() + interval > deadline {
+        return Err(Auth0Error::Auth0(format!(
+          "Job {} did not finish within {:?}.",
+          job.id, timeout
+        )));
+      }
+
+      tokio::time::sleep(interval).await;
+    }
+  }
+}
+
+impl Auth0RequestBuilder for JobGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/jobs/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,110 @@
+//! Create a users export job.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Format of a users export file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+  /// Newline delimited JSON.
+  Json,
+  /// Comma separated values.
+  Csv,
+}
+
+/// User field to include in an export.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportField {
+  /// Name of the field in the profile.
+  pub name: String,
+  /// Title of the column in the exported CSV.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub export_as: Option<String>,
+}
+
+/// Export all users to a file via a long-running job.
+///
+/// Send as [Job](crate::Job) and poll [JobGet](crate::JobGet) until it completes.
+///
+/// # Scopes
+/// * `read:users`
+#[derive(Serialize)]
+pub struct JobUsersExport<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  connection_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  format: Option<ExportFormat>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  limit: Option<u32>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  fields: Vec<ExportField>,
+}
+
+impl<'a> JobUsersExport<'a> {
+  /// Create users export job request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      connection_id: None,
+      format: None,
+      limit: None,
+      fields: Vec::new(),
+    }
+  }
+
+  /// Connection ID of the connection from which users will be exported.
+  pub fn connection_id(mut self, connection_id: &str) -> Self {
+    self.connection_id = Some(connection_id.to_owned());
+    self
+  }
+
+  /// Format of the file.  Defaults to [ExportFormat::Json].
+  pub fn format(mut self, format: ExportFormat) -> Self {
+    self.format = Some(format);
+    self
+  }
+
+  /// Limit the number of records exported.
+  pub fn limit(mut self, limit: u32) -> Self {
+    self.limit = Some(limit);
+    self
+  }
+
+  /// Add a user field to include in the export.
+  pub fn field(mut self, name: &str) -> Self {
+    self.fields.push(ExportField {
+      name: name.to_owned(),
+      export_as: None,
+    });
+    self
+  }
+
+  /// Add a user field to include in the export under a different column title.
+  pub fn field_as(mut self, name: &str, export_as: &str) -> Self {
+    self.fields.push(ExportField {
+      name: name.to_owned(),
+      export_as: Some(export_as.to_owned()),
+    });
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for JobUsersExport<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for JobUsersExport<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/jobs/users-exports")
+      .json(self)
+  }
+}
@@ -0,0 +1,141 @@
+//! Create a users import job.
+use reqwest::multipart::{Form, Part};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{Auth0Client, Auth0Result, Job, MultipartAuth0RequestBuilder};
+
+/// User record in a users import file.
+///
+/// See [bulk user import database schema](https://auth0.com/docs/manage-users/user-migration/bulk-user-import-database-schema-and-examples)
+/// for details on each field.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportUser {
+  /// The user's email address.
+  pub email: String,
+  /// Whether the user's email address has been verified.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email_verified: Option<bool>,
+  /// The user's unique identifier.  Prefixed with the connection strategy on import.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub user_id: Option<String>,
+  /// The user's username.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub username: Option<String>,
+  /// The user's given name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub given_name: Option<String>,
+  /// The user's family name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub family_name: Option<String>,
+  /// The user's full name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The user's nickname.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nickname: Option<String>,
+  /// URL pointing to the user's profile picture.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub picture: Option<String>,
+  /// Whether the user is blocked.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub blocked: Option<bool>,
+  /// Hashed password and algorithm, in the format documented by Auth0.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_password_hash: Option<Value>,
+  /// Data that the user has read-only access to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub app_metadata: Option<Value>,
+  /// Data that the user has read/write access to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub user_metadata: Option<Value>,
+}
+
+/// Import users from a JSON file into a connection via a long-running job.
+///
+/// # Scopes
+/// * `create:users`
+pub struct JobUsersImport<'a> {
+  client: &'a Auth0Client,
+
+  connection_id: String,
+  users: Vec<ImportUser>,
+  upsert: Option<bool>,
+  send_completion_email: Option<bool>,
+  external_id: Option<String>,
+}
+
+impl<'a> JobUsersImport<'a> {
+  /// Create users import job request.
+  ///
+  /// # Arguments
+  /// * `connection_id` - Connection ID of the connection to which users will be imported.
+  /// * `users` - Users to import.
+  pub fn new<S: AsRef<str>>(
+    client: &'a Auth0Client,
+    connection_id: S,
+    users: Vec<ImportUser>,
+  ) -> Self {
+    Self {
+      client,
+
+      connection_id: connection_id.as_ref().to_string(),
+      users,
+      upsert: None,
+      send_completion_email: None,
+      external_id: None,
+    }
+  }
+
+  /// Whether to update users if they already exist (true) or to ignore them (false).
+  pub fn upsert(mut self, upsert: bool) -> Self {
+    self.upsert = Some(upsert);
+    self
+  }
+
+  /// Whether to send a completion email to all tenant owners when the job is finished
+  /// (true) or not (false).
+  pub fn send_completion_email(mut self, send_completion_email: bool) -> Self {
+    self.send_completion_email = Some(send_completion_email);
+    self
+  }
+
+  /// Customer-defined ID.
+  pub fn external_id(mut self, external_id: &str) -> Self {
+    self.external_id = Some(external_id.to_owned());
+    self
+  }
+
+  /// Send
+  pub async fn send(&self) -> Auth0Result<Job> {
+    self.client.send_multipart(self).await
+  }
+}
+
+impl<'a> MultipartAuth0RequestBuilder for JobUsersImport<'a> {
+  fn path(&self) -> String {
+    "api/v2/jobs/users-imports".to_owned()
+  }
+
+  fn build_multipart(&self) -> Auth0Result<Form> {
+    let users = Part::bytes(serde_json::to_vec(&self.users)?)
+      .file_name("users.json")
+      .mime_str("application/json")?;
+
+    let mut form = Form::new()
+      .part("users", users)
+      .text("connection_id", self.connection_id.clone());
+
+    if let Some(upsert) = self.upsert {
+      form = form.text("upsert", upsert.to_string());
+    }
+    if let Some(send_completion_email) = self.send_completion_email {
+      form = form.text("send_completion_email", send_completion_email.to_string());
+    }
+    if let Some(external_id) = &self.external_id {
+      form = form.text("external_id", external_id.clone());
+    }
+
+    Ok(form)
+  }
+}
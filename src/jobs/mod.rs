@@ -0,0 +1,103 @@
+#![warn(missing_docs)]
+//! Job request builders.
+
+#[doc(inline)]
+pub use job::*;
+#[doc(inline)]
+pub use job_errors_get::*;
+#[doc(inline)]
+pub use job_get::*;
+#[doc(inline)]
+pub use job_users_export::*;
+#[doc(inline)]
+pub use job_users_import::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub mod helpers;
+pub mod job;
+pub mod job_errors_get;
+pub mod job_get;
+pub mod job_users_export;
+pub mod job_users_import;
+
+/// Jobs manager
+pub struct JobsManager(Arc<Auth0Client>);
+
+impl JobsManager {
+  /// Create jobs manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Export all users to a file via a long-running job.
+  ///
+  /// # Scopes
+  /// * `read:users`
+  pub fn export_users(&self) -> JobUsersExport<'_> {
+    JobUsersExport::new(&self.0)
+  }
+
+  /// Retrieve the user records rejected by an import job and the reasons they were
+  /// rejected.  Returns an empty list when the job has no errors.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the job.
+  /// # Scopes
+  /// * `create:users`
+  /// * `read:users`
+  pub async fn get_errors<S: AsRef<str>>(&self, id: S) -> Auth0Result<Vec<JobError>> {
+    let errors: Option<Vec<JobError>> = JobErrorsGet::new(id).send_to(&self.0).await?;
+
+    Ok(errors.unwrap_or_default())
+  }
+
+  /// Import users from a JSON file into a connection via a long-running job.
+  ///
+  /// # Arguments
+  /// * `connection_id` - Connection ID of the connection to which users will be imported.
+  /// * `users` - Users to import.
+  /// # Scopes
+  /// * `create:users`
+  pub fn import_users<S: AsRef<str>>(
+    &self,
+    connection_id: S,
+    users: Vec<ImportUser>,
+  ) -> JobUsersImport<'_> {
+    JobUsersImport::new(&self.0, connection_id, users)
+  }
+
+  /// Retrieves a job.  Useful to check its status.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the job.
+  /// # Scopes
+  /// * `create:users`
+  /// * `read:users`
+  pub async fn get<S: AsRef<str>>(&self, id: S) -> Auth0Result<Job> {
+    JobGet::new(id).send_to(&self.0).await
+  }
+
+  /// Poll a job every `interval` until it has either completed or failed.  Returns an
+  /// error if the job is still running after `timeout`.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the job.
+  /// * `interval` - How long to wait between polls.
+  /// * `timeout` - How long to wait for the job to finish.
+  /// # Scopes
+  /// * `create:users`
+  /// * `read:users`
+  pub async fn wait_until_done<S: AsRef<str>>(
+    &self,
+    id: S,
+    interval: Duration,
+    timeout: Duration,
+  ) -> Auth0Result<Job> {
+    JobGet::new(id)
+      .wait_until_done(&self.0, interval, timeout)
+      .await
+  }
+}
@@ -0,0 +1,78 @@
+#![warn(missing_docs)]
+//! Key request builders.
+
+#[doc(inline)]
+pub use signing_key::*;
+#[doc(inline)]
+pub use signing_key_get::*;
+#[doc(inline)]
+pub use signing_key_revoke::*;
+#[doc(inline)]
+pub use signing_key_rotate::*;
+#[doc(inline)]
+pub use signing_keys_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod signing_key;
+pub mod signing_key_get;
+pub mod signing_key_revoke;
+pub mod signing_key_rotate;
+pub mod signing_keys_get;
+
+/// Keys manager
+pub struct KeysManager(Arc<Auth0Client>);
+
+impl KeysManager {
+  /// Create keys manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve an application signing key by its key ID.
+  ///
+  /// # Arguments
+  /// * `kid` - The key ID of the key to retrieve.
+  /// # Scopes
+  /// * `read:signing_keys`
+  pub async fn get_signing_key<S: AsRef<str>>(&self, kid: S) -> Auth0Result<SigningKey> {
+    SigningKeyGet::new(kid).send_to(&self.0).await
+  }
+
+  /// Retrieve the application signing keys.
+  ///
+  /// # Scopes
+  /// * `read:signing_keys`
+  pub async fn get_signing_keys(&self) -> Auth0Result<Vec<SigningKey>> {
+    SigningKeysGet::new().send_to(&self.0).await
+  }
+
+  /// Revoke an application signing key.
+  ///
+  /// # Arguments
+  /// * `kid` - The key ID of the key to revoke.
+  /// # Scopes
+  /// * `update:signing_keys`
+  pub async fn revoke_signing_key<S: AsRef<str>>(
+    &self,
+    kid: S,
+  ) -> Auth0Result<SigningKeyCert> {
+    SigningKeyRevoke::new(kid).send_to(&self.0).await
+  }
+
+  /// Rotate the application signing key and return the new current key.
+  ///
+  /// The rotate endpoint only answers with the key ID and certificate, so the new key
+  /// is fetched afterwards.
+  ///
+  /// # Scopes
+  /// * `create:signing_keys`
+  /// * `update:signing_keys`
+  /// * `read:signing_keys`
+  pub async fn rotate_signing_key(&self) -> Auth0Result<SigningKey> {
+    let rotated: SigningKeyCert = SigningKeyRotate::new().send_to(&self.0).await?;
+
+    self.get_signing_key(rotated.kid).await
+  }
+}
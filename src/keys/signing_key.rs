@@ -0,0 +1,45 @@
+//! Signing key types.
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Key used to sign the tokens issued by the tenant.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SigningKey {
+  /// The key ID.
+  pub kid: String,
+  /// The public certificate in PEM format.
+  pub cert: String,
+  /// The public certificate in PKCS#7 format.
+  pub pkcs7: Option<String>,
+  /// Whether the key is currently used to sign tokens.
+  #[serde(default)]
+  pub current: bool,
+  /// Whether the key will be used once the current key is rotated.
+  #[serde(default)]
+  pub next: bool,
+  /// Whether the key was used before the current key.
+  #[serde(default)]
+  pub previous: bool,
+  /// The time when the key became the current key.
+  pub current_since: Option<DateTime<Utc>>,
+  /// The time when the key stopped being the current key.
+  pub current_until: Option<DateTime<Utc>>,
+  /// SHA-1 fingerprint of the certificate.
+  pub fingerprint: String,
+  /// SHA-1 thumbprint of the certificate.
+  pub thumbprint: String,
+  /// Whether the key has been revoked.
+  #[serde(default)]
+  pub revoked: bool,
+  /// The time when the key was revoked.
+  pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Key affected by a rotation or revocation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SigningKeyCert {
+  /// The key ID.
+  pub kid: String,
+  /// The public certificate in PEM format.
+  pub cert: String,
+}
@@ -0,0 +1,32 @@
+//! Retrieve a signing key.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve an application signing key by its key ID.
+///
+/// Send as [SigningKey](crate::SigningKey).
+///
+/// # Scopes
+/// * `read:signing_keys`
+pub struct SigningKeyGet(String);
+
+impl SigningKeyGet {
+  /// Create get signing key request.
+  ///
+  /// # Arguments
+  /// * `kid` - The key ID of the key to retrieve.
+  pub fn new<S: AsRef<str>>(kid: S) -> Self {
+    Self(kid.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for SigningKeyGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/keys/signing/{}", path_segment(&self.0)),
+    )
+  }
+}
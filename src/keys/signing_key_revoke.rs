@@ -0,0 +1,33 @@
+//! Revoke a signing key.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Revoke an application signing key.  Tokens signed with a revoked key are no longer
+/// valid.  The current and next keys cannot be revoked.
+///
+/// Send as [SigningKeyCert](crate::SigningKeyCert).
+///
+/// # Scopes
+/// * `update:signing_keys`
+pub struct SigningKeyRevoke(String);
+
+impl SigningKeyRevoke {
+  /// Create revoke signing key request.
+  ///
+  /// # Arguments
+  /// * `kid` - The key ID of the key to revoke.
+  pub fn new<S: AsRef<str>>(kid: S) -> Self {
+    Self(kid.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for SigningKeyRevoke {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::PUT,
+      &format!("api/v2/keys/signing/{}/revoke", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,28 @@
+//! Rotate the signing key.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Rotate the application signing key.  The next key becomes the current key and a new
+/// next key is generated.
+///
+/// Send as [SigningKeyCert](crate::SigningKeyCert).
+///
+/// # Scopes
+/// * `create:signing_keys`
+/// * `update:signing_keys`
+#[derive(Default)]
+pub struct SigningKeyRotate;
+
+impl SigningKeyRotate {
+  /// Create rotate signing key request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for SigningKeyRotate {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::POST, "api/v2/keys/signing/rotate")
+  }
+}
@@ -0,0 +1,26 @@
+//! Retrieve signing keys.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the application signing keys.
+///
+/// Send as `Vec<SigningKey>`.
+///
+/// # Scopes
+/// * `read:signing_keys`
+#[derive(Default)]
+pub struct SigningKeysGet;
+
+impl SigningKeysGet {
+  /// Create get signing keys request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for SigningKeysGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/keys/signing")
+  }
+}
@@ -63,47 +63,191 @@
 //!     .expect("Failed to update user.");
 //! }
 //! ```
+//!
+//! # Tracing
+//! Enable the `tracing` feature to wrap every HTTP request in an `auth0.request` span
+//! with `auth0.method`, `auth0.path`, `auth0.status_code` and `auth0.duration_ms`
+//! fields.  Failed requests are also logged with `tracing::error!`.
+pub use actions::*;
+pub use anomaly::*;
 #[doc(inline)]
 pub use api::*;
+pub use attack_protection::*;
+pub use branding::*;
 pub use builder::*;
 pub use client::*;
+pub use client_grants::*;
+pub use clients::*;
+pub use connections::*;
+pub use custom_domains::*;
+pub use device_credentials::*;
+pub use email_templates::*;
+pub use emails::*;
 pub use error::*;
+pub use fields::*;
+pub use grants::*;
+pub use guardian::*;
+pub use jobs::*;
+pub use keys::*;
+pub use log_streams::*;
+pub use logs::*;
+pub use organizations::*;
 pub use page::*;
+pub use prompts::*;
+pub use query::*;
 pub use request::*;
+pub use resource_servers::*;
+pub use roles::*;
+pub use rules::*;
+pub use self_service_profiles::*;
 pub use sort::*;
+pub use stats::*;
+pub use tenants::*;
+pub use tickets::*;
 pub use users::*;
 
-use crate::client::Auth0Client;
 use std::sync::Arc;
+use url::{ParseError, Url};
 
 mod request;
 pub mod sort;
 
+pub mod actions;
+pub mod anomaly;
 #[allow(missing_docs)]
 pub mod api;
+pub mod attack_protection;
+pub mod branding;
 pub mod builder;
 pub mod client;
+pub mod client_grants;
+pub mod clients;
+pub mod connections;
+pub mod custom_domains;
+pub mod device_credentials;
+pub mod email_templates;
+pub mod emails;
 pub mod error;
+pub mod fields;
+pub mod grants;
+pub mod guardian;
+pub mod jobs;
+pub mod keys;
+pub mod log_streams;
+pub mod logs;
+pub mod organizations;
 pub mod page;
+pub mod prompts;
+pub mod query;
 #[doc(hidden)]
 pub mod rate;
+pub mod resource_servers;
+pub mod roles;
+pub mod rules;
+pub mod self_service_profiles;
+pub mod stats;
+pub mod tenants;
+pub mod tickets;
 #[doc(hidden)]
 pub mod token;
 pub mod users;
 
 /// Auth0 management client.
 pub struct Auth0 {
+  client: Arc<Auth0Client>,
+
+  /// Actions manager
+  pub actions: ActionsManager,
+  /// Anomaly manager
+  pub anomaly: AnomalyManager,
+  /// Attack protection manager
+  pub attack_protection: AttackProtectionManager,
+  /// Branding manager
+  pub branding: BrandingManager,
+  /// Client grants manager
+  pub client_grants: ClientGrantsManager,
+  /// Clients manager
+  pub clients: ClientsManager,
+  /// Connections manager
+  pub connections: ConnectionsManager,
+  /// Custom domains manager
+  pub custom_domains: CustomDomainsManager,
+  /// Device credentials manager
+  pub device_credentials: DeviceCredentialsManager,
+  /// Email templates manager
+  pub email_templates: EmailTemplatesManager,
+  /// Emails manager
+  pub emails: EmailsManager,
+  /// Grants manager
+  pub grants: GrantsManager,
+  /// Guardian manager
+  pub guardian: GuardianManager,
+  /// Jobs manager
+  pub jobs: JobsManager,
+  /// Keys manager
+  pub keys: KeysManager,
+  /// Log streams manager
+  pub log_streams: LogStreamsManager,
+  /// Logs manager
+  pub logs: LogsManager,
+  /// Organizations manager
+  pub organizations: OrganizationsManager,
+  /// Prompts manager
+  pub prompts: PromptsManager,
+  /// Resource servers manager
+  pub resource_servers: ResourceServersManager,
+  /// Rules manager
+  pub rules: RulesManager,
+  /// Self-service profiles manager
+  pub self_service_profiles: SelfServiceProfilesManager,
+  /// Stats manager
+  pub stats: StatsManager,
+  /// Tenants manager
+  pub tenants: TenantsManager,
+  /// Tickets manager
+  pub tickets: TicketsManager,
   /// Users manager
   pub users: UsersManager,
 }
 
 impl Auth0 {
   /// Create auth0 management api
+  ///
+  /// The client's domain must be a valid host name, otherwise the URL helpers such as
+  /// [Auth0::authorize_url] return an error.  [Auth0Builder::build] validates this for
+  /// you.
   pub fn new(client: Auth0Client) -> Self {
     let client = Arc::new(client);
 
     Self {
-      users: UsersManager::new(client),
+      actions: ActionsManager::new(client.clone()),
+      anomaly: AnomalyManager::new(client.clone()),
+      attack_protection: AttackProtectionManager::new(client.clone()),
+      branding: BrandingManager::new(client.clone()),
+      client_grants: ClientGrantsManager::new(client.clone()),
+      clients: ClientsManager::new(client.clone()),
+      connections: ConnectionsManager::new(client.clone()),
+      custom_domains: CustomDomainsManager::new(client.clone()),
+      device_credentials: DeviceCredentialsManager::new(client.clone()),
+      email_templates: EmailTemplatesManager::new(client.clone()),
+      emails: EmailsManager::new(client.clone()),
+      grants: GrantsManager::new(client.clone()),
+      guardian: GuardianManager::new(client.clone()),
+      jobs: JobsManager::new(client.clone()),
+      keys: KeysManager::new(client.clone()),
+      log_streams: LogStreamsManager::new(client.clone()),
+      logs: LogsManager::new(client.clone()),
+      organizations: OrganizationsManager::new(client.clone()),
+      prompts: PromptsManager::new(client.clone()),
+      resource_servers: ResourceServersManager::new(client.clone()),
+      rules: RulesManager::new(client.clone()),
+      self_service_profiles: SelfServiceProfilesManager::new(client.clone()),
+      stats: StatsManager::new(client.clone()),
+      tenants: TenantsManager::new(client.clone()),
+      tickets: TicketsManager::new(client.clone()),
+      users: UsersManager::new(client.clone()),
+
+      client,
     }
   }
 
@@ -111,4 +255,82 @@ impl Auth0 {
   pub fn builder() -> Auth0Builder {
     Default::default()
   }
+
+  /// Create management client for the tenant at `domain` authenticated with the client
+  /// credentials of a Machine-to-Machine application.  The audience is the tenant's
+  /// management API, `https://{domain}/api/v2/`.
+  ///
+  /// The management API token is fetched on the first request, cached, and refreshed
+  /// once it is within 60 seconds of expiring.
+  pub fn with_client_credentials(
+    domain: &str,
+    client_id: &str,
+    client_secret: &str,
+  ) -> Result<Self, Auth0BuilderError> {
+    Self::builder()
+      .domain(domain)
+      .audience(&format!("https://{}/api/v2/", domain))
+      .client_id(client_id)
+      .client_secret(client_secret)
+      .build()
+  }
+
+  /// Name of the tenant, e.g. `mycompany` for `mycompany.auth0.com` or
+  /// `mycompany.eu.auth0.com`.  Custom domains carry no tenant name, so the full domain
+  /// is returned for those.
+  pub fn tenant_name(&self) -> &str {
+    let domain = self.client.domain();
+
+    match domain.strip_suffix(".auth0.com") {
+      Some(subdomain) => subdomain.split('.').next().unwrap_or(subdomain),
+      None => domain,
+    }
+  }
+
+  /// URL of the Universal Login page, `https://{domain}/authorize`.
+  pub fn authorize_url(&self) -> Result<Url, ParseError> {
+    self.tenant_url("authorize")
+  }
+
+  /// URL of the OAuth token endpoint, `https://{domain}/oauth/token`.
+  pub fn token_url(&self) -> Result<Url, ParseError> {
+    self.tenant_url("oauth/token")
+  }
+
+  /// URL of the JSON Web Key Set used to verify tokens issued by the tenant,
+  /// `https://{domain}/.well-known/jwks.json`.
+  pub fn jwks_url(&self) -> Result<Url, ParseError> {
+    self.tenant_url(".well-known/jwks.json")
+  }
+
+  /// URL of the logout endpoint, `https://{domain}/v2/logout`.
+  pub fn logout_url(&self) -> Result<Url, ParseError> {
+    self.tenant_url("v2/logout")
+  }
+
+  /// Whether the cached management API token has expired, or will expire within the
+  /// next 60 seconds.  Useful to refresh the token up front, e.g. before starting a
+  /// batch of requests, rather than having it expire part way through.  Also true
+  /// before the first request has been sent.
+  pub fn is_token_expired(&self) -> bool {
+    self.client.is_token_expired()
+  }
+
+  /// Verify that the management API is reachable and the client credentials are valid
+  /// by requesting a single user.  Useful for startup or readiness probes.
+  ///
+  /// # Scopes
+  /// * `read:users`
+  pub async fn health_check(&self) -> Auth0Result<()> {
+    UsersFind::new(&self.client)
+      .page(0)
+      .per_page(1)
+      .send::<serde_json::Value, serde_json::Value>()
+      .await
+      .map(|_| ())
+  }
+
+  fn tenant_url(&self, path: &str) -> Result<Url, ParseError> {
+    Url::parse(&format!("https://{}/{}", self.client.domain(), path))
+  }
 }
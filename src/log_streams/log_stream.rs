@@ -0,0 +1,175 @@
+//! Log stream types.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Destination type of a log stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStreamType {
+  /// HTTP webhook.
+  Http,
+  /// Amazon EventBridge.
+  EventBridge,
+  /// Azure Event Grid.
+  EventGrid,
+  /// Datadog.
+  Datadog,
+  /// Splunk.
+  Splunk,
+  /// Sumo Logic.
+  Sumo,
+  /// Segment.
+  Segment,
+  /// Mixpanel.
+  Mixpanel,
+  /// A destination type not known to this crate.
+  #[serde(other)]
+  Unknown,
+}
+
+/// Status of a log stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStreamStatus {
+  /// Logs are being delivered.
+  Active,
+  /// Delivery was paused by the user.
+  Paused,
+  /// Delivery was suspended by Auth0 after repeated failures.
+  Suspended,
+}
+
+/// Format of the payload sent to an HTTP log stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpContentFormat {
+  /// A JSON array of log events.
+  JsonArray,
+  /// One JSON log event per line.
+  JsonLines,
+  /// A single JSON log event.
+  JsonObject,
+}
+
+/// Destination of a log stream.  Every variant is sent as a flat object whose keys are
+/// prefixed with the destination type, e.g. `httpEndpoint` or `datadogApiKey`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LogStreamSink {
+  /// HTTP webhook.
+  Http {
+    /// URL the logs are posted to.
+    #[serde(rename = "httpEndpoint")]
+    endpoint: String,
+    /// Value of the `Authorization` header.
+    #[serde(rename = "httpAuthorization")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authorization: Option<String>,
+    /// Format of the payload.
+    #[serde(rename = "httpContentFormat")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_format: Option<HttpContentFormat>,
+    /// Value of the `Content-Type` header.
+    #[serde(rename = "httpContentType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+  },
+  /// Amazon EventBridge.
+  EventBridge {
+    /// AWS account the partner event source is created in.
+    #[serde(rename = "awsAccountId")]
+    account_id: String,
+    /// AWS region, e.g. `us-east-1`.
+    #[serde(rename = "awsRegion")]
+    region: String,
+    /// Name of the partner event source, set by Auth0.
+    #[serde(rename = "awsPartnerEventSource")]
+    #[serde(skip_serializing)]
+    partner_event_source: Option<String>,
+  },
+  /// Azure Event Grid.
+  EventGrid {
+    /// Azure subscription ID.
+    #[serde(rename = "azureSubscriptionId")]
+    subscription_id: String,
+    /// Azure resource group.
+    #[serde(rename = "azureResourceGroup")]
+    resource_group: String,
+    /// Azure region, e.g. `eastus`.
+    #[serde(rename = "azureRegion")]
+    region: String,
+    /// Name of the partner topic, set by Auth0.
+    #[serde(rename = "azurePartnerTopic")]
+    #[serde(skip_serializing)]
+    partner_topic: Option<String>,
+  },
+  /// Datadog.
+  Datadog {
+    /// Datadog site, e.g. `us` or `eu`.
+    #[serde(rename = "datadogRegion")]
+    region: String,
+    /// Datadog API key.
+    #[serde(rename = "datadogApiKey")]
+    api_key: String,
+  },
+  /// Splunk HTTP Event Collector.
+  Splunk {
+    /// Splunk domain.
+    #[serde(rename = "splunkDomain")]
+    domain: String,
+    /// HTTP Event Collector token.
+    #[serde(rename = "splunkToken")]
+    token: String,
+    /// HTTP Event Collector port.
+    #[serde(rename = "splunkPort")]
+    port: String,
+    /// Whether to verify the TLS certificate.
+    #[serde(rename = "splunkSecure")]
+    secure: bool,
+  },
+  /// Sumo Logic.
+  Sumo {
+    /// HTTP source address.
+    #[serde(rename = "sumoSourceAddress")]
+    source_address: String,
+  },
+  /// Segment.
+  Segment {
+    /// Segment write key.
+    #[serde(rename = "segmentWriteKey")]
+    write_key: String,
+  },
+  /// Mixpanel.
+  Mixpanel {
+    /// Mixpanel data residency region, e.g. `us` or `eu`.
+    #[serde(rename = "mixpanelRegion")]
+    region: String,
+    /// Mixpanel project ID.
+    #[serde(rename = "mixpanelProjectId")]
+    project_id: String,
+    /// Service account username.
+    #[serde(rename = "mixpanelServiceAccountUsername")]
+    service_account_username: String,
+    /// Service account password.
+    #[serde(rename = "mixpanelServiceAccountPassword")]
+    service_account_password: String,
+  },
+  /// A destination not known to this crate, or one whose secrets were omitted.
+  Unknown(Value),
+}
+
+/// Log stream.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LogStream {
+  /// The ID of the log stream.
+  pub id: String,
+  /// The name of the log stream.
+  pub name: String,
+  /// Destination type of the log stream.
+  #[serde(rename = "type")]
+  pub kind: LogStreamType,
+  /// Status of the log stream.
+  pub status: LogStreamStatus,
+  /// Destination of the log stream.
+  pub sink: LogStreamSink,
+}
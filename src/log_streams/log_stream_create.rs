@@ -0,0 +1,58 @@
+//! Create a log stream.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, LogStreamSink, LogStreamType};
+
+/// Create a log stream.
+///
+/// Send as [LogStream](crate::LogStream).
+///
+/// # Scopes
+/// * `create:log_streams`
+#[derive(Serialize)]
+pub struct LogStreamCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(rename = "type")]
+  kind: LogStreamType,
+  sink: LogStreamSink,
+}
+
+impl<'a> LogStreamCreate<'a> {
+  /// Create create log stream request.
+  ///
+  /// # Arguments
+  /// * `kind` - Destination type of the log stream.
+  /// * `sink` - Destination of the log stream, must match `kind`.
+  pub fn new(client: &'a Auth0Client, kind: LogStreamType, sink: LogStreamSink) -> Self {
+    Self {
+      client,
+
+      name: None,
+      kind,
+      sink,
+    }
+  }
+
+  /// The name of the log stream.
+  pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for LogStreamCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for LogStreamCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::POST, "api/v2/log-streams").json(self)
+  }
+}
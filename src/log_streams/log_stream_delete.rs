@@ -0,0 +1,30 @@
+//! Delete a log stream.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete a log stream.
+///
+/// # Scopes
+/// * `delete:log_streams`
+pub struct LogStreamDelete(String);
+
+impl LogStreamDelete {
+  /// Create delete log stream request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to delete.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for LogStreamDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/log-streams/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,32 @@
+//! Retrieve a log stream.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve a log stream configuration and status.
+///
+/// Send as [LogStream](crate::LogStream).
+///
+/// # Scopes
+/// * `read:log_streams`
+pub struct LogStreamGet(String);
+
+impl LogStreamGet {
+  /// Create get log stream request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to retrieve.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for LogStreamGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/log-streams/{}", path_segment(&self.0)),
+    )
+  }
+}
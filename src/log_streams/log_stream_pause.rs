@@ -0,0 +1,36 @@
+//! Pause a log stream.
+use reqwest::{Method, RequestBuilder};
+use serde_json::json;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, LogStreamStatus};
+
+/// Pause delivery of a log stream, e.g. during a maintenance window.  Resume it with
+/// [LogStreamResume](crate::LogStreamResume).
+///
+/// Send as [LogStream](crate::LogStream).
+///
+/// # Scopes
+/// * `update:log_streams`
+pub struct LogStreamPause(String);
+
+impl LogStreamPause {
+  /// Create pause log stream request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to pause.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for LogStreamPause {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!("api/v2/log-streams/{}", path_segment(&self.0)),
+      )
+      .json(&json!({ "status": LogStreamStatus::Paused }))
+  }
+}
@@ -0,0 +1,35 @@
+//! Resume a log stream.
+use reqwest::{Method, RequestBuilder};
+use serde_json::json;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, LogStreamStatus};
+
+/// Resume delivery of a paused or suspended log stream.
+///
+/// Send as [LogStream](crate::LogStream).
+///
+/// # Scopes
+/// * `update:log_streams`
+pub struct LogStreamResume(String);
+
+impl LogStreamResume {
+  /// Create resume log stream request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to resume.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for LogStreamResume {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!("api/v2/log-streams/{}", path_segment(&self.0)),
+      )
+      .json(&json!({ "status": LogStreamStatus::Active }))
+  }
+}
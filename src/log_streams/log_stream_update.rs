@@ -0,0 +1,79 @@
+//! Update a log stream.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, LogStreamSink, LogStreamStatus};
+
+/// Update a log stream.
+///
+/// Send as [LogStream](crate::LogStream).
+///
+/// # Scopes
+/// * `update:log_streams`
+#[derive(Serialize)]
+pub struct LogStreamUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  status: Option<LogStreamStatus>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  sink: Option<LogStreamSink>,
+}
+
+impl<'a> LogStreamUpdate<'a> {
+  /// Create update log stream request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to update.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      name: None,
+      status: None,
+      sink: None,
+    }
+  }
+
+  /// The name of the log stream.
+  pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
+    self
+  }
+
+  /// Status of the log stream, set to `active` to resume a suspended stream.
+  pub fn status(mut self, status: LogStreamStatus) -> Self {
+    self.status = Some(status);
+    self
+  }
+
+  /// Destination of the log stream.  The destination type cannot be changed.
+  pub fn sink(mut self, sink: LogStreamSink) -> Self {
+    self.sink = Some(sink);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for LogStreamUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for LogStreamUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!("api/v2/log-streams/{}", path_segment(&self.id)),
+      )
+      .json(self)
+  }
+}
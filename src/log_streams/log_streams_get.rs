@@ -0,0 +1,26 @@
+//! Retrieve log streams.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve details on all log streams.
+///
+/// Send as `Vec<LogStream>`.
+///
+/// # Scopes
+/// * `read:log_streams`
+#[derive(Default)]
+pub struct LogStreamsGet;
+
+impl LogStreamsGet {
+  /// Create get log streams request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for LogStreamsGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/log-streams")
+  }
+}
@@ -0,0 +1,110 @@
+#![warn(missing_docs)]
+//! Log stream request builders.
+
+#[doc(inline)]
+pub use log_stream::*;
+#[doc(inline)]
+pub use log_stream_create::*;
+#[doc(inline)]
+pub use log_stream_delete::*;
+#[doc(inline)]
+pub use log_stream_get::*;
+#[doc(inline)]
+pub use log_stream_pause::*;
+#[doc(inline)]
+pub use log_stream_resume::*;
+#[doc(inline)]
+pub use log_stream_update::*;
+#[doc(inline)]
+pub use log_streams_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod log_stream;
+pub mod log_stream_create;
+pub mod log_stream_delete;
+pub mod log_stream_get;
+pub mod log_stream_pause;
+pub mod log_stream_resume;
+pub mod log_stream_update;
+pub mod log_streams_get;
+
+/// Log streams manager
+pub struct LogStreamsManager(Arc<Auth0Client>);
+
+impl LogStreamsManager {
+  /// Create log streams manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a log stream.
+  ///
+  /// # Arguments
+  /// * `kind` - Destination type of the log stream.
+  /// * `sink` - Destination of the log stream, must match `kind`.
+  /// # Scopes
+  /// * `create:log_streams`
+  pub fn create(&self, kind: LogStreamType, sink: LogStreamSink) -> LogStreamCreate<'_> {
+    LogStreamCreate::new(&self.0, kind, sink)
+  }
+
+  /// Delete a log stream.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to delete.
+  /// # Scopes
+  /// * `delete:log_streams`
+  pub async fn delete<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    LogStreamDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve a log stream configuration and status.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to retrieve.
+  /// # Scopes
+  /// * `read:log_streams`
+  pub async fn get<S: AsRef<str>>(&self, id: S) -> Auth0Result<LogStream> {
+    LogStreamGet::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve details on all log streams.
+  ///
+  /// # Scopes
+  /// * `read:log_streams`
+  pub async fn list(&self) -> Auth0Result<Vec<LogStream>> {
+    LogStreamsGet::new().send_to(&self.0).await
+  }
+
+  /// Pause delivery of a log stream.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to pause.
+  /// # Scopes
+  /// * `update:log_streams`
+  pub async fn pause<S: AsRef<str>>(&self, id: S) -> Auth0Result<LogStream> {
+    LogStreamPause::new(id).send_to(&self.0).await
+  }
+
+  /// Resume delivery of a paused or suspended log stream.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to resume.
+  /// # Scopes
+  /// * `update:log_streams`
+  pub async fn resume<S: AsRef<str>>(&self, id: S) -> Auth0Result<LogStream> {
+    LogStreamResume::new(id).send_to(&self.0).await
+  }
+
+  /// Update a log stream.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the log stream to update.
+  /// # Scopes
+  /// * `update:log_streams`
+  pub fn update<S: AsRef<str>>(&self, id: S) -> LogStreamUpdate<'_> {
+    LogStreamUpdate::new(&self.0, id)
+  }
+}
@@ -0,0 +1,573 @@
+//! Log event type codes.
+use serde::Deserialize;
+
+/// Type of a log event.
+///
+/// See [Log Event Type Codes](https://auth0.com/docs/logs/log-event-type-codes).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "String")]
+pub enum LogEventType {
+  /// Auth0 Update Launched (`admin_update_launch`).
+  AdminUpdateLaunch,
+  /// Rate Limit on the Authentication or Management APIs (`api_limit`).
+  ApiLimit,
+  /// Rate Limit warning on the Authentication or Management APIs (`api_limit_warning`).
+  ApiLimitWarning,
+  /// Code/Link Sent (`cls`).
+  CodeLinkSent,
+  /// Code Sent (`cs`).
+  CodeSent,
+  /// Deprecation Notice (`depnote`).
+  DeprecationNotice,
+  /// Deleted User (`du`).
+  DeletedUser,
+  /// Failed Login (`f`).
+  FailedLogin,
+  /// Failed Management API operation (`fapi`).
+  FailedApiOperation,
+  /// Failed by Connector (`fc`).
+  FailedByConnector,
+  /// Failed Change Email (`fce`).
+  FailedChangeEmail,
+  /// Failed by CORS (`fco`).
+  FailedByCors,
+  /// Failed cross-origin authentication (`fcoa`).
+  FailedCrossOriginAuthentication,
+  /// Failed Change Password (`fcp`).
+  FailedChangePassword,
+  /// Failed Post Change Password Hook (`fcph`).
+  FailedPostChangePasswordHook,
+  /// Failed Change Phone Number (`fcpn`).
+  FailedChangePhoneNumber,
+  /// Failed Change Password Request (`fcpr`).
+  FailedChangePasswordRequest,
+  /// Failed Connection Provisioning (`fcpro`).
+  FailedConnectionProvisioning,
+  /// Failed Change Username (`fcu`).
+  FailedChangeUsername,
+  /// Failed Delegation (`fd`).
+  FailedDelegation,
+  /// Failed Device Activation (`fdeac`).
+  FailedDeviceActivation,
+  /// Failed Device Authorization Request (`fdeaz`).
+  FailedDeviceAuthorizationRequest,
+  /// User Canceled Device Confirmation (`fdecc`).
+  UserCanceledDeviceConfirmation,
+  /// Failed User Deletion (`fdu`).
+  FailedUserDeletion,
+  /// Failed Exchange (Authorization Code for Access Token) (`feacft`).
+  FailedExchangeAuthorizationCode,
+  /// Failed Exchange (Access Token for a Client Credentials Grant) (`feccft`).
+  FailedExchangeClientCredentials,
+  /// Failed Exchange (Device Code for Access Token) (`fede`).
+  FailedExchangeDeviceCode,
+  /// Failed Exchange (Native Social Login) (`fens`).
+  FailedExchangeNativeSocialLogin,
+  /// Failed Exchange (Password and OOB Challenge for Access Token) (`feoobft`).
+  FailedExchangeOobChallenge,
+  /// Failed Exchange (Password and OTP Challenge for Access Token) (`feotpft`).
+  FailedExchangeOtpChallenge,
+  /// Failed Exchange (Password for Access Token) (`fepft`).
+  FailedExchangePassword,
+  /// Failed Exchange (Passwordless OTP for Access Token) (`fepotpft`).
+  FailedExchangePasswordlessOtp,
+  /// Failed Exchange (Password and MFA Recovery code for Access Token) (`fercft`).
+  FailedExchangeRecoveryCode,
+  /// Failed Exchange (Rotating Refresh Token) (`ferrt`).
+  FailedExchangeRotatingRefreshToken,
+  /// Failed Exchange (Refresh Token for Access Token) (`fertft`).
+  FailedExchangeRefreshToken,
+  /// Failed invite accept (`fi`).
+  FailedInviteAccept,
+  /// Failed Logout (`flo`).
+  FailedLogout,
+  /// Failed Sending Notification (`fn`).
+  FailedSendingNotification,
+  /// Failed Login (Incorrect Password) (`fp`).
+  FailedLoginIncorrectPassword,
+  /// Failed Signup (`fs`).
+  FailedSignup,
+  /// Failed Silent Auth (`fsa`).
+  FailedSilentAuth,
+  /// Failed Login (Invalid Email/Username) (`fu`).
+  FailedLoginInvalidUsername,
+  /// Failed users import (`fui`).
+  FailedUsersImport,
+  /// Failed Verification Email (`fv`).
+  FailedVerificationEmail,
+  /// Failed Verification Email Request (`fvr`).
+  FailedVerificationEmailRequest,
+  /// OTP Auth failed (`gd_auth_failed`).
+  GuardianAuthFailed,
+  /// OTP Auth rejected (`gd_auth_rejected`).
+  GuardianAuthRejected,
+  /// OTP Auth success (`gd_auth_succeed`).
+  GuardianAuthSucceed,
+  /// Guardian enrollment complete (`gd_enrollment_complete`).
+  GuardianEnrollmentComplete,
+  /// Too many OTP failures (`gd_otp_rate_limit_exceed`).
+  GuardianOtpRateLimitExceed,
+  /// Recovery failed (`gd_recovery_failed`).
+  GuardianRecoveryFailed,
+  /// Too many recovery failures (`gd_recovery_rate_limit_exceed`).
+  GuardianRecoveryRateLimitExceed,
+  /// Recovery success (`gd_recovery_succeed`).
+  GuardianRecoverySucceed,
+  /// Push notification sent (`gd_send_pn`).
+  GuardianSendPushNotification,
+  /// SMS sent (`gd_send_sms`).
+  GuardianSendSms,
+  /// Second factor started (`gd_start_auth`).
+  GuardianStartAuth,
+  /// Enroll started (`gd_start_enroll`).
+  GuardianStartEnroll,
+  /// Unenroll device account (`gd_unenroll`).
+  GuardianUnenroll,
+  /// Update device account (`gd_update_device_account`).
+  GuardianUpdateDeviceAccount,
+  /// Too Many Calls to /delegation (`limit_delegation`).
+  LimitDelegation,
+  /// Blocked IP Address (`limit_mu`).
+  LimitMultipleUsers,
+  /// Blocked Account (`limit_sul`).
+  LimitSingleUser,
+  /// Blocked Account (`limit_wc`).
+  LimitWrongCredentials,
+  /// MFA Required (`mfar`).
+  MfaRequired,
+  /// Management API read operation (`mgmt_api_read`).
+  ManagementApiRead,
+  /// Pre-login assessment (`pla`).
+  PreLoginAssessment,
+  /// Breached password (`pwd_leak`).
+  PasswordLeak,
+  /// Success Login (`s`).
+  SuccessLogin,
+  /// Success Management API operation (`sapi`).
+  SuccessApiOperation,
+  /// Success Change Email (`sce`).
+  SuccessChangeEmail,
+  /// Success cross-origin authentication (`scoa`).
+  SuccessCrossOriginAuthentication,
+  /// Success Change Password (`scp`).
+  SuccessChangePassword,
+  /// Success Post Change Password Hook (`scph`).
+  SuccessPostChangePasswordHook,
+  /// Success Change Phone Number (`scpn`).
+  SuccessChangePhoneNumber,
+  /// Success Change Password Request (`scpr`).
+  SuccessChangePasswordRequest,
+  /// Success Change Username (`scu`).
+  SuccessChangeUsername,
+  /// Success Delegation (`sd`).
+  SuccessDelegation,
+  /// Success User Deletion (`sdu`).
+  SuccessUserDeletion,
+  /// Success Exchange (Authorization Code for Access Token) (`seacft`).
+  SuccessExchangeAuthorizationCode,
+  /// Success Exchange (Access Token for a Client Credentials Grant) (`seccft`).
+  SuccessExchangeClientCredentials,
+  /// Success Exchange (Device Code for Access Token) (`sede`).
+  SuccessExchangeDeviceCode,
+  /// Success Exchange (Native Social Login) (`sens`).
+  SuccessExchangeNativeSocialLogin,
+  /// Success Exchange (Password and OOB Challenge for Access Token) (`seoobft`).
+  SuccessExchangeOobChallenge,
+  /// Success Exchange (Password and OTP Challenge for Access Token) (`seotpft`).
+  SuccessExchangeOtpChallenge,
+  /// Success Exchange (Password for Access Token) (`sepft`).
+  SuccessExchangePassword,
+  /// Success Exchange (Password and MFA Recovery code for Access Token) (`sercft`).
+  SuccessExchangeRecoveryCode,
+  /// Success Exchange (Refresh Token for Access Token) (`sertft`).
+  SuccessExchangeRefreshToken,
+  /// Successfully accepted user invite (`si`).
+  SuccessInviteAccept,
+  /// Success Logout (`slo`).
+  SuccessLogout,
+  /// Success Revocation (`srrt`).
+  SuccessRevocation,
+  /// Success Signup (`ss`).
+  SuccessSignup,
+  /// Success Silent Auth (`ssa`).
+  SuccessSilentAuth,
+  /// Success users import (`sui`).
+  SuccessUsersImport,
+  /// Success Verification Email (`sv`).
+  SuccessVerificationEmail,
+  /// Success Verification Email Request (`svr`).
+  SuccessVerificationEmailRequest,
+  /// Auth0 OS Update Ended (`sys_os_update_end`).
+  SystemOsUpdateEnd,
+  /// Auth0 OS Update Started (`sys_os_update_start`).
+  SystemOsUpdateStart,
+  /// Auth0 Update Ended (`sys_update_end`).
+  SystemUpdateEnd,
+  /// Auth0 Update Started (`sys_update_start`).
+  SystemUpdateStart,
+  /// User login block released (`ublkdu`).
+  UserLoginBlockReleased,
+  /// Warnings During Login (`w`).
+  Warning,
+  /// An event type code not known to this crate.
+  Unknown(String),
+}
+
+impl LogEventType {
+  /// The event type code, e.g. `f` for [LogEventType::FailedLogin].
+  pub fn code(&self) -> &str {
+    match self {
+      Self::AdminUpdateLaunch => "admin_update_launch",
+      Self::ApiLimit => "api_limit",
+      Self::ApiLimitWarning => "api_limit_warning",
+      Self::CodeLinkSent => "cls",
+      Self::CodeSent => "cs",
+      Self::DeprecationNotice => "depnote",
+      Self::DeletedUser => "du",
+      Self::FailedLogin => "f",
+      Self::FailedApiOperation => "fapi",
+      Self::FailedByConnector => "fc",
+      Self::FailedChangeEmail => "fce",
+      Self::FailedByCors => "fco",
+      Self::FailedCrossOriginAuthentication => "fcoa",
+      Self::FailedChangePassword => "fcp",
+      Self::FailedPostChangePasswordHook => "fcph",
+      Self::FailedChangePhoneNumber => "fcpn",
+      Self::FailedChangePasswordRequest => "fcpr",
+      Self::FailedConnectionProvisioning => "fcpro",
+      Self::FailedChangeUsername => "fcu",
+      Self::FailedDelegation => "fd",
+      Self::FailedDeviceActivation => "fdeac",
+      Self::FailedDeviceAuthorizationRequest => "fdeaz",
+      Self::UserCanceledDeviceConfirmation => "fdecc",
+      Self::FailedUserDeletion => "fdu",
+      Self::FailedExchangeAuthorizationCode => "feacft",
+      Self::FailedExchangeClientCredentials => "feccft",
+      Self::FailedExchangeDeviceCode => "fede",
+      Self::FailedExchangeNativeSocialLogin => "fens",
+      Self::FailedExchangeOobChallenge => "feoobft",
+      Self::FailedExchangeOtpChallenge => "feotpft",
+      Self::FailedExchangePassword => "fepft",
+      Self::FailedExchangePasswordlessOtp => "fepotpft",
+      Self::FailedExchangeRecoveryCode => "fercft",
+      Self::FailedExchangeRotatingRefreshToken => "ferrt",
+      Self::FailedExchangeRefreshToken => "fertft",
+      Self::FailedInviteAccept => "fi",
+      Self::FailedLogout => "flo",
+      Self::FailedSendingNotification => "fn",
+      Self::FailedLoginIncorrectPassword => "fp",
+      Self::FailedSignup => "fs",
+      Self::FailedSilentAuth => "fsa",
+      Self::FailedLoginInvalidUsername => "fu",
+      Self::FailedUsersImport => "fui",
+      Self::FailedVerificationEmail => "fv",
+      Self::FailedVerificationEmailRequest => "fvr",
+      Self::GuardianAuthFailed => "gd_auth_failed",
+      Self::GuardianAuthRejected => "gd_auth_rejected",
+      Self::GuardianAuthSucceed => "gd_auth_succeed",
+      Self::GuardianEnrollmentComplete => "gd_enrollment_complete",
+      Self::GuardianOtpRateLimitExceed => "gd_otp_rate_limit_exceed",
+      Self::GuardianRecoveryFailed => "gd_recovery_failed",
+      Self::GuardianRecoveryRateLimitExceed => "gd_recovery_rate_limit_exceed",
+      Self::GuardianRecoverySucceed => "gd_recovery_succeed",
+      Self::GuardianSendPushNotification => "gd_send_pn",
+      Self::GuardianSendSms => "gd_send_sms",
+      Self::GuardianStartAuth => "gd_start_auth",
+      Self::GuardianStartEnroll => "gd_start_enroll",
+      Self::GuardianUnenroll => "gd_unenroll",
+      Self::GuardianUpdateDeviceAccount => "gd_update_device_account",
+      Self::LimitDelegation => "limit_delegation",
+      Self::LimitMultipleUsers => "limit_mu",
+      Self::LimitSingleUser => "limit_sul",
+      Self::LimitWrongCredentials => "limit_wc",
+      Self::MfaRequired => "mfar",
+      Self::ManagementApiRead => "mgmt_api_read",
+      Self::PreLoginAssessment => "pla",
+      Self::PasswordLeak => "pwd_leak",
+      Self::SuccessLogin => "s",
+      Self::SuccessApiOperation => "sapi",
+      Self::SuccessChangeEmail => "sce",
+      Self::SuccessCrossOriginAuthentication => "scoa",
+      Self::SuccessChangePassword => "scp",
+      Self::SuccessPostChangePasswordHook => "scph",
+      Self::SuccessChangePhoneNumber => "scpn",
+      Self::SuccessChangePasswordRequest => "scpr",
+      Self::SuccessChangeUsername => "scu",
+      Self::SuccessDelegation => "sd",
+      Self::SuccessUserDeletion => "sdu",
+      Self::SuccessExchangeAuthorizationCode => "seacft",
+      Self::SuccessExchangeClientCredentials => "seccft",
+      Self::SuccessExchangeDeviceCode => "sede",
+      Self::SuccessExchangeNativeSocialLogin => "sens",
+      Self::SuccessExchangeOobChallenge => "seoobft",
+      Self::SuccessExchangeOtpChallenge => "seotpft",
+      Self::SuccessExchangePassword => "sepft",
+      Self::SuccessExchangeRecoveryCode => "sercft",
+      Self::SuccessExchangeRefreshToken => "sertft",
+      Self::SuccessInviteAccept => "si",
+      Self::SuccessLogout => "slo",
+      Self::SuccessRevocation => "srrt",
+      Self::SuccessSignup => "ss",
+      Self::SuccessSilentAuth => "ssa",
+      Self::SuccessUsersImport => "sui",
+      Self::SuccessVerificationEmail => "sv",
+      Self::SuccessVerificationEmailRequest => "svr",
+      Self::SystemOsUpdateEnd => "sys_os_update_end",
+      Self::SystemOsUpdateStart => "sys_os_update_start",
+      Self::SystemUpdateEnd => "sys_update_end",
+      Self::SystemUpdateStart => "sys_update_start",
+      Self::UserLoginBlockReleased => "ublkdu",
+      Self::Warning => "w",
+      Self::Unknown(code) => code,
+    }
+  }
+
+  /// Human readable description of the event type.
+  pub fn description(&self) -> &'static str {
+    match self {
+      Self::AdminUpdateLaunch => "Auth0 Update Launched",
+      Self::ApiLimit => "Rate Limit on the Authentication or Management APIs",
+      Self::ApiLimitWarning => {
+        "Rate Limit warning on the Authentication or Management APIs"
+      }
+      Self::CodeLinkSent => "Code/Link Sent",
+      Self::CodeSent => "Code Sent",
+      Self::DeprecationNotice => "Deprecation Notice",
+      Self::DeletedUser => "Deleted User",
+      Self::FailedLogin => "Failed Login",
+      Self::FailedApiOperation => "Failed Management API operation",
+      Self::FailedByConnector => "Failed by Connector",
+      Self::FailedChangeEmail => "Failed Change Email",
+      Self::FailedByCors => "Failed by CORS",
+      Self::FailedCrossOriginAuthentication => "Failed cross-origin authentication",
+      Self::FailedChangePassword => "Failed Change Password",
+      Self::FailedPostChangePasswordHook => "Failed Post Change Password Hook",
+      Self::FailedChangePhoneNumber => "Failed Change Phone Number",
+      Self::FailedChangePasswordRequest => "Failed Change Password Request",
+      Self::FailedConnectionProvisioning => "Failed Connection Provisioning",
+      Self::FailedChangeUsername => "Failed Change Username",
+      Self::FailedDelegation => "Failed Delegation",
+      Self::FailedDeviceActivation => "Failed Device Activation",
+      Self::FailedDeviceAuthorizationRequest => "Failed Device Authorization Request",
+      Self::UserCanceledDeviceConfirmation => "User Canceled Device Confirmation",
+      Self::FailedUserDeletion => "Failed User Deletion",
+      Self::FailedExchangeAuthorizationCode => {
+        "Failed Exchange (Authorization Code for Access Token)"
+      }
+      Self::FailedExchangeClientCredentials => {
+        "Failed Exchange (Access Token for a Client Credentials Grant)"
+      }
+      Self::FailedExchangeDeviceCode => "Failed Exchange (Device Code for Access Token)",
+      Self::FailedExchangeNativeSocialLogin => "Failed Exchange (Native Social Login)",
+      Self::FailedExchangeOobChallenge => {
+        "Failed Exchange (Password and OOB Challenge for Access Token)"
+      }
+      Self::FailedExchangeOtpChallenge => {
+        "Failed Exchange (Password and OTP Challenge for Access Token)"
+      }
+      Self::FailedExchangePassword => "Failed Exchange (Password for Access Token)",
+      Self::FailedExchangePasswordlessOtp => {
+        "Failed Exchange (Passwordless OTP for Access Token)"
+      }
+      Self::FailedExchangeRecoveryCode => {
+        "Failed Exchange (Password and MFA Recovery code for Access Token)"
+      }
+      Self::FailedExchangeRotatingRefreshToken => {
+        "Failed Exchange (Rotating Refresh Token)"
+      }
+      Self::FailedExchangeRefreshToken => {
+        "Failed Exchange (Refresh Token for Access Token)"
+      }
+      Self::FailedInviteAccept => "Failed invite accept",
+      Self::FailedLogout => "Failed Logout",
+      Self::FailedSendingNotification => "Failed Sending Notification",
+      Self::FailedLoginIncorrectPassword => "Failed Login (Incorrect Password)",
+      Self::FailedSignup => "Failed Signup",
+      Self::FailedSilentAuth => "Failed Silent Auth",
+      Self::FailedLoginInvalidUsername => "Failed Login (Invalid Email/Username)",
+      Self::FailedUsersImport => "Failed users import",
+      Self::FailedVerificationEmail => "Failed Verification Email",
+      Self::FailedVerificationEmailRequest => "Failed Verification Email Request",
+      Self::GuardianAuthFailed => "OTP Auth failed",
+      Self::GuardianAuthRejected => "OTP Auth rejected",
+      Self::GuardianAuthSucceed => "OTP Auth success",
+      Self::GuardianEnrollmentComplete => "Guardian enrollment complete",
+      Self::GuardianOtpRateLimitExceed => "Too many OTP failures",
+      Self::GuardianRecoveryFailed => "Recovery failed",
+      Self::GuardianRecoveryRateLimitExceed => "Too many recovery failures",
+      Self::GuardianRecoverySucceed => "Recovery success",
+      Self::GuardianSendPushNotification => "Push notification sent",
+      Self::GuardianSendSms => "SMS sent",
+      Self::GuardianStartAuth => "Second factor started",
+      Self::GuardianStartEnroll => "Enroll started",
+      Self::GuardianUnenroll => "Unenroll device account",
+      Self::GuardianUpdateDeviceAccount => "Update device account",
+      Self::LimitDelegation => "Too Many Calls to /delegation",
+      Self::LimitMultipleUsers => "Blocked IP Address",
+      Self::LimitSingleUser => "Blocked Account",
+      Self::LimitWrongCredentials => "Blocked Account",
+      Self::MfaRequired => "MFA Required",
+      Self::ManagementApiRead => "Management API read operation",
+      Self::PreLoginAssessment => "Pre-login assessment",
+      Self::PasswordLeak => "Breached password",
+      Self::SuccessLogin => "Success Login",
+      Self::SuccessApiOperation => "Success Management API operation",
+      Self::SuccessChangeEmail => "Success Change Email",
+      Self::SuccessCrossOriginAuthentication => "Success cross-origin authentication",
+      Self::SuccessChangePassword => "Success Change Password",
+      Self::SuccessPostChangePasswordHook => "Success Post Change Password Hook",
+      Self::SuccessChangePhoneNumber => "Success Change Phone Number",
+      Self::SuccessChangePasswordRequest => "Success Change Password Request",
+      Self::SuccessChangeUsername => "Success Change Username",
+      Self::SuccessDelegation => "Success Delegation",
+      Self::SuccessUserDeletion => "Success User Deletion",
+      Self::SuccessExchangeAuthorizationCode => {
+        "Success Exchange (Authorization Code for Access Token)"
+      }
+      Self::SuccessExchangeClientCredentials => {
+        "Success Exchange (Access Token for a Client Credentials Grant)"
+      }
+      Self::SuccessExchangeDeviceCode => {
+        "Success Exchange (Device Code for Access Token)"
+      }
+      Self::SuccessExchangeNativeSocialLogin => "Success Exchange (Native Social Login)",
+      Self::SuccessExchangeOobChallenge => {
+        "Success Exchange (Password and OOB Challenge for Access Token)"
+      }
+      Self::SuccessExchangeOtpChallenge => {
+        "Success Exchange (Password and OTP Challenge for Access Token)"
+      }
+      Self::SuccessExchangePassword => "Success Exchange (Password for Access Token)",
+      Self::SuccessExchangeRecoveryCode => {
+        "Success Exchange (Password and MFA Recovery code for Access Token)"
+      }
+      Self::SuccessExchangeRefreshToken => {
+        "Success Exchange (Refresh Token for Access Token)"
+      }
+      Self::SuccessInviteAccept => "Successfully accepted user invite",
+      Self::SuccessLogout => "Success Logout",
+      Self::SuccessRevocation => "Success Revocation",
+      Self::SuccessSignup => "Success Signup",
+      Self::SuccessSilentAuth => "Success Silent Auth",
+      Self::SuccessUsersImport => "Success users import",
+      Self::SuccessVerificationEmail => "Success Verification Email",
+      Self::SuccessVerificationEmailRequest => "Success Verification Email Request",
+      Self::SystemOsUpdateEnd => "Auth0 OS Update Ended",
+      Self::SystemOsUpdateStart => "Auth0 OS Update Started",
+      Self::SystemUpdateEnd => "Auth0 Update Ended",
+      Self::SystemUpdateStart => "Auth0 Update Started",
+      Self::UserLoginBlockReleased => "User login block released",
+      Self::Warning => "Warnings During Login",
+      Self::Unknown(_) => "Unknown event type",
+    }
+  }
+}
+
+impl From<String> for LogEventType {
+  fn from(code: String) -> Self {
+    match code.as_str() {
+      "admin_update_launch" => Self::AdminUpdateLaunch,
+      "api_limit" => Self::ApiLimit,
+      "api_limit_warning" => Self::ApiLimitWarning,
+      "cls" => Self::CodeLinkSent,
+      "cs" => Self::CodeSent,
+      "depnote" => Self::DeprecationNotice,
+      "du" => Self::DeletedUser,
+      "f" => Self::FailedLogin,
+      "fapi" => Self::FailedApiOperation,
+      "fc" => Self::FailedByConnector,
+      "fce" => Self::FailedChangeEmail,
+      "fco" => Self::FailedByCors,
+      "fcoa" => Self::FailedCrossOriginAuthentication,
+      "fcp" => Self::FailedChangePassword,
+      "fcph" => Self::FailedPostChangePasswordHook,
+      "fcpn" => Self::FailedChangePhoneNumber,
+      "fcpr" => Self::FailedChangePasswordRequest,
+      "fcpro" => Self::FailedConnectionProvisioning,
+      "fcu" => Self::FailedChangeUsername,
+      "fd" => Self::FailedDelegation,
+      "fdeac" => Self::FailedDeviceActivation,
+      "fdeaz" => Self::FailedDeviceAuthorizationRequest,
+      "fdecc" => Self::UserCanceledDeviceConfirmation,
+      "fdu" => Self::FailedUserDeletion,
+      "feacft" => Self::FailedExchangeAuthorizationCode,
+      "feccft" => Self::FailedExchangeClientCredentials,
+      "fede" => Self::FailedExchangeDeviceCode,
+      "fens" => Self::FailedExchangeNativeSocialLogin,
+      "feoobft" => Self::FailedExchangeOobChallenge,
+      "feotpft" => Self::FailedExchangeOtpChallenge,
+      "fepft" => Self::FailedExchangePassword,
+      "fepotpft" => Self::FailedExchangePasswordlessOtp,
+      "fercft" => Self::FailedExchangeRecoveryCode,
+      "ferrt" => Self::FailedExchangeRotatingRefreshToken,
+      "fertft" => Self::FailedExchangeRefreshToken,
+      "fi" => Self::FailedInviteAccept,
+      "flo" => Self::FailedLogout,
+      "fn" => Self::FailedSendingNotification,
+      "fp" => Self::FailedLoginIncorrectPassword,
+      "fs" => Self::FailedSignup,
+      "fsa" => Self::FailedSilentAuth,
+      "fu" => Self::FailedLoginInvalidUsername,
+      "fui" => Self::FailedUsersImport,
+      "fv" => Self::FailedVerificationEmail,
+      "fvr" => Self::FailedVerificationEmailRequest,
+      "gd_auth_failed" => Self::GuardianAuthFailed,
+      "gd_auth_rejected" => Self::GuardianAuthRejected,
+      "gd_auth_succeed" => Self::GuardianAuthSucceed,
+      "gd_enrollment_complete" => Self::GuardianEnrollmentComplete,
+      "gd_otp_rate_limit_exceed" => Self::GuardianOtpRateLimitExceed,
+      "gd_recovery_failed" => Self::GuardianRecoveryFailed,
+      "gd_recovery_rate_limit_exceed" => Self::GuardianRecoveryRateLimitExceed,
+      "gd_recovery_succeed" => Self::GuardianRecoverySucceed,
+      "gd_send_pn" => Self::GuardianSendPushNotification,
+      "gd_send_sms" => Self::GuardianSendSms,
+      "gd_start_auth" => Self::GuardianStartAuth,
+      "gd_start_enroll" => Self::GuardianStartEnroll,
+      "gd_unenroll" => Self::GuardianUnenroll,
+      "gd_update_device_account" => Self::GuardianUpdateDeviceAccount,
+      "limit_delegation" => Self::LimitDelegation,
+      "limit_mu" => Self::LimitMultipleUsers,
+      "limit_sul" => Self::LimitSingleUser,
+      "limit_wc" => Self::LimitWrongCredentials,
+      "mfar" => Self::MfaRequired,
+      "mgmt_api_read" => Self::ManagementApiRead,
+      "pla" => Self::PreLoginAssessment,
+      "pwd_leak" => Self::PasswordLeak,
+      "s" => Self::SuccessLogin,
+      "sapi" => Self::SuccessApiOperation,
+      "sce" => Self::SuccessChangeEmail,
+      "scoa" => Self::SuccessCrossOriginAuthentication,
+      "scp" => Self::SuccessChangePassword,
+      "scph" => Self::SuccessPostChangePasswordHook,
+      "scpn" => Self::SuccessChangePhoneNumber,
+      "scpr" => Self::SuccessChangePasswordRequest,
+      "scu" => Self::SuccessChangeUsername,
+      "sd" => Self::SuccessDelegation,
+      "sdu" => Self::SuccessUserDeletion,
+      "seacft" => Self::SuccessExchangeAuthorizationCode,
+      "seccft" => Self::SuccessExchangeClientCredentials,
+      "sede" => Self::SuccessExchangeDeviceCode,
+      "sens" => Self::SuccessExchangeNativeSocialLogin,
+      "seoobft" => Self::SuccessExchangeOobChallenge,
+      "seotpft" => Self::SuccessExchangeOtpChallenge,
+      "sepft" => Self::SuccessExchangePassword,
+      "sercft" => Self::SuccessExchangeRecoveryCode,
+      "sertft" => Self::SuccessExchangeRefreshToken,
+      "si" => Self::SuccessInviteAccept,
+      "slo" => Self::SuccessLogout,
+      "srrt" => Self::SuccessRevocation,
+      "ss" => Self::SuccessSignup,
+      "ssa" => Self::SuccessSilentAuth,
+      "sui" => Self::SuccessUsersImport,
+      "sv" => Self::SuccessVerificationEmail,
+      "svr" => Self::SuccessVerificationEmailRequest,
+      "sys_os_update_end" => Self::SystemOsUpdateEnd,
+      "sys_os_update_start" => Self::SystemOsUpdateStart,
+      "sys_update_end" => Self::SystemUpdateEnd,
+      "sys_update_start" => Self::SystemUpdateStart,
+      "ublkdu" => Self::UserLoginBlockReleased,
+      "w" => Self::Warning,
+      _ => Self::Unknown(code),
+    }
+  }
+}
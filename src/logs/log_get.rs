@@ -0,0 +1,42 @@
+//! Retrieve a log event.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::logs::UserLog;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve a log event by its ID.
+///
+/// # Scopes
+/// * `read:logs`
+/// * `read:logs_users`
+pub struct LogGet {
+  id: String,
+}
+
+impl LogGet {
+  /// Create get log request.
+  ///
+  /// # Arguments
+  /// * `id` - The `log_id` of the log event to retrieve.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self {
+      id: id.as_ref().to_string(),
+    }
+  }
+}
+
+impl From<&UserLog> for LogGet {
+  fn from(log: &UserLog) -> Self {
+    Self::new(&log.log_id)
+  }
+}
+
+impl Auth0RequestBuilder for LogGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/logs/{}", path_segment(&self.id)),
+    )
+  }
+}
@@ -0,0 +1,55 @@
+//! Log pagination.
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::Page;
+
+/// Pagination parameters for log searches.
+///
+/// Auth0 rejects log searches that combine offset and checkpoint parameters, so only one
+/// of them is ever serialized.
+pub enum LogPagination {
+  /// Offset pagination using `page` and `per_page`.
+  Offset(Page),
+  /// Checkpoint pagination starting from the given log event ID.
+  Checkpoint(String),
+}
+
+impl LogPagination {
+  /// Switch to offset pagination, if needed, and return the page parameters.
+  pub(crate) fn offset_mut(&mut self) -> &mut Page {
+    if let Self::Checkpoint(_) = self {
+      *self = Self::Offset(Default::default());
+    }
+
+    match self {
+      Self::Offset(page) => page,
+      Self::Checkpoint(_) => unreachable!(),
+    }
+  }
+}
+
+impl Default for LogPagination {
+  fn default() -> Self {
+    Self::Offset(Default::default())
+  }
+}
+
+impl Serialize for LogPagination {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(None)?;
+
+    match self {
+      Self::Offset(page) => {
+        if let Some(index) = page.page {
+          map.serialize_entry("page", &index)?;
+        }
+        if let Some(per_page) = page.per_page {
+          map.serialize_entry("per_page", &per_page)?;
+        }
+      }
+      Self::Checkpoint(from) => map.serialize_entry("from", from)?,
+    }
+
+    map.end()
+  }
+}
@@ -0,0 +1,123 @@
+//! Log search query builder.
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{LogEventType, LuceneQuery};
+
+/// Builds a [log search query](https://auth0.com/docs/logs/log-search-query-syntax) for
+/// common filters.  Every condition is combined using `AND`, except event types which
+/// match any of the requested types.
+///
+/// ```
+/// use auth0_management::{LogEventType, LogQuery};
+///
+/// let q = LogQuery::new()
+///   .event_type(LogEventType::SuccessLogin)
+///   .connection("Username-Password-Authentication")
+///   .build();
+///
+/// assert_eq!(q, r#"connection:"Username-Password-Authentication" AND (type:s)"#);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LogQuery {
+  query: LuceneQuery,
+  since: Option<DateTime<Utc>>,
+  until: Option<DateTime<Utc>>,
+  event_types: Vec<LogEventType>,
+}
+
+impl LogQuery {
+  /// Create empty query.
+  pub fn new() -> Self {
+    Default::default()
+  }
+
+  /// Only match log events of this type.  Call again to also match other types.
+  pub fn event_type(mut self, event_type: LogEventType) -> Self {
+    self.event_types.push(event_type);
+    self
+  }
+
+  /// Only match log events from the connection with this name.
+  pub fn connection(mut self, connection: &str) -> Self {
+    self.query = self.query.and_eq("connection", connection);
+    self
+  }
+
+  /// Only match log events from the connection with this ID.
+  pub fn connection_id(mut self, connection_id: &str) -> Self {
+    self.query = self.query.and_eq("connection_id", connection_id);
+    self
+  }
+
+  /// Only match log events from the client with this ID.
+  pub fn client_id(mut self, client_id: &str) -> Self {
+    self.query = self.query.and_eq("client_id", client_id);
+    self
+  }
+
+  /// Only match log events of the user with this ID.
+  pub fn user_id(mut self, user_id: &str) -> Self {
+    self.query = self.query.and_eq("user_id", user_id);
+    self
+  }
+
+  /// Only match log events originating from this IP address.
+  pub fn ip(mut self, ip: &str) -> Self {
+    self.query = self.query.and_eq("ip", ip);
+    self
+  }
+
+  /// Only match log events that happened between `start` and `end`, inclusive.
+  pub fn date_range(self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+    self.since(start).until(end)
+  }
+
+  /// Only match log events that happened at or after this time.
+  pub fn since(mut self, since: DateTime<Utc>) -> Self {
+    self.since = Some(since);
+    self
+  }
+
+  /// Only match log events that happened at or before this time.
+  pub fn until(mut self, until: DateTime<Utc>) -> Self {
+    self.until = Some(until);
+    self
+  }
+
+  /// The query string, to pass to [LogsGet::q](crate::LogsGet::q).
+  pub fn build(&self) -> String {
+    let mut conditions = Vec::new();
+
+    let query = self.query.to_string();
+    if !query.is_empty() {
+      conditions.push(query);
+    }
+    if self.since.is_some() || self.until.is_some() {
+      let date = |date: &Option<DateTime<Utc>>| {
+        date
+          .map(|date| date.to_rfc3339_opts(SecondsFormat::Millis, true))
+          .unwrap_or_else(|| "*".to_owned())
+      };
+
+      conditions.push(format!(
+        "date:[{} TO {}]",
+        date(&self.since),
+        date(&self.until)
+      ));
+    }
+    match self.event_types.as_slice() {
+      [] => {}
+      [event_type] => conditions.push(format!("(type:{})", event_type.code())),
+      event_types => conditions.push(format!(
+        "(type:({}))",
+        event_types
+          .iter()
+          .map(LogEventType::code)
+          .collect::<Vec<_>>()
+          .join(" ")
+      )),
+    }
+
+    conditions.join(" AND ")
+  }
+}
@@ -0,0 +1,164 @@
+//! Search log events.
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, Auth0Result, UserLog};
+use crate::{Fields, LogPagination, Page, PaginatedStream, Sort};
+
+/// Search log events.
+///
+/// # Scopes
+/// * `read:logs`
+#[derive(Serialize)]
+pub struct LogsGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  q: Option<String>,
+  #[serde(flatten)]
+  pagination: LogPagination,
+  #[serde(skip_serializing_if = "Sort::is_emtpy")]
+  sort: Sort,
+  #[serde(flatten)]
+  fields: Fields,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  take: Option<u32>,
+}
+
+impl<'a> LogsGet<'a> {
+  /// Create search logs request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      q: None,
+      pagination: Default::default(),
+      sort: Default::default(),
+      fields: Default::default(),
+      take: None,
+    }
+  }
+
+  /// Query in [Lucene query string syntax](https://auth0.com/docs/logs/log-search-query-syntax).
+  pub fn q(mut self, q: &str) -> Self {
+    self.q = Some(q.to_owned());
+    self
+  }
+
+  /// Log event ID to start retrieving logs from (checkpoint pagination).  Use in
+  /// combination with [LogsGet::take] instead of `page` and `per_page`, which are
+  /// dropped from the request once `from` is set.
+  pub fn from(mut self, log_id: &str) -> Self {
+    self.pagination = LogPagination::Checkpoint(log_id.to_owned());
+    self
+  }
+
+  /// Number of entries to retrieve when using the `from` parameter.
+  pub fn take(mut self, take: u32) -> Self {
+    self.take = Some(take);
+    self
+  }
+
+  /// Stream all matching log events as CSV rows, fetching pages as needed.
+  ///
+  /// The first item is the header row.  Every row is terminated by a newline so the
+  /// items can be written straight to a file.  The stream ends after the first error.
+  ///
+  /// Pages are fetched by a [PaginatedStream] using offset
+  /// pagination, so any `from` checkpoint is ignored.
+  pub fn to_csv_stream(self) -> impl Stream<Item = Auth0Result<String>> + 'a {
+    let header = stream::iter(vec![Ok(csv_row(&CSV_HEADER))]);
+    let rows = PaginatedStream::<UserLog>::new(self).flat_map(|page| {
+      stream::iter(match page {
+        Ok(logs) => logs.iter().map(|log| Ok(csv_log_row(log))).collect(),
+        Err(err) => vec![Err(err)],
+      })
+    });
+
+    header.chain(rows)
+  }
+}
+
+const CSV_HEADER: [&str; 12] = [
+  "date",
+  "type",
+  "description",
+  "connection",
+  "client_id",
+  "client_name",
+  "ip",
+  "hostname",
+  "user_id",
+  "user_name",
+  "user_agent",
+  "log_id",
+];
+
+fn csv_log_row(log: &UserLog) -> String {
+  let date = log.date.to_rfc3339();
+  let opt = |value: &Option<String>| value.as_deref().unwrap_or("").to_owned();
+
+  csv_row(&[
+    date.as_str(),
+    log.kind.code(),
+    &opt(&log.description),
+    &opt(&log.connection),
+    &opt(&log.client_id),
+    &opt(&log.client_name),
+    &opt(&log.ip),
+    &opt(&log.hostname),
+    &opt(&log.user_id),
+    &opt(&log.user_name),
+    &opt(&log.user_agent),
+    log.log_id.as_str(),
+  ])
+}
+
+fn csv_row(values: &[&str]) -> String {
+  let mut row = values
+    .iter()
+    .map(|value| {
+      if value.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+      } else {
+        value.to_string()
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(",");
+
+  row.push('\n');
+  row
+}
+
+impl<'a> AsMut<Page> for LogsGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    self.pagination.offset_mut()
+  }
+}
+
+impl<'a> AsMut<Sort> for LogsGet<'a> {
+  fn as_mut(&mut self) -> &mut Sort {
+    &mut self.sort
+  }
+}
+
+impl<'a> AsMut<Fields> for LogsGet<'a> {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for LogsGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for LogsGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/logs").query(&self)
+  }
+}
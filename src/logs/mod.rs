@@ -0,0 +1,138 @@
+#![warn(missing_docs)]
+//! Log request builders.
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[doc(inline)]
+pub use log_event_type::*;
+#[doc(inline)]
+pub use log_get::*;
+#[doc(inline)]
+pub use log_pagination::*;
+#[doc(inline)]
+pub use log_query::*;
+#[doc(inline)]
+pub use logs_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result, ConnectionStrategy};
+use std::sync::Arc;
+
+pub mod log_event_type;
+pub mod log_get;
+pub mod log_pagination;
+pub mod log_query;
+pub mod logs_get;
+
+/// User log event.
+#[derive(Debug, Deserialize)]
+pub struct UserLog {
+  /// Date when the event occurred.
+  pub date: DateTime<Utc>,
+  /// Type of event.
+  #[serde(rename = "type")]
+  pub kind: LogEventType,
+  /// Description of this event.
+  pub description: Option<String>,
+  /// Name of the connection the event relates to.
+  pub connection: Option<String>,
+  /// ID of the connection the event relates to.
+  pub connection_id: Option<String>,
+  /// ID of the client (application).
+  pub client_id: Option<String>,
+  /// Name of the client (application).
+  pub client_name: Option<String>,
+  /// IP address of the log event source.
+  pub ip: Option<String>,
+  /// Hostname the event applies to.
+  pub hostname: Option<String>,
+  /// ID of the user involved in the event.
+  pub user_id: Option<String>,
+  /// Name of the user involved in the event.
+  pub user_name: Option<String>,
+  /// API audience the event applies to.
+  pub audience: Option<String>,
+  /// Scope permissions applied to the event.
+  pub scope: Option<String>,
+  /// Strategy of the connection involved in the event.
+  pub strategy: Option<ConnectionStrategy>,
+  /// Type of strategy involved in the event.
+  pub strategy_type: Option<String>,
+  /// Unique ID of the event.
+  pub log_id: String,
+  /// Whether the client was a mobile device (true) or desktop/laptop/server (false).
+  #[serde(rename = "isMobile", default)]
+  pub is_mobile: bool,
+  /// User agent string from the client device that caused the event.
+  pub user_agent: Option<String>,
+  /// Additional useful details about this event (structure is dependent upon event type).
+  pub details: Value,
+  /// Information about the location that triggered this event based on the ip.
+  pub location_info: Option<UserLogLocationInfo>,
+}
+
+/// User log event location.
+#[derive(Debug, Deserialize)]
+pub struct UserLogLocationInfo {
+  /// Two-letter [Alpha-2 ISO 3166-1](https://www.iso.org/iso-3166-country-codes.html)
+  /// country code.
+  pub country_code: String,
+  /// Three-letter [Alpha-3 ISO 3166-1](https://www.iso.org/iso-3166-country-codes.html)
+  /// country code.
+  pub country_code3: String,
+  /// Full country name in English.
+  pub country_name: String,
+  /// Full city name in English.
+  pub city_name: String,
+  /// Global latitude (horizontal) position.
+  pub latitude: Option<f32>,
+  /// Global longitude (vertical) position.
+  pub longitude: Option<f32>,
+  /// Time zone name as found in the [tz database](https://www.iana.org/time-zones).
+  pub time_zone: String,
+  /// Continent the country is located within. Can be AF (Africa), AN (Antarctica),
+  /// AS (Asia), EU (Europe), NA (North America), OC (Oceania) or SA (South America).
+  pub continent_code: String,
+}
+
+/// Logs manager
+pub struct LogsManager(Arc<Auth0Client>);
+
+impl LogsManager {
+  /// Create logs manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve an individual log event.
+  ///
+  /// # Arguments
+  /// * `id` - The `log_id` of the log event to retrieve.
+  /// # Scopes
+  /// * `read:logs`
+  /// * `read:logs_users`
+  pub async fn get<S: AsRef<str>>(&self, id: S) -> Auth0Result<UserLog> {
+    LogGet::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve log entries that match the specified search criteria (or all log entries
+  /// if no criteria specified).
+  ///
+  /// Set custom search criteria using the `q` parameter, or search from a specific log ID
+  /// ("search from checkpoint").
+  ///
+  /// For more information on all possible event types, their respective acronyms, and
+  /// descriptions, see
+  /// [Log Event Type Codes](https://auth0.com/docs/logs/log-event-type-codes).
+  ///
+  /// Auth0 [limits the number of logs](https://auth0.com/docs/logs#limitations) you can
+  /// return by search criteria to 100 logs per request.  Furthermore, you may only
+  /// paginate through up to 1,000 search results.  If you exceed this threshold, please
+  /// redefine your search or use checkpoint pagination.
+  ///
+  /// # Scopes
+  /// * `read:logs`
+  pub fn find(&self) -> LogsGet<'_> {
+    LogsGet::new(&self.0)
+  }
+}
@@ -0,0 +1,351 @@
+#![warn(missing_docs)]
+//! Organization request builders.
+
+#[doc(inline)]
+pub use organization::*;
+#[doc(inline)]
+pub use organization_connection_add::*;
+#[doc(inline)]
+pub use organization_connection_delete::*;
+#[doc(inline)]
+pub use organization_connection_update::*;
+#[doc(inline)]
+pub use organization_connections_get::*;
+#[doc(inline)]
+pub use organization_create::*;
+#[doc(inline)]
+pub use organization_delete::*;
+#[doc(inline)]
+pub use organization_get::*;
+#[doc(inline)]
+pub use organization_invitation_create::*;
+#[doc(inline)]
+pub use organization_invitation_delete::*;
+#[doc(inline)]
+pub use organization_invitation_get::*;
+#[doc(inline)]
+pub use organization_invitations_get::*;
+#[doc(inline)]
+pub use organization_member_roles_assign::*;
+#[doc(inline)]
+pub use organization_member_roles_delete::*;
+#[doc(inline)]
+pub use organization_member_roles_get::*;
+#[doc(inline)]
+pub use organization_members_add::*;
+#[doc(inline)]
+pub use organization_members_delete::*;
+#[doc(inline)]
+pub use organization_members_get::*;
+#[doc(inline)]
+pub use organization_update::*;
+#[doc(inline)]
+pub use organizations_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+
+pub mod organization;
+pub mod organization_connection_add;
+pub mod organization_connection_delete;
+pub mod organization_connection_update;
+pub mod organization_connections_get;
+pub mod organization_create;
+pub mod organization_delete;
+pub mod organization_get;
+pub mod organization_invitation_create;
+pub mod organization_invitation_delete;
+pub mod organization_invitation_get;
+pub mod organization_invitations_get;
+pub mod organization_member_roles_assign;
+pub mod organization_member_roles_delete;
+pub mod organization_member_roles_get;
+pub mod organization_members_add;
+pub mod organization_members_delete;
+pub mod organization_members_get;
+pub mod organization_update;
+pub mod organizations_get;
+
+/// Organizations manager
+pub struct OrganizationsManager(Arc<Auth0Client>);
+
+impl OrganizationsManager {
+  /// Create organizations manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a new organization.
+  ///
+  /// # Arguments
+  /// * `name` - The name of this organization.
+  /// # Scopes
+  /// * `create:organizations`
+  pub fn create(&self, name: &str) -> OrganizationCreate<'_, ()> {
+    OrganizationCreate::new(&self.0, name)
+  }
+
+  /// Assign one or more roles to a member that will be applied in the context of the
+  /// organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `user_id` - The ID of the member.
+  /// # Scopes
+  /// * `create:organization_member_roles`
+  pub fn assign_member_roles<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    user_id: T,
+  ) -> OrganizationMemberRolesAssign<'_> {
+    OrganizationMemberRolesAssign::new(&self.0, id, user_id)
+  }
+
+  /// Create an invitation for a user to join an organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `inviter` - The name of the user sending the invitation.
+  /// * `invitee` - The email address of the user being invited.
+  /// * `client_id` - Auth0 client ID used to resolve the application's login initiation
+  ///   uri.
+  /// # Scopes
+  /// * `create:organization_invitations`
+  pub fn create_invitation<S: AsRef<str>>(
+    &self,
+    id: S,
+    inviter: &str,
+    invitee: &str,
+    client_id: &str,
+  ) -> OrganizationInvitationCreate<'_> {
+    OrganizationInvitationCreate::new(&self.0, id, inviter, invitee, client_id)
+  }
+
+  /// Remove an organization from your tenant.  This action cannot be undone.
+  ///
+  /// Note: Members are automatically disassociated from an organization when it is
+  /// deleted, however they are not removed from the tenant.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization to delete.
+  /// # Scopes
+  /// * `delete:organizations`
+  pub async fn delete<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    OrganizationDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve details about a single organization specified by ID.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization to retrieve.
+  /// # Scopes
+  /// * `read:organizations`
+  pub async fn get<M, S: AsRef<str>>(&self, id: S) -> Auth0Result<Organization<M>>
+  where
+    M: DeserializeOwned + Send + Sync,
+  {
+    OrganizationGet::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve details about a single organization specified by name.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the organization to retrieve.
+  /// # Scopes
+  /// * `read:organizations`
+  pub async fn get_by_name<M, S: AsRef<str>>(
+    &self,
+    name: S,
+  ) -> Auth0Result<Organization<M>>
+  where
+    M: DeserializeOwned + Send + Sync,
+  {
+    OrganizationGetByName::new(name).send_to(&self.0).await
+  }
+
+  /// Enable a connection for an organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `connection_id` - The ID of the connection to enable.
+  /// # Scopes
+  /// * `create:organization_connections`
+  pub fn add_connection<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    connection_id: T,
+  ) -> OrganizationConnectionAdd<'_> {
+    OrganizationConnectionAdd::new(&self.0, id, connection_id)
+  }
+
+  /// Add members to an organization.
+  ///
+  /// Note: Users must already exist in the tenant before being added as members.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// # Scopes
+  /// * `create:organization_members`
+  pub fn add_members<S: AsRef<str>>(&self, id: S) -> OrganizationMembersAdd<'_> {
+    OrganizationMembersAdd::new(&self.0, id)
+  }
+
+  /// Disable a connection for an organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `connection_id` - The ID of the connection to disable.
+  /// # Scopes
+  /// * `delete:organization_connections`
+  pub async fn delete_connection<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    connection_id: T,
+  ) -> Auth0Result<()> {
+    OrganizationConnectionDelete::new(id, connection_id)
+      .send_to(&self.0)
+      .await
+  }
+
+  /// Delete an invitation to an organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `invitation_id` - The ID of the invitation to delete.
+  /// # Scopes
+  /// * `delete:organization_invitations`
+  pub async fn delete_invitation<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    invitation_id: T,
+  ) -> Auth0Result<()> {
+    OrganizationInvitationDelete::new(id, invitation_id)
+      .send_to(&self.0)
+      .await
+  }
+
+  /// Remove members from an organization.  Removing a member does not delete the user
+  /// from the tenant.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// # Scopes
+  /// * `delete:organization_members`
+  pub fn delete_members<S: AsRef<str>>(&self, id: S) -> OrganizationMembersDelete<'_> {
+    OrganizationMembersDelete::new(&self.0, id)
+  }
+
+  /// Remove one or more roles from a member in the context of the organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `user_id` - The ID of the member.
+  /// # Scopes
+  /// * `delete:organization_member_roles`
+  pub fn delete_member_roles<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    user_id: T,
+  ) -> OrganizationMemberRolesDelete<'_> {
+    OrganizationMemberRolesDelete::new(&self.0, id, user_id)
+  }
+
+  /// Retrieve the connections enabled for an organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// # Scopes
+  /// * `read:organization_connections`
+  pub fn get_connections<S: AsRef<str>>(&self, id: S) -> OrganizationConnectionsGet<'_> {
+    OrganizationConnectionsGet::new(&self.0, id)
+  }
+
+  /// Retrieve an invitation to an organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `invitation_id` - The ID of the invitation to retrieve.
+  /// # Scopes
+  /// * `read:organization_invitations`
+  pub async fn get_invitation<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    invitation_id: T,
+  ) -> Auth0Result<OrganizationInvitation> {
+    OrganizationInvitationGet::new(id, invitation_id)
+      .send_to(&self.0)
+      .await
+  }
+
+  /// Retrieve a list of invitations to an organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// # Scopes
+  /// * `read:organization_invitations`
+  pub fn get_invitations<S: AsRef<str>>(&self, id: S) -> OrganizationInvitationsGet<'_> {
+    OrganizationInvitationsGet::new(&self.0, id)
+  }
+
+  /// Retrieve the roles assigned to a member in the context of the organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `user_id` - The ID of the member.
+  /// # Scopes
+  /// * `read:organization_member_roles`
+  pub fn get_member_roles<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    user_id: T,
+  ) -> OrganizationMemberRolesGet<'_> {
+    OrganizationMemberRolesGet::new(&self.0, id, user_id)
+  }
+
+  /// List organization members using checkpoint pagination.  Use
+  /// [OrganizationMembers::next] with [CursorPageable::from](crate::CursorPageable::from)
+  /// to request the following page.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// # Scopes
+  /// * `read:organization_members`
+  pub fn get_members<S: AsRef<str>>(&self, id: S) -> OrganizationMembersGet<'_> {
+    OrganizationMembersGet::new(&self.0, id)
+  }
+
+  /// Retrieve detailed list of all organizations available in your tenant.
+  ///
+  /// # Scopes
+  /// * `read:organizations`
+  pub fn list(&self) -> OrganizationsGet<'_> {
+    OrganizationsGet::new(&self.0)
+  }
+
+  /// Modify the details of a connection enabled for an organization.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `connection_id` - The ID of the enabled connection.
+  /// # Scopes
+  /// * `update:organization_connections`
+  pub fn update_connection<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    connection_id: T,
+  ) -> OrganizationConnectionUpdate<'_> {
+    OrganizationConnectionUpdate::new(&self.0, id, connection_id)
+  }
+
+  /// Update the details of a specific organization, such as name and display name,
+  /// branding options, and metadata.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization to update.
+  /// # Scopes
+  /// * `update:organizations`
+  pub fn update<S: AsRef<str>>(&self, id: S) -> OrganizationUpdate<'_, ()> {
+    OrganizationUpdate::new(&self.0, id)
+  }
+}
@@ -0,0 +1,37 @@
+//! Organization
+use serde::{Deserialize, Serialize};
+
+/// Organization branding.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct OrganizationBranding {
+  /// URL of logo to display on login page.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub logo_url: Option<String>,
+  /// Color scheme used to customize the login pages.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub colors: Option<OrganizationColors>,
+}
+
+/// Organization branding colors.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OrganizationColors {
+  /// HEX Color for primary elements.
+  pub primary: String,
+  /// HEX Color for background.
+  pub page_background: String,
+}
+
+/// Organization
+#[derive(Deserialize, Debug)]
+pub struct Organization<Metadata> {
+  /// Organization identifier.
+  pub id: String,
+  /// The name of this organization.
+  pub name: String,
+  /// Friendly name of this organization.
+  pub display_name: Option<String>,
+  /// Theme defines how to style the login pages.
+  pub branding: Option<OrganizationBranding>,
+  /// Metadata associated with the organization.
+  pub metadata: Option<Metadata>,
+}
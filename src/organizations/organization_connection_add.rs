@@ -0,0 +1,71 @@
+//! Enable a connection for an organization.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Enable a connection for an organization.
+///
+/// # Scopes
+/// * `create:organization_connections`
+#[derive(Serialize)]
+pub struct OrganizationConnectionAdd<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  connection_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  assign_membership_on_login: Option<bool>,
+}
+
+impl<'a> OrganizationConnectionAdd<'a> {
+  /// Create add organization connection request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `connection_id` - The ID of the connection to enable.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(
+    client: &'a Auth0Client,
+    id: S,
+    connection_id: T,
+  ) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      connection_id: connection_id.as_ref().to_string(),
+      assign_membership_on_login: None,
+    }
+  }
+
+  /// When true, all users that log in with this connection will be automatically granted
+  /// membership in the organization.  When false, users must be granted membership in the
+  /// organization before logging in with this connection.
+  pub fn assign_membership_on_login(mut self, assign: bool) -> Self {
+    self.assign_membership_on_login = Some(assign);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationConnectionAdd<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationConnectionAdd<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::POST,
+        &format!(
+          "api/v2/organizations/{}/enabled_connections",
+          path_segment(&self.id)
+        ),
+      )
+      .json(self)
+  }
+}
@@ -0,0 +1,41 @@
+//! Disable a connection for an organization.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Disable a connection for an organization.
+///
+/// # Scopes
+/// * `delete:organization_connections`
+pub struct OrganizationConnectionDelete {
+  id: String,
+  connection_id: String,
+}
+
+impl OrganizationConnectionDelete {
+  /// Create delete organization connection request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `connection_id` - The ID of the connection to disable.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(id: S, connection_id: T) -> Self {
+    Self {
+      id: id.as_ref().to_string(),
+      connection_id: connection_id.as_ref().to_string(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for OrganizationConnectionDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!(
+        "api/v2/organizations/{}/enabled_connections/{}",
+        path_segment(&self.id),
+        path_segment(&self.connection_id)
+      ),
+    )
+  }
+}
@@ -0,0 +1,85 @@
+//! List the connections enabled for an organization.
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::client::path_segment;
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Connection enabled for an organization.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrganizationConnection {
+  /// ID of the connection.
+  pub connection_id: String,
+  /// When true, all users that log in with this connection will be automatically granted
+  /// membership in the organization.  When false, users must be granted membership in the
+  /// organization before logging in with this connection.
+  pub assign_membership_on_login: bool,
+  /// Summary of the connection.
+  pub connection: OrganizationConnectionSummary,
+}
+
+/// Summary of a connection enabled for an organization.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrganizationConnectionSummary {
+  /// The name of the connection.
+  pub name: String,
+  /// The strategy of the connection.
+  pub strategy: String,
+}
+
+/// List the connections enabled for an organization.
+///
+/// # Scopes
+/// * `read:organization_connections`
+#[derive(Serialize)]
+pub struct OrganizationConnectionsGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> OrganizationConnectionsGet<'a> {
+  /// Create get organization connections request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      page: Default::default(),
+    }
+  }
+}
+
+impl<'a> AsMut<Page> for OrganizationConnectionsGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationConnectionsGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationConnectionsGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::GET,
+        &format!(
+          "api/v2/organizations/{}/enabled_connections",
+          path_segment(&self.id)
+        ),
+      )
+      .query(&self)
+  }
+}
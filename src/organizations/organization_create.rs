@@ -0,0 +1,88 @@
+//! Create an organization.
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::organizations::{Organization, OrganizationBranding};
+use crate::{Auth0Client, Auth0Result};
+
+/// Create a new organization.
+#[derive(Serialize, Clone, Debug)]
+pub struct OrganizationCreate<'a, M> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  display_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  branding: Option<OrganizationBranding>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  metadata: Option<M>,
+}
+
+impl<'a> OrganizationCreate<'a, ()> {
+  /// Create create organization request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of this organization.
+  pub fn new(client: &'a Auth0Client, name: &str) -> Self {
+    Self {
+      client,
+
+      name: name.to_owned(),
+      display_name: None,
+      branding: None,
+
+      metadata: None,
+    }
+  }
+}
+
+impl<'a, M> OrganizationCreate<'a, M> {
+  /// Friendly name of this organization.
+  pub fn display_name(mut self, display_name: &str) -> Self {
+    self.display_name = Some(display_name.to_owned());
+    self
+  }
+
+  /// Theme defines how to style the login pages.
+  pub fn branding(mut self, branding: OrganizationBranding) -> Self {
+    self.branding = Some(branding);
+    self
+  }
+
+  /// Metadata associated with the organization.
+  pub fn metadata<Metadata>(
+    self,
+    metadata: Metadata,
+  ) -> OrganizationCreate<'a, Metadata> {
+    OrganizationCreate {
+      client: self.client,
+      name: self.name,
+      display_name: self.display_name,
+      branding: self.branding,
+      metadata: Some(metadata),
+    }
+  }
+}
+
+impl<'a, MIn> OrganizationCreate<'a, MIn> {
+  /// Send
+  pub async fn send<MOut>(&self) -> Auth0Result<Organization<MOut>>
+  where
+    MIn: Serialize,
+    MOut: DeserializeOwned,
+  {
+    self
+      .client
+      .send(
+        self
+          .client
+          .begin(Method::POST, "api/v2/organizations")
+          .json(self),
+      )
+      .await
+  }
+}
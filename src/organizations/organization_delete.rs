@@ -0,0 +1,27 @@
+//! Delete an organization.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete a single organization and its relationships.
+///
+/// # Scopes
+/// * `delete:organizations`
+pub struct OrganizationDelete(String);
+
+impl OrganizationDelete {
+  /// Create delete organization request.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for OrganizationDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/organizations/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,63 @@
+//! Retrieve a single organization.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve a single organization by its ID.
+///
+/// # Scopes
+/// * `read:organizations`
+pub struct OrganizationGet {
+  id: String,
+}
+
+impl OrganizationGet {
+  /// Create get organization request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization to retrieve.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self {
+      id: id.as_ref().to_string(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for OrganizationGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/organizations/{}", path_segment(&self.id)),
+    )
+  }
+}
+
+/// Retrieve a single organization by its name.
+///
+/// # Scopes
+/// * `read:organizations`
+pub struct OrganizationGetByName {
+  name: String,
+}
+
+impl OrganizationGetByName {
+  /// Create get organization by name request.
+  ///
+  /// # Arguments
+  /// * `name` - The name of the organization to retrieve.
+  pub fn new<S: AsRef<str>>(name: S) -> Self {
+    Self {
+      name: name.as_ref().to_string(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for OrganizationGetByName {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/organizations/name/{}", path_segment(&self.name)),
+    )
+  }
+}
@@ -0,0 +1,126 @@
+//! Create an organization invitation.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::organizations::{
+  OrganizationInvitationInvitee, OrganizationInvitationInviter,
+};
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Create an invitation for a user to join an organization.
+///
+/// # Scopes
+/// * `create:organization_invitations`
+#[derive(Serialize)]
+pub struct OrganizationInvitationCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  inviter: OrganizationInvitationInviter,
+  invitee: OrganizationInvitationInvitee,
+  client_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  connection_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  ttl_sec: Option<u32>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  roles: Vec<String>,
+  send_invitation_email: bool,
+}
+
+impl<'a> OrganizationInvitationCreate<'a> {
+  /// Create create organization invitation request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `inviter` - The name of the user sending the invitation.
+  /// * `invitee` - The email address of the user being invited.
+  /// * `client_id` - Auth0 client ID used to resolve the application's login initiation
+  ///   uri.
+  pub fn new<S: AsRef<str>>(
+    client: &'a Auth0Client,
+    id: S,
+    inviter: &str,
+    invitee: &str,
+    client_id: &str,
+  ) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      inviter: OrganizationInvitationInviter {
+        name: inviter.to_owned(),
+      },
+      invitee: OrganizationInvitationInvitee {
+        email: invitee.to_owned(),
+      },
+      client_id: client_id.to_owned(),
+      connection_id: None,
+      ttl_sec: None,
+      roles: Vec::new(),
+      send_invitation_email: true,
+    }
+  }
+
+  /// The ID of the connection to force invitee to authenticate with.
+  pub fn connection_id(mut self, connection_id: &str) -> Self {
+    self.connection_id = Some(connection_id.to_owned());
+    self
+  }
+
+  /// Number of seconds for which the invitation is valid before expiration.  If
+  /// unspecified or set to 0, this value defaults to 604800 seconds (7 days).  Max value:
+  /// 2592000 seconds (30 days).
+  pub fn ttl_sec(mut self, ttl_sec: u32) -> Self {
+    self.ttl_sec = Some(ttl_sec);
+    self
+  }
+
+  /// Add role to be assigned to the user once the invitation is accepted.
+  ///
+  /// # Arguments
+  /// * `role_id` - The ID of the role.
+  pub fn role(mut self, role_id: &str) -> Self {
+    self.roles.push(role_id.to_owned());
+    self
+  }
+
+  /// Add multiple roles to be assigned to the user once the invitation is accepted.
+  ///
+  /// # Arguments
+  /// * `role_ids` - The IDs of the roles.
+  pub fn roles(mut self, role_ids: &[&str]) -> Self {
+    self.roles.extend(role_ids.iter().map(|id| id.to_string()));
+    self
+  }
+
+  /// Whether the user will receive an invitation email (true, default) or no email
+  /// (false), e.g. when the invitation URL is delivered another way.
+  pub fn send_invitation_email(mut self, send_invitation_email: bool) -> Self {
+    self.send_invitation_email = send_invitation_email;
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationInvitationCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationInvitationCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::POST,
+        &format!(
+          "api/v2/organizations/{}/invitations",
+          path_segment(&self.id)
+        ),
+      )
+      .json(self)
+  }
+}
@@ -0,0 +1,41 @@
+//! Delete an organization invitation.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete an organization invitation.
+///
+/// # Scopes
+/// * `delete:organization_invitations`
+pub struct OrganizationInvitationDelete {
+  id: String,
+  invitation_id: String,
+}
+
+impl OrganizationInvitationDelete {
+  /// Create delete organization invitation request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `invitation_id` - The ID of the invitation.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(id: S, invitation_id: T) -> Self {
+    Self {
+      id: id.as_ref().to_string(),
+      invitation_id: invitation_id.as_ref().to_string(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for OrganizationInvitationDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!(
+        "api/v2/organizations/{}/invitations/{}",
+        path_segment(&self.id),
+        path_segment(&self.invitation_id)
+      ),
+    )
+  }
+}
@@ -0,0 +1,116 @@
+//! List the invitations of an organization.
+use chrono::{DateTime, Utc};
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+use crate::{Fields, Page};
+
+/// Organization invitation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrganizationInvitation {
+  /// The ID of the user invitation.
+  pub id: String,
+  /// The ID of the organization.
+  pub organization_id: String,
+  /// The user sending the invitation.
+  pub inviter: OrganizationInvitationInviter,
+  /// The user being invited.
+  pub invitee: OrganizationInvitationInvitee,
+  /// The invitation url to be sent to the invitee.  Embed this in a custom email or
+  /// return it to the front-end when Auth0 isn't sending the invitation email.
+  pub invitation_url: String,
+  /// The ID of the invitation ticket.
+  pub ticket_id: String,
+  /// The time at which the invitation was created.
+  pub created_at: DateTime<Utc>,
+  /// The time at which the invitation expires.
+  pub expires_at: DateTime<Utc>,
+  /// The ID of the connection to force invitee to authenticate with.
+  pub connection_id: Option<String>,
+  /// Auth0 client ID.  Used to resolve the application's login initiation uri.
+  pub client_id: String,
+  /// IDs of the roles to be assigned to the user once the invitation is accepted.
+  #[serde(default)]
+  pub roles: Vec<String>,
+}
+
+/// The user sending an organization invitation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationInvitationInviter {
+  /// The name of the user sending the invitation.
+  pub name: String,
+}
+
+/// The user receiving an organization invitation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationInvitationInvitee {
+  /// The email address of the user being invited.
+  pub email: String,
+}
+
+/// List the invitations of an organization.
+///
+/// # Scopes
+/// * `read:organization_invitations`
+#[derive(Serialize)]
+pub struct OrganizationInvitationsGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(flatten)]
+  page: Page,
+  #[serde(flatten)]
+  fields: Fields,
+}
+
+impl<'a> OrganizationInvitationsGet<'a> {
+  /// Create get organization invitations request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      page: Default::default(),
+      fields: Default::default(),
+    }
+  }
+}
+
+impl<'a> AsMut<Page> for OrganizationInvitationsGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsMut<Fields> for OrganizationInvitationsGet<'a> {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationInvitationsGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationInvitationsGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::GET,
+        &format!(
+          "api/v2/organizations/{}/invitations",
+          path_segment(&self.id)
+        ),
+      )
+      .query(&self)
+  }
+}
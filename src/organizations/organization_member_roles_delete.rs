@@ -0,0 +1,82 @@
+//! Remove roles from an organization member.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Remove one or more roles from a given user in the context of the organization.
+///
+/// # Scopes
+/// * `delete:organization_member_roles`
+#[derive(Serialize)]
+pub struct OrganizationMemberRolesDelete<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing)]
+  user_id: String,
+  roles: Vec<String>,
+}
+
+impl<'a> OrganizationMemberRolesDelete<'a> {
+  /// Create delete organization member roles request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `user_id` - The ID of the user.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(
+    client: &'a Auth0Client,
+    id: S,
+    user_id: T,
+  ) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      user_id: user_id.as_ref().to_string(),
+      roles: Vec::new(),
+    }
+  }
+
+  /// Add role to request.
+  ///
+  /// # Arguments
+  /// * `role_id` - The ID of the role to remove.
+  pub fn role(mut self, role_id: &str) -> Self {
+    self.roles.push(role_id.to_owned());
+    self
+  }
+
+  /// Add multiple roles to request.
+  ///
+  /// # Arguments
+  /// * `role_ids` - The IDs of the roles to remove.
+  pub fn roles(mut self, role_ids: &[&str]) -> Self {
+    self.roles.extend(role_ids.iter().map(|id| id.to_string()));
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationMemberRolesDelete<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationMemberRolesDelete<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::DELETE,
+        &format!(
+          "api/v2/organizations/{}/members/{}/roles",
+          path_segment(&self.id),
+          path_segment(&self.user_id)
+        ),
+      )
+      .json(self)
+  }
+}
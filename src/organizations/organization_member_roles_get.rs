@@ -0,0 +1,72 @@
+//! Get the roles assigned to an organization member.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Get the roles assigned to an organization member.
+///
+/// # Scopes
+/// * `read:organization_member_roles`
+#[derive(Serialize)]
+pub struct OrganizationMemberRolesGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing)]
+  user_id: String,
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> OrganizationMemberRolesGet<'a> {
+  /// Create get organization member roles request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  /// * `user_id` - The ID of the user.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(
+    client: &'a Auth0Client,
+    id: S,
+    user_id: T,
+  ) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      user_id: user_id.as_ref().to_string(),
+      page: Default::default(),
+    }
+  }
+}
+
+impl<'a> AsMut<Page> for OrganizationMemberRolesGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationMemberRolesGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationMemberRolesGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::GET,
+        &format!(
+          "api/v2/organizations/{}/members/{}/roles",
+          path_segment(&self.id),
+          path_segment(&self.user_id)
+        ),
+      )
+      .query(&self)
+  }
+}
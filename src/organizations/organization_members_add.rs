@@ -0,0 +1,72 @@
+//! Add members to an organization.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Add members to an organization.
+///
+/// # Scopes
+/// * `create:organization_members`
+#[derive(Serialize)]
+pub struct OrganizationMembersAdd<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  members: Vec<String>,
+}
+
+impl<'a> OrganizationMembersAdd<'a> {
+  /// Create add organization members request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      members: Vec::new(),
+    }
+  }
+
+  /// Add user to request.
+  ///
+  /// # Arguments
+  /// * `user_id` - The ID of the user to add.
+  pub fn member(mut self, user_id: &str) -> Self {
+    self.members.push(user_id.to_owned());
+    self
+  }
+
+  /// Add multiple users to request.
+  ///
+  /// # Arguments
+  /// * `user_ids` - The IDs of the users to add.
+  pub fn members(mut self, user_ids: &[&str]) -> Self {
+    self
+      .members
+      .extend(user_ids.iter().map(|id| id.to_string()));
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationMembersAdd<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationMembersAdd<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::POST,
+        &format!("api/v2/organizations/{}/members", path_segment(&self.id)),
+      )
+      .json(self)
+  }
+}
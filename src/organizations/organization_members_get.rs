@@ -0,0 +1,140 @@
+//! List the members of an organization.
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, Auth0Result};
+use crate::{CursorPage, Role};
+
+/// Organization member.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrganizationMember {
+  /// ID of this user.
+  pub user_id: String,
+  /// Email address of this user.
+  pub email: Option<String>,
+  /// Name of this user.
+  pub name: Option<String>,
+  /// URL to a picture for this user.
+  pub picture: Option<String>,
+  /// Roles assigned to this user within the organization.  Only returned when requested
+  /// with the `roles` field.
+  pub roles: Option<Vec<Role>>,
+}
+
+/// A page of organization members.
+#[derive(Debug, Deserialize)]
+pub struct OrganizationMembers {
+  /// Members of the organization.
+  pub members: Vec<OrganizationMember>,
+  /// Checkpoint token used to request the next page.  `None` once the last page was
+  /// returned.
+  pub next: Option<String>,
+}
+
+/// List organization members.
+///
+/// # Scopes
+/// * `read:organization_members`
+#[derive(Serialize)]
+pub struct OrganizationMembersGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(flatten)]
+  page: CursorPage,
+}
+
+impl<'a> OrganizationMembersGet<'a> {
+  /// Create get organization members request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the organization.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      page: Default::default(),
+    }
+  }
+
+  /// Retrieve every member that has been assigned the given role.
+  ///
+  /// Auth0 can't filter organization members by role, so this requests every page of
+  /// members (along with their roles) starting from the current checkpoint and filters
+  /// them client side.  This costs one request per page of members which makes it slow
+  /// for large organizations.  When performance matters, prefer listing the users
+  /// assigned to the role and checking them against the organization instead.
+  ///
+  /// # Arguments
+  /// * `role_id` - The ID of the role members must have.
+  pub async fn with_role_filter(
+    &self,
+    role_id: &str,
+  ) -> Auth0Result<Vec<OrganizationMember>> {
+    let mut found = Vec::new();
+    let mut page = CursorPage {
+      from: self.page.from.clone(),
+      take: self.page.take,
+    };
+
+    loop {
+      let members: OrganizationMembers = self
+        .client
+        .send(
+          self
+            .client
+            .begin(
+              Method::GET,
+              &format!("api/v2/organizations/{}/members", path_segment(&self.id)),
+            )
+            .query(&page)
+            .query(&[
+              ("fields", "user_id,email,name,picture,roles"),
+              ("include_fields", "true"),
+            ]),
+        )
+        .await?;
+
+      found.extend(
+        members
+          .members
+          .into_iter()
+          .filter(|member| member.roles.iter().flatten().any(|role| role.id == role_id)),
+      );
+
+      match members.next {
+        Some(next) => page.from = Some(next),
+        None => break,
+      }
+    }
+
+    Ok(found)
+  }
+}
+
+impl<'a> AsMut<CursorPage> for OrganizationMembersGet<'a> {
+  fn as_mut(&mut self) -> &mut CursorPage {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationMembersGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationMembersGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::GET,
+        &format!("api/v2/organizations/{}/members", path_segment(&self.id)),
+      )
+      .query(&self)
+  }
+}
@@ -0,0 +1,100 @@
+//! Update an organization.
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::organizations::{Organization, OrganizationBranding};
+use crate::{Auth0Client, Auth0Result};
+
+/// Modify an organization.
+#[derive(Serialize, Clone, Debug)]
+pub struct OrganizationUpdate<'a, M> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  display_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  branding: Option<OrganizationBranding>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  metadata: Option<M>,
+}
+
+impl<'a> OrganizationUpdate<'a, ()> {
+  /// Create update organization request.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      name: None,
+      display_name: None,
+      branding: None,
+
+      metadata: None,
+    }
+  }
+}
+
+impl<'a, M> OrganizationUpdate<'a, M> {
+  /// The name of this organization.
+  pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
+    self
+  }
+
+  /// Friendly name of this organization.
+  pub fn display_name(mut self, display_name: &str) -> Self {
+    self.display_name = Some(display_name.to_owned());
+    self
+  }
+
+  /// Theme defines how to style the login pages.
+  pub fn branding(mut self, branding: OrganizationBranding) -> Self {
+    self.branding = Some(branding);
+    self
+  }
+
+  /// Metadata associated with the organization.
+  pub fn metadata<Metadata>(
+    self,
+    metadata: Metadata,
+  ) -> OrganizationUpdate<'a, Metadata> {
+    OrganizationUpdate {
+      client: self.client,
+      id: self.id,
+      name: self.name,
+      display_name: self.display_name,
+      branding: self.branding,
+      metadata: Some(metadata),
+    }
+  }
+}
+
+impl<'a, MIn> OrganizationUpdate<'a, MIn> {
+  /// Send
+  pub async fn send<MOut>(&self) -> Auth0Result<Organization<MOut>>
+  where
+    MIn: Serialize,
+    MOut: DeserializeOwned,
+  {
+    self
+      .client
+      .send(
+        self
+          .client
+          .begin(
+            Method::PATCH,
+            &format!("api/v2/organizations/{}", path_segment(&self.id)),
+          )
+          .json(self),
+      )
+      .await
+  }
+}
@@ -0,0 +1,108 @@
+//! Retrieve a list of organizations.
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::organizations::Organization;
+use crate::{Auth0Client, Auth0RequestBuilder, Auth0Result};
+use crate::{CursorPage, Page};
+
+/// Retrieve a list of organizations.
+///
+/// # Scopes
+/// * `read:organizations`
+#[derive(Serialize)]
+pub struct OrganizationsGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> OrganizationsGet<'a> {
+  /// Create get organizations request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      page: Default::default(),
+    }
+  }
+
+  /// Retrieve every organization whose metadata has `key` set to `value`.
+  ///
+  /// Auth0 cannot filter organizations by metadata, so this fetches **all**
+  /// organizations, page by page, and filters them locally.  It is O(n) in requests and
+  /// memory and should not be used on tenants with large organization catalogs.  Pages
+  /// are requested with checkpoint pagination, which is not capped at 1000 results like
+  /// offset pagination.  Any paging set on this request is ignored.
+  pub async fn metadata_filter(
+    &self,
+    key: &str,
+    value: &str,
+  ) -> Auth0Result<Vec<Organization<HashMap<String, String>>>> {
+    let mut page = CursorPage {
+      from: None,
+      take: 100,
+    };
+    let mut matches = Vec::new();
+
+    loop {
+      let organizations: OrganizationsCheckpoint = self
+        .client
+        .send(
+          self
+            .client
+            .begin(Method::GET, "api/v2/organizations")
+            .query(&page),
+        )
+        .await?;
+
+      matches.extend(
+        organizations
+          .organizations
+          .into_iter()
+          .filter(|organization| {
+            organization
+              .metadata
+              .as_ref()
+              .and_then(|metadata| metadata.get(key))
+              .is_some_and(|found| found == value)
+          }),
+      );
+
+      match organizations.next {
+        Some(next) => page.from = Some(next),
+        None => return Ok(matches),
+      }
+    }
+  }
+}
+
+/// A page of organizations returned by checkpoint pagination.
+#[derive(Deserialize)]
+struct OrganizationsCheckpoint {
+  organizations: Vec<Organization<HashMap<String, String>>>,
+  next: Option<String>,
+}
+
+impl<'a> AsMut<Page> for OrganizationsGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for OrganizationsGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for OrganizationsGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::GET, "api/v2/organizations")
+      .query(&self)
+  }
+}
@@ -1,14 +1,24 @@
 //! Paging helper.
-use serde::Serialize;
+use std::convert::TryFrom;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{self, Stream};
+use reqwest::Method;
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{Auth0Client, Auth0RequestBuilder, Auth0Result};
 
 /// Provides serializable pagination parameters.
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 pub struct Page {
   #[serde(skip_serializing_if = "Option::is_none")]
-  page: Option<u32>,
+  pub(crate) page: Option<u32>,
   #[serde(rename = "per_page")]
   #[serde(skip_serializing_if = "Option::is_none")]
-  per_page: Option<u32>,
+  pub(crate) per_page: Option<u32>,
 }
 
 impl Page {
@@ -18,31 +28,284 @@ impl Page {
   }
 }
 
-impl Default for Page {
-  fn default() -> Self {
-    Self {
-      page: None,
-      per_page: None,
-    }
-  }
-}
-
 /// Build pagination parameters.
 pub trait Pageable {
   /// Page index of the results to return.  First page is `0`.
-  fn page(&mut self, page: u32) -> &mut Self;
+  fn page(self, page: u32) -> Self;
   /// Number of results per page.  Paging is disabled if parameter is `Option::None`
-  fn per_page(&mut self, per_page: u32) -> &mut Self;
+  fn per_page(self, per_page: u32) -> Self;
 }
 
 impl<P: AsMut<Page>> Pageable for P {
-  fn page(&mut self, page: u32) -> &mut Self {
+  fn page(mut self, page: u32) -> Self {
     self.as_mut().page = Some(page);
     self
   }
 
-  fn per_page(&mut self, per_page: u32) -> &mut Self {
+  fn per_page(mut self, per_page: u32) -> Self {
     self.as_mut().per_page = Some(per_page);
     self
   }
 }
+
+/// Provides serializable checkpoint pagination parameters.
+///
+/// Checkpoint pagination replaces the page index with the `next` token returned in the
+/// previous response.  The first page is requested by omitting `from`.
+#[derive(Serialize)]
+pub struct CursorPage {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) from: Option<String>,
+  pub(crate) take: usize,
+}
+
+impl CursorPage {
+  /// Create checkpoint pagination instance.
+  pub fn new() -> Self {
+    Default::default()
+  }
+}
+
+impl Default for CursorPage {
+  fn default() -> Self {
+    Self {
+      from: None,
+      take: 50,
+    }
+  }
+}
+
+/// Build checkpoint pagination parameters.
+pub trait CursorPageable {
+  /// Checkpoint token to start retrieving results from.  Use the `next` value of the
+  /// previous response.
+  fn from(self, from: &str) -> Self;
+  /// Number of results per page.  Defaults to `50`.
+  fn take(self, take: usize) -> Self;
+}
+
+impl<P: AsMut<CursorPage>> CursorPageable for P {
+  fn from(mut self, from: &str) -> Self {
+    self.as_mut().from = Some(from.to_owned());
+    self
+  }
+
+  fn take(mut self, take: usize) -> Self {
+    self.as_mut().take = take;
+    self
+  }
+}
+
+/// Next page to request when streaming results.
+pub(crate) enum NextPage {
+  /// Page index, for offset pagination.
+  Index(u32),
+  /// URL from the `rel="next"` link of the previous response.
+  Link(String),
+}
+
+/// Read the `rel="next"` URL from an [RFC 5988](https://tools.ietf.org/html/rfc5988)
+/// `Link` header, e.g. `<https://example.auth0.com/api/v2/users?page=1>; rel="next"`.
+pub fn next_link(header: &str) -> Option<String> {
+  link_values(header).find_map(|link| {
+    let mut parts = link.split(';');
+    let url = parts.next()?.trim().strip_prefix('<')?.strip_suffix('>')?;
+    let is_next = parts.any(|param| {
+      let param = param.trim();
+      param
+        .strip_prefix("rel=")
+        .map(|rel| {
+          rel
+            .trim_matches('"')
+            .split_whitespace()
+            .any(|rel| rel.eq_ignore_ascii_case("next"))
+        })
+        .unwrap_or(false)
+    });
+
+    if is_next {
+      Some(url.to_owned())
+    } else {
+      None
+    }
+  })
+}
+
+/// Split a `Link` header into its comma separated values.  Commas inside a `<...>` URL,
+/// e.g. `fields=a,b`, are part of the URL.
+fn link_values(header: &str) -> impl Iterator<Item = &str> {
+  let mut in_url = false;
+  let mut start = 0;
+  let mut values = Vec::new();
+
+  for (i, c) in header.char_indices() {
+    match c {
+      '<' => in_url = true,
+      '>' => in_url = false,
+      ',' if !in_url => {
+        values.push(&header[start..i]);
+        start = i + 1;
+      }
+      _ => {}
+    }
+  }
+  values.push(&header[start..]);
+
+  values.into_iter()
+}
+
+/// A page of results returned when `include_totals` is requested.
+///
+/// Auth0 wraps the results in an object keyed by the resource name (e.g.
+/// `resource_servers`) together with the paging totals.  A plain array, returned when
+/// totals are not requested, is accepted as well.  An object with more than one array
+/// is rejected, as the results could not be told apart.
+#[derive(Debug, Clone)]
+pub struct ResponsePage<T> {
+  /// Results in this page.
+  pub items: Vec<T>,
+  /// Index of the first result in this page.
+  pub start: Option<u32>,
+  /// Maximum number of results per page.
+  pub limit: Option<u32>,
+  /// Total number of results.
+  pub total: Option<u32>,
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for ResponsePage<T> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let number = |map: &Map<String, Value>, key: &str| {
+      map
+        .get(key)
+        .and_then(Value::as_u64)
+        .and_then(|n| u32::try_from(n).ok())
+    };
+
+    let (items, start, limit, total) = match Value::deserialize(deserializer)? {
+      Value::Array(items) => (Value::Array(items), None, None, None),
+      Value::Object(mut map) => {
+        let start = number(&map, "start");
+        let limit = number(&map, "limit");
+        let total = number(&map, "total");
+        let keys = map
+          .iter()
+          .filter(|(_, value)| value.is_array())
+          .map(|(key, _)| key.clone())
+          .collect::<Vec<_>>();
+        let key = match keys.as_slice() {
+          [key] => key.clone(),
+          [] => return Err(D::Error::custom("missing results array")),
+          keys => {
+            return Err(D::Error::custom(format!(
+              "ambiguous results array, found {}",
+              keys.join(", ")
+            )))
+          }
+        };
+
+        (map.remove(&key).unwrap(), start, limit, total)
+      }
+      _ => return Err(D::Error::custom("expected array or object")),
+    };
+
+    Ok(Self {
+      items: serde_json::from_value(items).map_err(D::Error::custom)?,
+      start,
+      limit,
+      total,
+    })
+  }
+}
+
+/// Stream of every page of results of a paginated request.
+///
+/// Pages are requested lazily, starting from the request's page (or the first page).  The
+/// page index is incremented after each page until a page returns fewer items than
+/// `per_page` (`50` when not set).  When a response carries a `Link` header with a
+/// `rel="next"` URL, that URL is followed instead.  The stream ends after yielding the
+/// first error.
+///
+/// Dropping the stream part way through stops fetching; no request is left running in
+/// the background.
+///
+/// ```
+/// use auth0_management::{Auth0, PaginatedStream, Rule};
+/// use futures::TryStreamExt;
+///
+/// async fn all_rules(auth0: &Auth0) {
+///   let pages: Vec<Vec<Rule>> = PaginatedStream::new(auth0.rules.list())
+///     .try_collect()
+///     .await
+///     .unwrap();
+/// }
+/// ```
+pub struct PaginatedStream<'a, T> {
+  inner: Pin<Box<dyn Stream<Item = Auth0Result<Vec<T>>> + Send + 'a>>,
+}
+
+impl<'a, T> PaginatedStream<'a, T>
+where
+  T: DeserializeOwned + Send + 'a,
+{
+  /// Create stream of every page of results of `req`.
+  pub fn new<R>(mut req: R) -> Self
+  where
+    R: AsMut<Page> + AsRef<Auth0Client> + Auth0RequestBuilder + Send + 'a,
+  {
+    let page = req.as_mut();
+    let per_page = page.per_page.unwrap_or(50);
+    let first = page.page.unwrap_or(0);
+
+    page.per_page = Some(per_page);
+
+    let inner = stream::unfold(
+      Some((req, NextPage::Index(first))),
+      move |state| async move {
+        let (mut req, page) = state?;
+
+        let http = match &page {
+          NextPage::Index(page) => {
+            req.as_mut().page = Some(*page);
+            req.build(req.as_ref())
+          }
+          NextPage::Link(url) => match req.as_ref().begin_url(Method::GET, url) {
+            Ok(http) => http,
+            Err(err) => return Some((Err(err), None)),
+          },
+        };
+
+        let res = req.as_ref().send_paged::<ResponsePage<T>>(http).await;
+        match res {
+          Ok((items, link)) => {
+            let items = items.items;
+            let next = match (link, page) {
+              (Some(link), _) => Some((req, NextPage::Link(link))),
+              (None, NextPage::Index(page)) if items.len() >= per_page as usize => {
+                Some((req, NextPage::Index(page + 1)))
+              }
+              (None, _) => None,
+            };
+
+            Some((Ok(items), next))
+          }
+          Err(err) => Some((Err(err), None)),
+        }
+      },
+    );
+
+    Self {
+      inner: Box::pin(inner),
+    }
+  }
+}
+
+impl<'a, T> Stream for PaginatedStream<'a, T> {
+  type Item = Auth0Result<Vec<T>>;
+
+  fn poll_next(
+    mut self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Option<Self::Item>> {
+    self.inner.as_mut().poll_next(cx)
+  }
+}
@@ -0,0 +1,85 @@
+#![warn(missing_docs)]
+//! Prompt request builders.
+
+#[doc(inline)]
+pub use prompt::*;
+#[doc(inline)]
+pub use prompt_custom_text_get::*;
+#[doc(inline)]
+pub use prompt_custom_text_update::*;
+#[doc(inline)]
+pub use prompt_settings::*;
+#[doc(inline)]
+pub use prompt_settings_get::*;
+#[doc(inline)]
+pub use prompt_settings_update::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub mod prompt;
+pub mod prompt_custom_text_get;
+pub mod prompt_custom_text_update;
+pub mod prompt_settings;
+pub mod prompt_settings_get;
+pub mod prompt_settings_update;
+
+/// Prompts manager
+pub struct PromptsManager(Arc<Auth0Client>);
+
+impl PromptsManager {
+  /// Create prompts manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve the Universal Login prompt settings.
+  ///
+  /// # Scopes
+  /// * `read:prompts`
+  pub async fn get(&self) -> Auth0Result<PromptSettings> {
+    PromptSettingsGet::new().send_to(&self.0).await
+  }
+
+  /// Update the Universal Login prompt settings.
+  ///
+  /// # Scopes
+  /// * `update:prompts`
+  pub fn update(&self) -> PromptSettingsUpdate<'_> {
+    PromptSettingsUpdate::new(&self.0)
+  }
+
+  /// Retrieve the custom text of a prompt, keyed by screen and then by text key.
+  ///
+  /// # Arguments
+  /// * `prompt` - The prompt to retrieve the text of.
+  /// * `language` - IETF BCP 47 language tag, e.g. `en`.
+  /// # Scopes
+  /// * `read:prompts`
+  pub async fn get_custom_text(
+    &self,
+    prompt: Prompt,
+    language: &str,
+  ) -> Auth0Result<HashMap<String, HashMap<String, String>>> {
+    PromptCustomTextGet::new(prompt, language)
+      .send_to(&self.0)
+      .await
+  }
+
+  /// Set the custom text of a prompt.  Replaces all existing custom text of the prompt in
+  /// that language.
+  ///
+  /// # Arguments
+  /// * `prompt` - The prompt to set the text of.
+  /// * `language` - IETF BCP 47 language tag, e.g. `en`.
+  /// # Scopes
+  /// * `update:prompts`
+  pub fn set_custom_text(
+    &self,
+    prompt: Prompt,
+    language: &str,
+  ) -> PromptCustomTextUpdate<'_> {
+    PromptCustomTextUpdate::new(&self.0, prompt, language)
+  }
+}
@@ -0,0 +1,77 @@
+//! Universal Login prompts.
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Universal Login prompt, i.e. a group of screens whose text can be customized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Prompt {
+  /// Login.
+  Login,
+  /// Sign up.
+  Signup,
+  /// Identifier first sign up.
+  SignupId,
+  /// Password step of identifier first sign up.
+  SignupPassword,
+  /// Password reset.
+  ResetPassword,
+  /// Consent to third-party application access.
+  Consent,
+  /// MFA with push notifications.
+  MfaPush,
+  /// MFA with one-time passwords.
+  MfaOtp,
+  /// MFA with voice calls.
+  MfaVoice,
+  /// MFA with phone messages.
+  MfaPhone,
+  /// MFA with WebAuthn.
+  MfaWebauthn,
+  /// MFA with SMS.
+  MfaSms,
+  /// MFA with email.
+  MfaEmail,
+  /// MFA with recovery codes.
+  MfaRecoveryCode,
+  /// MFA factor selection.
+  Mfa,
+  /// Status messages.
+  Status,
+  /// Device authorization flow.
+  DeviceFlow,
+  /// Email verification.
+  EmailVerification,
+}
+
+impl Prompt {
+  /// Prompt name as used by the Auth0 API.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Login => "login",
+      Self::Signup => "signup",
+      Self::SignupId => "signup-id",
+      Self::SignupPassword => "signup-password",
+      Self::ResetPassword => "reset-password",
+      Self::Consent => "consent",
+      Self::MfaPush => "mfa-push",
+      Self::MfaOtp => "mfa-otp",
+      Self::MfaVoice => "mfa-voice",
+      Self::MfaPhone => "mfa-phone",
+      Self::MfaWebauthn => "mfa-webauthn",
+      Self::MfaSms => "mfa-sms",
+      Self::MfaEmail => "mfa-email",
+      Self::MfaRecoveryCode => "mfa-recovery-code",
+      Self::Mfa => "mfa",
+      Self::Status => "status",
+      Self::DeviceFlow => "device-flow",
+      Self::EmailVerification => "email-verification",
+    }
+  }
+}
+
+impl Display for Prompt {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
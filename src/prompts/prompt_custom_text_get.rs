@@ -0,0 +1,44 @@
+//! Retrieve custom text for a prompt.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, Prompt};
+
+/// Retrieve the custom text of a prompt in a language, keyed by screen and then by text
+/// key.
+///
+/// Send as `HashMap<String, HashMap<String, String>>`.
+///
+/// # Scopes
+/// * `read:prompts`
+pub struct PromptCustomTextGet {
+  prompt: Prompt,
+  language: String,
+}
+
+impl PromptCustomTextGet {
+  /// Create get prompt custom text request.
+  ///
+  /// # Arguments
+  /// * `prompt` - The prompt to retrieve the text of.
+  /// * `language` - IETF BCP 47 language tag, e.g. `en` or `pt-BR`.
+  pub fn new(prompt: Prompt, language: &str) -> Self {
+    Self {
+      prompt,
+      language: language.to_owned(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for PromptCustomTextGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!(
+        "api/v2/prompts/{}/custom-text/{}",
+        path_segment(self.prompt.as_str()),
+        path_segment(&self.language)
+      ),
+    )
+  }
+}
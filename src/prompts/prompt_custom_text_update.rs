@@ -0,0 +1,74 @@
+//! Set custom text for a prompt.
+use std::collections::HashMap;
+
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, Prompt};
+
+/// Set the custom text of a prompt in a language.  Replaces all existing custom text of
+/// the prompt in that language.
+///
+/// # Scopes
+/// * `update:prompts`
+#[derive(Serialize)]
+pub struct PromptCustomTextUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  prompt: Prompt,
+  #[serde(skip_serializing)]
+  language: String,
+  #[serde(flatten)]
+  screens: HashMap<String, HashMap<String, String>>,
+}
+
+impl<'a> PromptCustomTextUpdate<'a> {
+  /// Create set prompt custom text request.
+  ///
+  /// # Arguments
+  /// * `prompt` - The prompt to set the text of.
+  /// * `language` - IETF BCP 47 language tag, e.g. `en` or `pt-BR`.
+  pub fn new(client: &'a Auth0Client, prompt: Prompt, language: &str) -> Self {
+    Self {
+      client,
+
+      prompt,
+      language: language.to_owned(),
+      screens: HashMap::new(),
+    }
+  }
+
+  /// Set the text of a key on a screen, e.g. screen `login`, key `title`.
+  pub fn text(mut self, screen: &str, key: &str, text: &str) -> Self {
+    self
+      .screens
+      .entry(screen.to_owned())
+      .or_default()
+      .insert(key.to_owned(), text.to_owned());
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for PromptCustomTextUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for PromptCustomTextUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PUT,
+        &format!(
+          "api/v2/prompts/{}/custom-text/{}",
+          path_segment(self.prompt.as_str()),
+          path_segment(&self.language)
+        ),
+      )
+      .json(self)
+  }
+}
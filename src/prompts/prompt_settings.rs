@@ -0,0 +1,25 @@
+//! Universal Login prompt settings.
+use serde::{Deserialize, Serialize};
+
+/// Which Universal Login experience is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UniversalLoginExperience {
+  /// New Universal Login.
+  New,
+  /// Classic Universal Login.
+  Classic,
+}
+
+/// Universal Login prompt settings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PromptSettings {
+  /// Which Universal Login experience is used.
+  pub universal_login_experience: Option<UniversalLoginExperience>,
+  /// Whether the login page asks for the identifier before the password.
+  #[serde(default)]
+  pub identifier_first: bool,
+  /// Whether the device biometrics can be used as the first factor.
+  #[serde(default)]
+  pub webauthn_platform_first_factor: bool,
+}
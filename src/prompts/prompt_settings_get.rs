@@ -0,0 +1,26 @@
+//! Retrieve prompt settings.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the Universal Login prompt settings.
+///
+/// Send as [PromptSettings](crate::PromptSettings).
+///
+/// # Scopes
+/// * `read:prompts`
+#[derive(Default)]
+pub struct PromptSettingsGet;
+
+impl PromptSettingsGet {
+  /// Create get prompt settings request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for PromptSettingsGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/prompts")
+  }
+}
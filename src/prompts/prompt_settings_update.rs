@@ -0,0 +1,70 @@
+//! Update prompt settings.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, UniversalLoginExperience};
+
+/// Update the Universal Login prompt settings.
+///
+/// Send as [PromptSettings](crate::PromptSettings).
+///
+/// # Scopes
+/// * `update:prompts`
+#[derive(Serialize)]
+pub struct PromptSettingsUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  universal_login_experience: Option<UniversalLoginExperience>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  identifier_first: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  webauthn_platform_first_factor: Option<bool>,
+}
+
+impl<'a> PromptSettingsUpdate<'a> {
+  /// Create update prompt settings request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      universal_login_experience: None,
+      identifier_first: None,
+      webauthn_platform_first_factor: None,
+    }
+  }
+
+  /// Which Universal Login experience to use.
+  pub fn universal_login_experience(
+    mut self,
+    experience: UniversalLoginExperience,
+  ) -> Self {
+    self.universal_login_experience = Some(experience);
+    self
+  }
+
+  /// Whether the login page asks for the identifier before the password.
+  pub fn identifier_first(mut self, identifier_first: bool) -> Self {
+    self.identifier_first = Some(identifier_first);
+    self
+  }
+
+  /// Whether the device biometrics can be used as the first factor.
+  pub fn webauthn_platform_first_factor(mut self, enabled: bool) -> Self {
+    self.webauthn_platform_first_factor = Some(enabled);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for PromptSettingsUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for PromptSettingsUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::PATCH, "api/v2/prompts").json(self)
+  }
+}
@@ -0,0 +1,93 @@
+//! Lucene query builder.
+use std::fmt::{Display, Formatter};
+
+/// Builds a [Lucene query string](https://auth0.com/docs/manage-users/user-search/user-search-query-syntax)
+/// for user and log searches.
+///
+/// ```
+/// use auth0_management::LuceneQuery;
+///
+/// let q = LuceneQuery::new()
+///   .field_boosted_eq("name", "John", 3.0)
+///   .field_boosted_eq("email", "john@*", 1.0);
+///
+/// assert_eq!(q.to_string(), "name:John^3 email:john@*^1");
+///
+/// let q = LuceneQuery::new()
+///   .and_eq("connection", "Username-Password-Authentication")
+///   .and_eq("email_verified", "false")
+///   .or_eq("name", "Jane Doe");
+///
+/// assert_eq!(
+///   q.to_string(),
+///   r#"connection:"Username-Password-Authentication" AND email_verified:false OR name:"Jane Doe""#
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LuceneQuery {
+  terms: Vec<String>,
+}
+
+impl LuceneQuery {
+  /// Create empty query.
+  pub fn new() -> Self {
+    Default::default()
+  }
+
+  /// Match `field` against `value`, scaling the relevance of matches by `boost`.
+  ///
+  /// `value` is used as-is so wildcards such as `john@*` keep working.  Terms are
+  /// separated by whitespace, so results matching any term are returned, ranked by boost.
+  pub fn field_boosted_eq(mut self, field: &str, value: &str, boost: f32) -> Self {
+    self.terms.push(format!("{}:{}^{}", field, value, boost));
+    self
+  }
+
+  /// Require `field` to equal `value`, combined with the previous terms using `AND`.
+  ///
+  /// `value` is quoted when it contains whitespace or Lucene special characters.
+  pub fn and_eq(self, field: &str, value: &str) -> Self {
+    self.push_eq("AND", field, value)
+  }
+
+  /// Match `field` equal to `value`, combined with the previous terms using `OR`.
+  ///
+  /// `value` is quoted when it contains whitespace or Lucene special characters.
+  pub fn or_eq(self, field: &str, value: &str) -> Self {
+    self.push_eq("OR", field, value)
+  }
+
+  fn push_eq(mut self, operator: &str, field: &str, value: &str) -> Self {
+    let term = format!("{}:{}", field, quote(value));
+
+    if self.terms.is_empty() {
+      self.terms.push(term);
+    } else {
+      self.terms.push(format!("{} {}", operator, term));
+    }
+
+    self
+  }
+}
+
+/// Characters with special meaning in Lucene query syntax.
+const SPECIAL: &[char] = &[
+  '+', '-', '&', '|', '!', '(', ')', '{', '}', '[', ']', '^', '"', '~', '*', '?', ':',
+  '\\', '/',
+];
+
+fn quote(value: &str) -> String {
+  if value.is_empty()
+    || value.contains(|c: char| c.is_whitespace() || SPECIAL.contains(&c))
+  {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+  } else {
+    value.to_owned()
+  }
+}
+
+impl Display for LuceneQuery {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.terms.join(" "))
+  }
+}
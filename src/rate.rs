@@ -2,11 +2,14 @@ use std::error::Error;
 use std::num::ParseIntError;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use reqwest::header::ToStrError;
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::header::{HeaderMap, ToStrError, RETRY_AFTER};
 use reqwest::Response;
 use std::fmt::{Display, Formatter};
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 
+use crate::Auth0Error;
+
 /// Provides ability to read rate limit headers and check if limits are exceeded.
 #[derive(Debug)]
 pub struct RateLimit {
@@ -46,41 +49,95 @@ impl RateLimit {
     }
   }
 
-  /// Read response headers and updates limits.
+  /// Read response headers and updates limits.  Limits are left unchanged if any of
+  /// the headers is missing or malformed.
   pub fn read(&self, res: &Response) -> Result<(), RateLimitError> {
     let headers = res.headers();
 
-    self.limit.store(
-      headers
-        .get("x-ratelimit-limit")
-        .ok_or(RateLimitError::MissingRateLimitHeader)?
-        .to_str()?
-        .parse()?,
-      Ordering::SeqCst,
-    );
-
-    self.remaining.store(
-      headers
-        .get("x-ratelimit-remaining")
-        .ok_or(RateLimitError::MissingRateRemainingHeader)?
-        .to_str()?
-        .parse()?,
-      Ordering::SeqCst,
-    );
-
-    self.reset.store(
-      headers
-        .get("x-ratelimit-reset")
-        .ok_or(RateLimitError::MissingRateResetHeader)?
-        .to_str()?
-        .parse::<u64>()?,
-      Ordering::SeqCst,
-    );
+    let limit = headers
+      .get("x-ratelimit-limit")
+      .ok_or(RateLimitError::MissingRateLimitHeader)?
+      .to_str()?
+      .parse()?;
+    let remaining = headers
+      .get("x-ratelimit-remaining")
+      .ok_or(RateLimitError::MissingRateRemainingHeader)?
+      .to_str()?
+      .parse()?;
+    let reset = headers
+      .get("x-ratelimit-reset")
+      .ok_or(RateLimitError::MissingRateResetHeader)?
+      .to_str()?
+      .parse::<u64>()?;
+
+    self.limit.store(limit, Ordering::SeqCst);
+    self.remaining.store(remaining, Ordering::SeqCst);
+    self.reset.store(reset, Ordering::SeqCst);
 
     Ok(())
   }
 }
 
+/// Parse a `Retry-After` header value, either a number of seconds or an HTTP-date.  An
+/// HTTP-date in the past is a zero duration.
+///
+/// # Arguments
+/// * `value` - The header value, e.g. `30` or `Wed, 21 Oct 2015 07:28:00 GMT`.
+/// * `now` - The time the HTTP-date is relative to.
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+  let value = value.trim();
+  if let Ok(secs) = value.parse::<u64>() {
+    return Some(Duration::from_secs(secs));
+  }
+
+  let date = DateTime::parse_from_rfc2822(value).ok()?;
+  Some(
+    (date.with_timezone(&Utc) - now)
+      .to_std()
+      .unwrap_or_default(),
+  )
+}
+
+/// How long to wait before retrying a rate limited request without a `Retry-After` or
+/// `X-RateLimit-Reset` header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Longest wait before retrying a rate limited request.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// How long to wait before retrying a rate limited request.  Uses the `Retry-After`
+/// duration, or else the time until the limit resets, capped at 60 seconds.
+///
+/// # Arguments
+/// * `retry_after` - The `Retry-After` duration, if any.
+/// * `reset` - When the limit resets, from the `X-RateLimit-Reset` header, if any.
+/// * `now` - The time `reset` is relative to.
+pub fn retry_delay(
+  retry_after: Option<Duration>,
+  reset: Option<DateTime<Utc>>,
+  now: DateTime<Utc>,
+) -> Duration {
+  retry_after
+    .or_else(|| reset.map(|reset| (reset - now).to_std().unwrap_or_default()))
+    .unwrap_or(DEFAULT_RETRY_AFTER)
+    .min(MAX_RETRY_AFTER)
+}
+
+/// Read the rate limit headers of a `429 Too Many Requests` response.  Headers that are
+/// missing or malformed are `None`.
+pub(crate) fn rate_limited(headers: &HeaderMap) -> Auth0Error {
+  let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+
+  Auth0Error::RateLimited {
+    retry_after: header(RETRY_AFTER.as_str())
+      .and_then(|value| parse_retry_after(value, Utc::now())),
+    limit: header("x-ratelimit-limit").and_then(|value| value.parse().ok()),
+    reset: header("x-ratelimit-reset")
+      .and_then(|value| value.parse().ok())
+      .and_then(|secs| Utc.timestamp_opt(secs, 0).single()),
+  }
+}
+
 pub trait RateLimitResponse: Sized {
   fn rate_limit(self, rate_limit: &RateLimit) -> Result<Self, RateLimitError>;
 }
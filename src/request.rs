@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use reqwest::multipart::Form;
 use reqwest::RequestBuilder;
 use serde::de::DeserializeOwned;
 
@@ -28,13 +29,21 @@ pub trait Auth0RequestBuilder {
   fn build(&self, client: &Auth0Client) -> RequestBuilder;
 }
 
+/// Multipart request builder, for endpoints that take `multipart/form-data` uploads.
+pub trait MultipartAuth0RequestBuilder {
+  /// Path of the endpoint the form is posted to.
+  fn path(&self) -> String;
+  /// Build multipart form
+  fn build_multipart(&self) -> Auth0Result<Form>;
+}
+
 #[async_trait]
 impl<A: Auth0RequestBuilder + Send + Sync> Auth0RequestSimple for A {
   async fn send_to<T>(&self, client: &Auth0Client) -> Auth0Result<T>
   where
     T: DeserializeOwned + Send + Sync,
   {
-    client.send(self.build(&client)).await
+    client.send(self.build(client)).await
   }
 }
 
@@ -45,7 +54,7 @@ impl<A: Auth0RequestBuilder + AsRef<Auth0Client> + Sync + Send> Auth0Request for
     T: DeserializeOwned + Send + Sync,
   {
     let client = self.as_ref();
-    let req = self.build(&client);
+    let req = self.build(client);
 
     client.send(req).await
   }
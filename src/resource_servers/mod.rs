@@ -0,0 +1,61 @@
+#![warn(missing_docs)]
+//! Resource server request builders.
+
+#[doc(inline)]
+pub use resource_server::*;
+#[doc(inline)]
+pub use resource_server_create::*;
+#[doc(inline)]
+pub use resource_servers_list::*;
+
+use crate::Auth0Client;
+use std::sync::Arc;
+
+pub mod resource_server;
+pub mod resource_server_create;
+pub mod resource_servers_list;
+
+/// Resource servers manager
+pub struct ResourceServersManager(Arc<Auth0Client>);
+
+impl ResourceServersManager {
+  /// Create resource servers manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a new API (resource server) associated with your tenant.
+  ///
+  /// # Arguments
+  /// * `identifier` - Unique identifier for the API used as the audience parameter on
+  ///   authorization calls.
+  /// # Scopes
+  /// * `create:resource_servers`
+  pub fn create<S: AsRef<str>>(&self, identifier: S) -> ResourceServerCreate<'_> {
+    ResourceServerCreate::new(&self.0, identifier)
+  }
+
+  /// Create a new API (resource server) with role-based access control enabled.
+  ///
+  /// # Arguments
+  /// * `name` - Friendly name for this resource server.
+  /// * `identifier` - Unique identifier for the API used as the audience parameter on
+  ///   authorization calls.
+  /// # Scopes
+  /// * `create:resource_servers`
+  pub fn create_with_rbac<S: AsRef<str>>(
+    &self,
+    name: &str,
+    identifier: S,
+  ) -> ResourceServerCreate<'_> {
+    ResourceServerCreate::with_rbac(&self.0, name, identifier)
+  }
+
+  /// Retrieve details of all APIs associated with your tenant.
+  ///
+  /// # Scopes
+  /// * `read:resource_servers`
+  pub fn list(&self) -> ResourceServersList<'_> {
+    ResourceServersList::new(&self.0)
+  }
+}
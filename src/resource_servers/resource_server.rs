@@ -0,0 +1,103 @@
+//! Resource server
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Dialect of the access tokens issued for a resource server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TokenDialect {
+  /// Access token without permissions.
+  AccessToken,
+  /// Access token including the `permissions` claim.
+  AccessTokenAuthz,
+  /// RFC 9068 access token profile.
+  Rfc9068Profile,
+  /// RFC 9068 access token profile including the `permissions` claim.
+  Rfc9068ProfileAuthz,
+  /// A token dialect not known to this crate.
+  Unknown(String),
+}
+
+impl TokenDialect {
+  /// The value as used by the Auth0 API.
+  pub fn as_str(&self) -> &str {
+    match self {
+      TokenDialect::AccessToken => "access_token",
+      TokenDialect::AccessTokenAuthz => "access_token_authz",
+      TokenDialect::Rfc9068Profile => "rfc9068_profile",
+      TokenDialect::Rfc9068ProfileAuthz => "rfc9068_profile_authz",
+      TokenDialect::Unknown(value) => value,
+    }
+  }
+}
+
+impl From<String> for TokenDialect {
+  fn from(value: String) -> Self {
+    match value.as_str() {
+      "access_token" => TokenDialect::AccessToken,
+      "access_token_authz" => TokenDialect::AccessTokenAuthz,
+      "rfc9068_profile" => TokenDialect::Rfc9068Profile,
+      "rfc9068_profile_authz" => TokenDialect::Rfc9068ProfileAuthz,
+      _ => TokenDialect::Unknown(value),
+    }
+  }
+}
+
+impl From<TokenDialect> for String {
+  fn from(value: TokenDialect) -> Self {
+    value.as_str().to_owned()
+  }
+}
+
+impl Display for TokenDialect {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Permission defined by a resource server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceServerScope {
+  /// Value of this scope.
+  pub value: String,
+  /// User-friendly description of this scope.
+  pub description: Option<String>,
+}
+
+/// Resource server (API).
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResourceServer {
+  /// ID of the API (resource server).
+  pub id: String,
+  /// Friendly name for this resource server.
+  pub name: Option<String>,
+  /// Unique identifier for the API used as the audience parameter on authorization
+  /// calls.
+  pub identifier: String,
+  /// Whether this is an Auth0 system API (true) or a custom API (false).
+  #[serde(default)]
+  pub is_system: bool,
+  /// List of permissions (scopes) that this API uses.
+  #[serde(default)]
+  pub scopes: Vec<ResourceServerScope>,
+  /// Algorithm used to sign JWTs. Can be `HS256` or `RS256`.
+  pub signing_alg: Option<String>,
+  /// Whether refresh tokens can be issued for this API (true) or not (false).
+  #[serde(default)]
+  pub allow_offline_access: bool,
+  /// Whether to skip user consent for applications flagged as first party (true) or not
+  /// (false).
+  #[serde(default)]
+  pub skip_consent_for_verifiable_first_party_clients: bool,
+  /// Expiration value (in seconds) for access tokens issued for this API from the token
+  /// endpoint.
+  pub token_lifetime: Option<u32>,
+  /// Expiration value (in seconds) for access tokens issued for this API via Implicit or
+  /// Hybrid Flows.
+  pub token_lifetime_for_web: Option<u32>,
+  /// Whether authorization polices are enforced (true) or unenforced (false).
+  #[serde(default)]
+  pub enforce_policies: bool,
+  /// Dialect of issued access token.
+  pub token_dialect: Option<TokenDialect>,
+}
@@ -0,0 +1,145 @@
+//! Create a resource server.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, ResourceServerScope, TokenDialect};
+
+/// Create a new API (resource server) associated with your tenant.
+///
+/// Send as [ResourceServer](crate::ResourceServer).
+///
+/// # Scopes
+/// * `create:resource_servers`
+#[derive(Serialize)]
+pub struct ResourceServerCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  identifier: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  scopes: Vec<ResourceServerScope>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  signing_alg: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  allow_offline_access: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  token_lifetime: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  skip_consent_for_verifiable_first_party_clients: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enforce_policies: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  token_dialect: Option<TokenDialect>,
+}
+
+impl<'a> ResourceServerCreate<'a> {
+  /// Create create resource server request.
+  ///
+  /// # Arguments
+  /// * `identifier` - Unique identifier for the API used as the audience parameter on
+  ///   authorization calls.  Can not be changed once set.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, identifier: S) -> Self {
+    Self {
+      client,
+
+      identifier: identifier.as_ref().to_string(),
+      name: None,
+      scopes: Vec::new(),
+      signing_alg: None,
+      allow_offline_access: None,
+      token_lifetime: None,
+      skip_consent_for_verifiable_first_party_clients: None,
+      enforce_policies: None,
+      token_dialect: None,
+    }
+  }
+
+  /// Create create resource server request with role-based access control enabled.
+  ///
+  /// Authorization policies are enforced and access tokens include the `permissions`
+  /// claim (`token_dialect` of `access_token_authz`).
+  ///
+  /// # Arguments
+  /// * `name` - Friendly name for this resource server.
+  /// * `identifier` - Unique identifier for the API used as the audience parameter on
+  ///   authorization calls.  Can not be changed once set.
+  pub fn with_rbac<S: AsRef<str>>(
+    client: &'a Auth0Client,
+    name: &str,
+    identifier: S,
+  ) -> Self {
+    Self::new(client, identifier)
+      .name(name)
+      .enforce_policies(true)
+      .token_dialect(TokenDialect::AccessTokenAuthz)
+  }
+
+  /// Friendly name for this resource server.
+  pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
+    self
+  }
+
+  /// Add a permission (scope) that this API uses.
+  pub fn scope(mut self, value: &str, description: &str) -> Self {
+    self.scopes.push(ResourceServerScope {
+      value: value.to_owned(),
+      description: Some(description.to_owned()),
+    });
+    self
+  }
+
+  /// Algorithm used to sign JWTs. Can be `HS256` or `RS256`.
+  pub fn signing_alg(mut self, signing_alg: &str) -> Self {
+    self.signing_alg = Some(signing_alg.to_owned());
+    self
+  }
+
+  /// Whether refresh tokens can be issued for this API (true) or not (false).
+  pub fn allow_offline_access(mut self, allow_offline_access: bool) -> Self {
+    self.allow_offline_access = Some(allow_offline_access);
+    self
+  }
+
+  /// Expiration value (in seconds) for access tokens issued for this API from the token
+  /// endpoint.
+  pub fn token_lifetime(mut self, token_lifetime: u32) -> Self {
+    self.token_lifetime = Some(token_lifetime);
+    self
+  }
+
+  /// Whether to skip user consent for applications flagged as first party (true) or not
+  /// (false).
+  pub fn skip_consent_for_verifiable_first_party_clients(mut self, skip: bool) -> Self {
+    self.skip_consent_for_verifiable_first_party_clients = Some(skip);
+    self
+  }
+
+  /// Whether authorization policies are enforced (true) or unenforced (false).
+  pub fn enforce_policies(mut self, enforce_policies: bool) -> Self {
+    self.enforce_policies = Some(enforce_policies);
+    self
+  }
+
+  /// Dialect of issued access tokens.
+  pub fn token_dialect(mut self, token_dialect: TokenDialect) -> Self {
+    self.token_dialect = Some(token_dialect);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ResourceServerCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ResourceServerCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/resource-servers")
+      .json(self)
+  }
+}
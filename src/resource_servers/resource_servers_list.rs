@@ -0,0 +1,63 @@
+//! Retrieve resource servers.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve details of all APIs associated with your tenant.
+///
+/// Send as `Vec<ResourceServer>`, or as `ResponsePage<ResourceServer>` when
+/// [ResourceServersList::include_totals] is set.
+///
+/// # Scopes
+/// * `read:resource_servers`
+#[derive(Serialize)]
+pub struct ResourceServersList<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  include_totals: Option<bool>,
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> ResourceServersList<'a> {
+  /// Create list resource servers request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      include_totals: None,
+      page: Default::default(),
+    }
+  }
+
+  /// Return results inside an object that contains the total result count (true) or as a
+  /// direct array of results (false, default).
+  pub fn include_totals(mut self, include_totals: bool) -> Self {
+    self.include_totals = Some(include_totals);
+    self
+  }
+}
+
+impl<'a> AsMut<Page> for ResourceServersList<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for ResourceServersList<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for ResourceServersList<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::GET, "api/v2/resource-servers")
+      .query(&self)
+  }
+}
@@ -0,0 +1,14 @@
+#![warn(missing_docs)]
+//! Role types.
+use serde::{Deserialize, Serialize};
+
+/// Role.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Role {
+  /// ID for this role.
+  pub id: String,
+  /// Name of this role.
+  pub name: String,
+  /// Description of this role.
+  pub description: Option<String>,
+}
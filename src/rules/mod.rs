@@ -0,0 +1,84 @@
+#![warn(missing_docs)]
+//! Rule request builders.
+
+#[doc(inline)]
+pub use rule::*;
+#[doc(inline)]
+pub use rule_create::*;
+#[doc(inline)]
+pub use rule_delete::*;
+#[doc(inline)]
+pub use rule_get::*;
+#[doc(inline)]
+pub use rule_update::*;
+#[doc(inline)]
+pub use rules_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod rule;
+pub mod rule_create;
+pub mod rule_delete;
+pub mod rule_get;
+pub mod rule_update;
+pub mod rules_get;
+
+/// Rules manager
+pub struct RulesManager(Arc<Auth0Client>);
+
+impl RulesManager {
+  /// Create rules manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create a new rule.
+  ///
+  /// # Arguments
+  /// * `name` - Name of this rule.
+  /// * `script` - Code to be executed when this rule runs.
+  /// # Scopes
+  /// * `create:rules`
+  pub fn create(&self, name: &str, script: &str) -> RuleCreate<'_> {
+    RuleCreate::new(&self.0, name, script)
+  }
+
+  /// Delete a rule.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the rule to delete.
+  /// # Scopes
+  /// * `delete:rules`
+  pub async fn delete<S: AsRef<str>>(&self, id: S) -> Auth0Result<()> {
+    RuleDelete::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve a rule by its ID.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the rule to retrieve.
+  /// # Scopes
+  /// * `read:rules`
+  pub async fn get<S: AsRef<str>>(&self, id: S) -> Auth0Result<Rule> {
+    RuleGet::new(id).send_to(&self.0).await
+  }
+
+  /// Retrieve a filtered list of rules.
+  ///
+  /// # Scopes
+  /// * `read:rules`
+  pub fn list(&self) -> RulesGet<'_> {
+    RulesGet::new(&self.0)
+  }
+
+  /// Update an existing rule.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the rule to update.
+  /// # Scopes
+  /// * `update:rules`
+  pub fn update<S: AsRef<str>>(&self, id: S) -> RuleUpdate<'_> {
+    RuleUpdate::new(&self.0, id)
+  }
+}
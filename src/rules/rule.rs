@@ -0,0 +1,33 @@
+//! Rule
+use serde::{Deserialize, Serialize};
+
+/// Execution stage of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleStage {
+  /// Runs after a successful login.
+  LoginSuccess,
+  /// Runs after a failed login.
+  LoginFailure,
+  /// Runs before authorization.
+  PreAuthorize,
+}
+
+/// Rule.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rule {
+  /// ID of this rule.
+  pub id: String,
+  /// Name of this rule.
+  pub name: String,
+  /// Code to be executed when this rule runs.
+  pub script: Option<String>,
+  /// Order that this rule should execute in relative to other rules.  Lower-valued rules
+  /// execute first.
+  pub order: Option<i32>,
+  /// Whether the rule is enabled (true), or disabled (false).
+  #[serde(default)]
+  pub enabled: bool,
+  /// Execution stage of this rule.
+  pub stage: Option<RuleStage>,
+}
@@ -0,0 +1,67 @@
+//! Create a rule.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Create a new rule.
+///
+/// Send as [Rule](crate::Rule).
+///
+/// # Scopes
+/// * `create:rules`
+#[derive(Serialize)]
+pub struct RuleCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  name: String,
+  script: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  order: Option<i32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled: Option<bool>,
+}
+
+impl<'a> RuleCreate<'a> {
+  /// Create create rule request.
+  ///
+  /// # Arguments
+  /// * `name` - Name of this rule.
+  /// * `script` - Code to be executed when this rule runs.
+  pub fn new(client: &'a Auth0Client, name: &str, script: &str) -> Self {
+    Self {
+      client,
+
+      name: name.to_owned(),
+      script: script.to_owned(),
+      order: None,
+      enabled: None,
+    }
+  }
+
+  /// Order that this rule should execute in relative to other rules.  Lower-valued rules
+  /// execute first.
+  pub fn order(mut self, order: i32) -> Self {
+    self.order = Some(order);
+    self
+  }
+
+  /// Whether the rule is enabled (true), or disabled (false).
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = Some(enabled);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for RuleCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for RuleCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::POST, "api/v2/rules").json(self)
+  }
+}
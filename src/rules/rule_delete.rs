@@ -0,0 +1,30 @@
+//! Delete a rule.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Delete a rule by its ID.
+///
+/// # Scopes
+/// * `delete:rules`
+pub struct RuleDelete(String);
+
+impl RuleDelete {
+  /// Create delete rule request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the rule to delete.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for RuleDelete {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/rules/{}", path_segment(&self.0)),
+    )
+  }
+}
@@ -0,0 +1,30 @@
+//! Retrieve a rule.
+use reqwest::{Method, RequestBuilder};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve a rule by its ID.
+///
+/// # Scopes
+/// * `read:rules`
+pub struct RuleGet(String);
+
+impl RuleGet {
+  /// Create get rule request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the rule to retrieve.
+  pub fn new<S: AsRef<str>>(id: S) -> Self {
+    Self(id.as_ref().to_string())
+  }
+}
+
+impl Auth0RequestBuilder for RuleGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(
+      Method::GET,
+      &format!("api/v2/rules/{}", path_segment(&self.0)),
+    )
+  }
+}
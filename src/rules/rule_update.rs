@@ -0,0 +1,89 @@
+//! Update a rule.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Update an existing rule.
+///
+/// Send as [Rule](crate::Rule).
+///
+/// # Scopes
+/// * `update:rules`
+#[derive(Serialize)]
+pub struct RuleUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  script: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  order: Option<i32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled: Option<bool>,
+}
+
+impl<'a> RuleUpdate<'a> {
+  /// Create update rule request.
+  ///
+  /// # Arguments
+  /// * `id` - ID of the rule to update.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      name: None,
+      script: None,
+      order: None,
+      enabled: None,
+    }
+  }
+
+  /// Name of this rule.
+  pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
+    self
+  }
+
+  /// Code to be executed when this rule runs.
+  pub fn script(mut self, script: &str) -> Self {
+    self.script = Some(script.to_owned());
+    self
+  }
+
+  /// Order that this rule should execute in relative to other rules.  Lower-valued rules
+  /// execute first.
+  pub fn order(mut self, order: i32) -> Self {
+    self.order = Some(order);
+    self
+  }
+
+  /// Whether the rule is enabled (true), or disabled (false).
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = Some(enabled);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for RuleUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for RuleUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!("api/v2/rules/{}", path_segment(&self.id)),
+      )
+      .json(self)
+  }
+}
@@ -0,0 +1,68 @@
+//! Retrieve rules.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+use crate::{Fields, Page};
+
+/// Retrieve a filtered list of rules.
+///
+/// Send as `Vec<Rule>`.
+///
+/// # Scopes
+/// * `read:rules`
+#[derive(Serialize)]
+pub struct RulesGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled: Option<bool>,
+  #[serde(flatten)]
+  page: Page,
+  #[serde(flatten)]
+  fields: Fields,
+}
+
+impl<'a> RulesGet<'a> {
+  /// Create get rules request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      enabled: None,
+      page: Default::default(),
+      fields: Default::default(),
+    }
+  }
+
+  /// Only retrieve rules that are enabled (true) or disabled (false).
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = Some(enabled);
+    self
+  }
+}
+
+impl<'a> AsMut<Page> for RulesGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsMut<Fields> for RulesGet<'a> {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for RulesGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for RulesGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/rules").query(&self)
+  }
+}
@@ -0,0 +1,52 @@
+#![warn(missing_docs)]
+//! Self-service profile request builders.
+
+#[doc(inline)]
+pub use self_service_profile::*;
+#[doc(inline)]
+pub use self_service_profile_sso_ticket_revoke::*;
+#[doc(inline)]
+pub use self_service_profiles_list::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod self_service_profile;
+pub mod self_service_profile_sso_ticket_revoke;
+pub mod self_service_profiles_list;
+
+/// Self-service profiles manager
+pub struct SelfServiceProfilesManager(Arc<Auth0Client>);
+
+impl SelfServiceProfilesManager {
+  /// Create self-service profiles manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve self-service profiles.
+  ///
+  /// # Scopes
+  /// * `read:self_service_profiles`
+  pub fn list(&self) -> SelfServiceProfilesList<'_> {
+    SelfServiceProfilesList::new(&self.0)
+  }
+
+  /// Revoke an SSO access ticket and invalidate associated sessions.  The ticket will no
+  /// longer be accepted to initiate a self-service SSO session.
+  ///
+  /// # Arguments
+  /// * `profile_id` - The ID of the self-service profile.
+  /// * `ticket_id` - The ID of the ticket to revoke.
+  /// # Scopes
+  /// * `delete:sso_access_tickets`
+  pub async fn revoke_sso_ticket<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    profile_id: S,
+    ticket_id: T,
+  ) -> Auth0Result<()> {
+    SelfServiceProfileSsoTicketRevoke::new(profile_id, ticket_id)
+      .send_to(&self.0)
+      .await
+  }
+}
@@ -0,0 +1,36 @@
+//! Self-service profile
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// User attribute collected during self-service SSO setup.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SelfServiceProfileUserAttribute {
+  /// Name of the attribute.
+  pub name: String,
+  /// Description of the attribute.
+  pub description: Option<String>,
+  /// Whether the attribute can be left out.
+  #[serde(default)]
+  pub is_optional: bool,
+}
+
+/// Self-service profile used to let customers set up their own SSO connection.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SelfServiceProfile {
+  /// ID of the self-service profile.
+  pub id: String,
+  /// Name of the self-service profile.
+  pub name: Option<String>,
+  /// Description of the self-service profile.
+  pub description: Option<String>,
+  /// User attributes collected during setup.
+  #[serde(default)]
+  pub user_attributes: Vec<SelfServiceProfileUserAttribute>,
+  /// Identity provider strategies customers can choose from, e.g. `oidc` or `samlp`.
+  #[serde(default)]
+  pub allowed_strategies: Vec<String>,
+  /// Date and time the profile was created.
+  pub created_at: Option<DateTime<Utc>>,
+  /// Date and time the profile was last updated.
+  pub updated_at: Option<DateTime<Utc>>,
+}
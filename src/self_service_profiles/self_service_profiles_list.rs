@@ -0,0 +1,63 @@
+//! Retrieve self-service profiles.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve self-service profiles.
+///
+/// Send as `Vec<SelfServiceProfile>`, or as `ResponsePage<SelfServiceProfile>` when
+/// [SelfServiceProfilesList::include_totals] is set.
+///
+/// # Scopes
+/// * `read:self_service_profiles`
+#[derive(Serialize)]
+pub struct SelfServiceProfilesList<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  include_totals: Option<bool>,
+  #[serde(flatten)]
+  page: Page,
+}
+
+impl<'a> SelfServiceProfilesList<'a> {
+  /// Create list self-service profiles request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      include_totals: None,
+      page: Default::default(),
+    }
+  }
+
+  /// Return results inside an object that contains the total result count (true) or as a
+  /// direct array of results (false, default).
+  pub fn include_totals(mut self, include_totals: bool) -> Self {
+    self.include_totals = Some(include_totals);
+    self
+  }
+}
+
+impl<'a> AsMut<Page> for SelfServiceProfilesList<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for SelfServiceProfilesList<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for SelfServiceProfilesList<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::GET, "api/v2/self-service-profiles")
+      .query(&self)
+  }
+}
@@ -11,6 +11,7 @@ pub enum Ordering {
 }
 
 /// Provides field sort.
+#[derive(Default)]
 pub struct Sort {
   field: Option<String>,
   order: Option<Ordering>,
@@ -35,26 +36,17 @@ pub trait Sortable {
   /// # Arguments
   /// * `field` - The field name to sort by. (This will be the name defined by the Auth0 api)
   /// * `order` - The order to sort field values.
-  fn sort(&mut self, field: &str, order: Ordering) -> &mut Self;
+  fn sort(self, field: &str, order: Ordering) -> Self;
 }
 
 impl<S: AsMut<Sort>> Sortable for S {
-  fn sort(&mut self, field: &str, order: Ordering) -> &mut Self {
+  fn sort(mut self, field: &str, order: Ordering) -> Self {
     self.as_mut().field = Some(field.to_owned());
     self.as_mut().order = Some(order);
     self
   }
 }
 
-impl Default for Sort {
-  fn default() -> Self {
-    Self {
-      field: None,
-      order: None,
-    }
-  }
-}
-
 impl Serialize for Sort {
   fn serialize<S>(
     &self,
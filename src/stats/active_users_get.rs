@@ -0,0 +1,26 @@
+//! Retrieve the number of active users.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the number of active users that logged in during the last 30 days.
+///
+/// Send as `u64`, the response is a bare number rather than an object.
+///
+/// # Scopes
+/// * `read:stats`
+#[derive(Default)]
+pub struct ActiveUsersGet;
+
+impl ActiveUsersGet {
+  /// Create get active users request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for ActiveUsersGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/stats/active-users")
+  }
+}
@@ -0,0 +1,32 @@
+//! Daily statistics.
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+/// Login and sign up statistics of a single day.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DailyStat {
+  /// Day the statistics are for.
+  #[serde(deserialize_with = "deserialize_date")]
+  pub date: NaiveDate,
+  /// Number of logins.
+  pub logins: u64,
+  /// Number of sign ups.
+  pub signups: u64,
+  /// Number of breached passwords detected.
+  pub leaked_passwords: u64,
+  /// The time when the statistics were last updated.
+  pub updated_at: DateTime<Utc>,
+  /// The time when the statistics were first recorded.
+  pub created_at: DateTime<Utc>,
+}
+
+/// Auth0 returns the day as a timestamp at midnight UTC, e.g. `2024-01-31T00:00:00.000Z`.
+fn deserialize_date<'de, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<NaiveDate, D::Error> {
+  let date = String::deserialize(deserializer)?;
+  let day = date.get(..10).unwrap_or(&date);
+
+  NaiveDate::parse_from_str(day, "%Y-%m-%d").map_err(D::Error::custom)
+}
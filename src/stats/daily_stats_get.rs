@@ -0,0 +1,73 @@
+//! Retrieve daily statistics.
+use chrono::NaiveDate;
+use reqwest::{Method, RequestBuilder};
+use serde::{Serialize, Serializer};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the number of logins, sign ups and breached password detections for each
+/// day in a date range.
+///
+/// Send as `Vec<DailyStat>`.
+///
+/// # Scopes
+/// * `read:stats`
+#[derive(Serialize)]
+pub struct DailyStatsGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(serialize_with = "serialize_date")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  from: Option<NaiveDate>,
+  #[serde(serialize_with = "serialize_date")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  to: Option<NaiveDate>,
+}
+
+impl<'a> DailyStatsGet<'a> {
+  /// Create get daily stats request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      from: None,
+      to: None,
+    }
+  }
+
+  /// First day to retrieve statistics for, inclusive.
+  pub fn from(mut self, from: NaiveDate) -> Self {
+    self.from = Some(from);
+    self
+  }
+
+  /// Last day to retrieve statistics for, inclusive.
+  pub fn to(mut self, to: NaiveDate) -> Self {
+    self.to = Some(to);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for DailyStatsGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for DailyStatsGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/stats/daily").query(&self)
+  }
+}
+
+/// The date range is given as `YYYYMMDD` rather than ISO 8601.
+fn serialize_date<S: Serializer>(
+  date: &Option<NaiveDate>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  match date {
+    Some(date) => serializer.collect_str(&date.format("%Y%m%d")),
+    None => serializer.serialize_none(),
+  }
+}
@@ -0,0 +1,43 @@
+#![warn(missing_docs)]
+//! Stats request builders.
+
+#[doc(inline)]
+pub use active_users_get::*;
+#[doc(inline)]
+pub use daily_stat::*;
+#[doc(inline)]
+pub use daily_stats_get::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod active_users_get;
+pub mod daily_stat;
+pub mod daily_stats_get;
+
+/// Stats manager
+pub struct StatsManager(Arc<Auth0Client>);
+
+impl StatsManager {
+  /// Create stats manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve the number of active users that logged in during the last 30 days.
+  ///
+  /// # Scopes
+  /// * `read:stats`
+  pub async fn active_users(&self) -> Auth0Result<u64> {
+    ActiveUsersGet::new().send_to(&self.0).await
+  }
+
+  /// Retrieve the number of logins, sign ups and breached password detections for each
+  /// day in a date range.
+  ///
+  /// # Scopes
+  /// * `read:stats`
+  pub fn daily(&self) -> DailyStatsGet<'_> {
+    DailyStatsGet::new(&self.0)
+  }
+}
@@ -0,0 +1,42 @@
+#![warn(missing_docs)]
+//! Tenant request builders.
+
+#[doc(inline)]
+pub use tenant_settings::*;
+#[doc(inline)]
+pub use tenant_settings_get::*;
+#[doc(inline)]
+pub use tenant_settings_update::*;
+
+use crate::{Auth0Client, Auth0RequestSimple, Auth0Result};
+use std::sync::Arc;
+
+pub mod tenant_settings;
+pub mod tenant_settings_get;
+pub mod tenant_settings_update;
+
+/// Tenants manager
+pub struct TenantsManager(Arc<Auth0Client>);
+
+impl TenantsManager {
+  /// Create tenants manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Retrieve settings for a tenant.
+  ///
+  /// # Scopes
+  /// * `read:tenant_settings`
+  pub async fn get_settings(&self) -> Auth0Result<TenantSettings> {
+    TenantSettingsGet::new().send_to(&self.0).await
+  }
+
+  /// Update settings for a tenant.
+  ///
+  /// # Scopes
+  /// * `update:tenant_settings`
+  pub fn update_settings(&self) -> TenantSettingsUpdate<'_> {
+    TenantSettingsUpdate::new(&self.0)
+  }
+}
@@ -0,0 +1,115 @@
+//! Tenant settings.
+use serde::{Deserialize, Serialize};
+
+/// Tenant feature flags.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TenantFlags {
+  /// Whether to use the older v1 change password flow.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub change_pwd_flow_v1: Option<bool>,
+  /// Whether the APIs section is enabled in the dashboard.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_apis_section: Option<bool>,
+  /// Whether the impersonation functionality has been disabled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub disable_impersonation: Option<bool>,
+  /// Whether all current connections are enabled when a new client is created.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_client_connections: Option<bool>,
+  /// Whether advanced API Authorization scenarios are enabled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_pipeline2: Option<bool>,
+  /// Whether the legacy delegation endpoint is enabled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub allow_legacy_delegation_grant_types: Option<bool>,
+  /// Whether the legacy `/oauth/ro` endpoint is enabled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub allow_legacy_ro_grant_types: Option<bool>,
+  /// Whether the legacy `/tokeninfo` endpoint is enabled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub allow_legacy_tokeninfo_endpoint: Option<bool>,
+  /// Whether ID tokens and the userinfo endpoint include a complete user profile.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_legacy_profile: Option<bool>,
+  /// Whether ID tokens can be used to authorize some `/api/v2` endpoints.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_idtoken_api2: Option<bool>,
+  /// Whether the public sign up process shows a `user_exists` error if the user already exists.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_public_signup_user_exists_error: Option<bool>,
+  /// Whether users are prompted to confirm log in before SSO redirection.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_sso: Option<bool>,
+  /// Whether the `enable_sso` setting can be changed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub allow_changing_enable_sso: Option<bool>,
+  /// Whether classic Universal Login prompts include additional security headers.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub disable_clickjack_protection_headers: Option<bool>,
+  /// Do not publish the enterprise connection information with IdP domains on the lock configuration file.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub no_disclose_enterprise_connections: Option<bool>,
+  /// Whether client authentication is enforced on the passwordless start endpoint.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enforce_client_authentication_on_passwordless_start: Option<bool>,
+  /// Whether the email verified flag is set for users of ADFS and Azure AD connections.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_adfs_waad_email_verification: Option<bool>,
+  /// Whether refresh tokens are revoked when the grant for them is revoked.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub revoke_refresh_token_grant: Option<bool>,
+  /// Whether third-party developers can dynamically register applications.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_dynamic_client_registration: Option<bool>,
+  /// Whether emails sent by Auth0 use the custom domain.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub enable_custom_domain_in_emails: Option<bool>,
+}
+
+/// Error page customization.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TenantErrorPage {
+  /// Custom error HTML template.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub html: Option<String>,
+  /// Whether to show the link to the log as part of the default error page.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub show_log_link: Option<bool>,
+  /// URL to redirect to when an error occurs instead of showing the default error page.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+}
+
+/// Tenant settings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TenantSettings {
+  /// The friendly name of the tenant, shown on the login page.
+  pub friendly_name: Option<String>,
+  /// URL of the tenant logo.
+  pub picture_url: Option<String>,
+  /// End-user support email address.
+  pub support_email: Option<String>,
+  /// End-user support URL.
+  pub support_url: Option<String>,
+  /// URLs that users can be redirected to after logout.
+  #[serde(default)]
+  pub allowed_logout_urls: Vec<String>,
+  /// Number of hours a session will stay valid.
+  pub session_lifetime: f64,
+  /// Number of hours a session can be inactive before it expires.
+  pub idle_session_lifetime: f64,
+  /// The Node.js version used by extensibility features.
+  pub sandbox_version: Option<String>,
+  /// Locales supported by Universal Login.  The first locale is the default.
+  #[serde(default)]
+  pub enabled_locales: Vec<String>,
+  /// Default audience for API authorization.
+  pub default_audience: Option<String>,
+  /// Name of the connection used for password grants at the token endpoint.
+  pub default_directory: Option<String>,
+  /// Error page customization.
+  pub error_page: Option<TenantErrorPage>,
+  /// Tenant feature flags.
+  #[serde(default)]
+  pub flags: TenantFlags,
+}
@@ -0,0 +1,26 @@
+//! Retrieve tenant settings.
+use reqwest::{Method, RequestBuilder};
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve settings for a tenant.
+///
+/// Send as [TenantSettings](crate::TenantSettings).
+///
+/// # Scopes
+/// * `read:tenant_settings`
+#[derive(Default)]
+pub struct TenantSettingsGet;
+
+impl TenantSettingsGet {
+  /// Create get tenant settings request.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl Auth0RequestBuilder for TenantSettingsGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/tenants/settings")
+  }
+}
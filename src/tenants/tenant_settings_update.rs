@@ -0,0 +1,329 @@
+//! Update tenant settings.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder, TenantErrorPage, TenantFlags};
+
+/// Update settings for a tenant.  Only the settings that are set are sent, the rest are
+/// left unchanged.
+///
+/// Send as [TenantSettings](crate::TenantSettings).
+///
+/// # Scopes
+/// * `update:tenant_settings`
+#[derive(Serialize)]
+pub struct TenantSettingsUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  friendly_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  picture_url: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  support_email: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  support_url: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  allowed_logout_urls: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  session_lifetime: Option<f64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  idle_session_lifetime: Option<f64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  sandbox_version: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  enabled_locales: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  default_audience: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  default_directory: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  error_page: Option<TenantErrorPage>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  flags: Option<TenantFlags>,
+}
+
+impl<'a> TenantSettingsUpdate<'a> {
+  /// Create update tenant settings request.
+  pub fn new(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      friendly_name: None,
+      picture_url: None,
+      support_email: None,
+      support_url: None,
+      allowed_logout_urls: None,
+      session_lifetime: None,
+      idle_session_lifetime: None,
+      sandbox_version: None,
+      enabled_locales: None,
+      default_audience: None,
+      default_directory: None,
+      error_page: None,
+      flags: None,
+    }
+  }
+
+  /// The friendly name of the tenant, shown on the login page.
+  pub fn friendly_name(mut self, friendly_name: &str) -> Self {
+    self.friendly_name = Some(friendly_name.to_owned());
+    self
+  }
+
+  /// URL of the tenant logo.
+  pub fn picture_url(mut self, picture_url: &str) -> Self {
+    self.picture_url = Some(picture_url.to_owned());
+    self
+  }
+
+  /// End-user support email address.
+  pub fn support_email(mut self, support_email: &str) -> Self {
+    self.support_email = Some(support_email.to_owned());
+    self
+  }
+
+  /// End-user support URL.
+  pub fn support_url(mut self, support_url: &str) -> Self {
+    self.support_url = Some(support_url.to_owned());
+    self
+  }
+
+  /// Add a URL that users can be redirected to after logout.  Replaces the existing
+  /// URLs.
+  pub fn allowed_logout_url(mut self, url: &str) -> Self {
+    self
+      .allowed_logout_urls
+      .get_or_insert_with(Vec::new)
+      .push(url.to_owned());
+    self
+  }
+
+  /// Number of hours a session will stay valid.
+  pub fn session_lifetime(mut self, session_lifetime: f64) -> Self {
+    self.session_lifetime = Some(session_lifetime);
+    self
+  }
+
+  /// Number of hours a session can be inactive before it expires.
+  pub fn idle_session_lifetime(mut self, idle_session_lifetime: f64) -> Self {
+    self.idle_session_lifetime = Some(idle_session_lifetime);
+    self
+  }
+
+  /// The Node.js version used by extensibility features, e.g. `18`.
+  pub fn sandbox_version(mut self, sandbox_version: &str) -> Self {
+    self.sandbox_version = Some(sandbox_version.to_owned());
+    self
+  }
+
+  /// Add a locale supported by Universal Login, e.g. `en`.  The first locale is the
+  /// default.  Replaces the existing locales.
+  pub fn enabled_locale(mut self, locale: &str) -> Self {
+    self
+      .enabled_locales
+      .get_or_insert_with(Vec::new)
+      .push(locale.to_owned());
+    self
+  }
+
+  /// Default audience for API authorization.
+  pub fn default_audience(mut self, default_audience: &str) -> Self {
+    self.default_audience = Some(default_audience.to_owned());
+    self
+  }
+
+  /// Name of the connection used for password grants at the token endpoint.
+  pub fn default_directory(mut self, default_directory: &str) -> Self {
+    self.default_directory = Some(default_directory.to_owned());
+    self
+  }
+
+  /// Error page customization.
+  pub fn error_page(mut self, error_page: TenantErrorPage) -> Self {
+    self.error_page = Some(error_page);
+    self
+  }
+
+  /// Whether to use the older v1 change password flow.
+  pub fn change_pwd_flow_v1(mut self, change_pwd_flow_v1: bool) -> Self {
+    self.flags_mut().change_pwd_flow_v1 = Some(change_pwd_flow_v1);
+    self
+  }
+
+  /// Whether the APIs section is enabled in the dashboard.
+  pub fn enable_apis_section(mut self, enable_apis_section: bool) -> Self {
+    self.flags_mut().enable_apis_section = Some(enable_apis_section);
+    self
+  }
+
+  /// Whether the impersonation functionality has been disabled.
+  pub fn disable_impersonation(mut self, disable_impersonation: bool) -> Self {
+    self.flags_mut().disable_impersonation = Some(disable_impersonation);
+    self
+  }
+
+  /// Whether all current connections are enabled when a new client is created.
+  pub fn enable_client_connections(mut self, enable_client_connections: bool) -> Self {
+    self.flags_mut().enable_client_connections = Some(enable_client_connections);
+    self
+  }
+
+  /// Whether advanced API Authorization scenarios are enabled.
+  pub fn enable_pipeline2(mut self, enable_pipeline2: bool) -> Self {
+    self.flags_mut().enable_pipeline2 = Some(enable_pipeline2);
+    self
+  }
+
+  /// Whether the legacy delegation endpoint is enabled.
+  pub fn allow_legacy_delegation_grant_types(
+    mut self,
+    allow_legacy_delegation_grant_types: bool,
+  ) -> Self {
+    self.flags_mut().allow_legacy_delegation_grant_types =
+      Some(allow_legacy_delegation_grant_types);
+    self
+  }
+
+  /// Whether the legacy `/oauth/ro` endpoint is enabled.
+  pub fn allow_legacy_ro_grant_types(
+    mut self,
+    allow_legacy_ro_grant_types: bool,
+  ) -> Self {
+    self.flags_mut().allow_legacy_ro_grant_types = Some(allow_legacy_ro_grant_types);
+    self
+  }
+
+  /// Whether the legacy `/tokeninfo` endpoint is enabled.
+  pub fn allow_legacy_tokeninfo_endpoint(
+    mut self,
+    allow_legacy_tokeninfo_endpoint: bool,
+  ) -> Self {
+    self.flags_mut().allow_legacy_tokeninfo_endpoint =
+      Some(allow_legacy_tokeninfo_endpoint);
+    self
+  }
+
+  /// Whether ID tokens and the userinfo endpoint include a complete user profile.
+  pub fn enable_legacy_profile(mut self, enable_legacy_profile: bool) -> Self {
+    self.flags_mut().enable_legacy_profile = Some(enable_legacy_profile);
+    self
+  }
+
+  /// Whether ID tokens can be used to authorize some `/api/v2` endpoints.
+  pub fn enable_idtoken_api2(mut self, enable_idtoken_api2: bool) -> Self {
+    self.flags_mut().enable_idtoken_api2 = Some(enable_idtoken_api2);
+    self
+  }
+
+  /// Whether the public sign up process shows a `user_exists` error if the user already exists.
+  pub fn enable_public_signup_user_exists_error(
+    mut self,
+    enable_public_signup_user_exists_error: bool,
+  ) -> Self {
+    self.flags_mut().enable_public_signup_user_exists_error =
+      Some(enable_public_signup_user_exists_error);
+    self
+  }
+
+  /// Whether users are prompted to confirm log in before SSO redirection.
+  pub fn enable_sso(mut self, enable_sso: bool) -> Self {
+    self.flags_mut().enable_sso = Some(enable_sso);
+    self
+  }
+
+  /// Whether the `enable_sso` setting can be changed.
+  pub fn allow_changing_enable_sso(mut self, allow_changing_enable_sso: bool) -> Self {
+    self.flags_mut().allow_changing_enable_sso = Some(allow_changing_enable_sso);
+    self
+  }
+
+  /// Whether classic Universal Login prompts include additional security headers.
+  pub fn disable_clickjack_protection_headers(
+    mut self,
+    disable_clickjack_protection_headers: bool,
+  ) -> Self {
+    self.flags_mut().disable_clickjack_protection_headers =
+      Some(disable_clickjack_protection_headers);
+    self
+  }
+
+  /// Do not publish the enterprise connection information with IdP domains on the lock configuration file.
+  pub fn no_disclose_enterprise_connections(
+    mut self,
+    no_disclose_enterprise_connections: bool,
+  ) -> Self {
+    self.flags_mut().no_disclose_enterprise_connections =
+      Some(no_disclose_enterprise_connections);
+    self
+  }
+
+  /// Whether client authentication is enforced on the passwordless start endpoint.
+  pub fn enforce_client_authentication_on_passwordless_start(
+    mut self,
+    enforce_client_authentication_on_passwordless_start: bool,
+  ) -> Self {
+    self
+      .flags_mut()
+      .enforce_client_authentication_on_passwordless_start =
+      Some(enforce_client_authentication_on_passwordless_start);
+    self
+  }
+
+  /// Whether the email verified flag is set for users of ADFS and Azure AD connections.
+  pub fn enable_adfs_waad_email_verification(
+    mut self,
+    enable_adfs_waad_email_verification: bool,
+  ) -> Self {
+    self.flags_mut().enable_adfs_waad_email_verification =
+      Some(enable_adfs_waad_email_verification);
+    self
+  }
+
+  /// Whether refresh tokens are revoked when the grant for them is revoked.
+  pub fn revoke_refresh_token_grant(mut self, revoke_refresh_token_grant: bool) -> Self {
+    self.flags_mut().revoke_refresh_token_grant = Some(revoke_refresh_token_grant);
+    self
+  }
+
+  /// Whether third-party developers can dynamically register applications.
+  pub fn enable_dynamic_client_registration(
+    mut self,
+    enable_dynamic_client_registration: bool,
+  ) -> Self {
+    self.flags_mut().enable_dynamic_client_registration =
+      Some(enable_dynamic_client_registration);
+    self
+  }
+
+  /// Whether emails sent by Auth0 use the custom domain.
+  pub fn enable_custom_domain_in_emails(
+    mut self,
+    enable_custom_domain_in_emails: bool,
+  ) -> Self {
+    self.flags_mut().enable_custom_domain_in_emails =
+      Some(enable_custom_domain_in_emails);
+    self
+  }
+}
+
+impl<'a> TenantSettingsUpdate<'a> {
+  fn flags_mut(&mut self) -> &mut TenantFlags {
+    self.flags.get_or_insert_with(Default::default)
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for TenantSettingsUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for TenantSettingsUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::PATCH, "api/v2/tenants/settings")
+      .json(self)
+  }
+}
@@ -0,0 +1,102 @@
+//! Create an email verification ticket.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Secondary identity whose email address should be verified.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketIdentity {
+  /// ID of the identity, without the provider prefix.
+  pub user_id: String,
+  /// Identity provider name of the identity, e.g. `google-oauth2`.
+  pub provider: String,
+}
+
+/// Create an email verification ticket for a user, without sending Auth0's verification
+/// email.  Deliver the returned ticket URL through your own email system.
+///
+/// Send as [Ticket](crate::Ticket).
+///
+/// # Scopes
+/// * `create:user_tickets`
+#[derive(Serialize)]
+pub struct EmailVerificationTicketCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  user_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  result_url: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  ttl_sec: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  include_email_in_redirect: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  identity: Option<TicketIdentity>,
+}
+
+impl<'a> EmailVerificationTicketCreate<'a> {
+  /// Create email verification ticket request.
+  ///
+  /// # Arguments
+  /// * `user_id` - ID of the user whose email should be verified.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, user_id: S) -> Self {
+    Self {
+      client,
+
+      user_id: user_id.as_ref().to_string(),
+      result_url: None,
+      ttl_sec: None,
+      include_email_in_redirect: None,
+      identity: None,
+    }
+  }
+
+  /// URL the user will be redirected to once the email has been verified.
+  pub fn result_url(mut self, result_url: &str) -> Self {
+    self.result_url = Some(result_url.to_owned());
+    self
+  }
+
+  /// Number of seconds for which the ticket is valid before expiration.  If unspecified
+  /// or set to 0, this value defaults to 432000 seconds (5 days).
+  pub fn ttl_sec(mut self, ttl_sec: u32) -> Self {
+    self.ttl_sec = Some(ttl_sec);
+    self
+  }
+
+  /// Whether to include the email address as part of the `result_url` (true), or not
+  /// (false).
+  pub fn include_email_in_redirect(mut self, include_email_in_redirect: bool) -> Self {
+    self.include_email_in_redirect = Some(include_email_in_redirect);
+    self
+  }
+
+  /// Verify the email of a secondary identity instead of the primary identity.
+  ///
+  /// # Arguments
+  /// * `user_id` - ID of the identity, without the provider prefix.
+  /// * `provider` - Identity provider name of the identity, e.g. `google-oauth2`.
+  pub fn identity(mut self, user_id: &str, provider: &str) -> Self {
+    self.identity = Some(TicketIdentity {
+      user_id: user_id.to_owned(),
+      provider: provider.to_owned(),
+    });
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for EmailVerificationTicketCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for EmailVerificationTicketCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/tickets/email-verification")
+      .json(self)
+  }
+}
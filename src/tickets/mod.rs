@@ -0,0 +1,68 @@
+#![warn(missing_docs)]
+//! Ticket request builders.
+
+#[doc(inline)]
+pub use email_verification_ticket_create::*;
+#[doc(inline)]
+pub use password_change_ticket_create::*;
+#[doc(inline)]
+pub use ticket::*;
+
+use crate::Auth0Client;
+use std::sync::Arc;
+
+pub mod email_verification_ticket_create;
+pub mod password_change_ticket_create;
+pub mod ticket;
+
+/// Tickets manager
+pub struct TicketsManager(Arc<Auth0Client>);
+
+impl TicketsManager {
+  /// Create tickets manager
+  pub fn new(client: Arc<Auth0Client>) -> Self {
+    Self(client)
+  }
+
+  /// Create an email verification ticket for a user.
+  ///
+  /// # Arguments
+  /// * `user_id` - ID of the user whose email should be verified.
+  /// # Scopes
+  /// * `create:user_tickets`
+  pub fn email_verification<S: AsRef<str>>(
+    &self,
+    user_id: S,
+  ) -> EmailVerificationTicketCreate<'_> {
+    EmailVerificationTicketCreate::new(&self.0, user_id)
+  }
+
+  /// Create a password change ticket for a user identified by ID.
+  ///
+  /// # Arguments
+  /// * `user_id` - ID of the user whose password should change.
+  /// # Scopes
+  /// * `create:user_tickets`
+  pub fn password_change<S: AsRef<str>>(
+    &self,
+    user_id: S,
+  ) -> PasswordChangeTicketCreate<'_> {
+    PasswordChangeTicketCreate::for_user(&self.0, user_id)
+  }
+
+  /// Create a password change ticket for a user identified by email address and database
+  /// connection.
+  ///
+  /// # Arguments
+  /// * `connection_id` - ID of the connection the user belongs to.
+  /// * `email` - Email address of the user.
+  /// # Scopes
+  /// * `create:user_tickets`
+  pub fn password_change_by_email<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    connection_id: S,
+    email: T,
+  ) -> PasswordChangeTicketCreate<'_> {
+    PasswordChangeTicketCreate::for_email(&self.0, connection_id, email)
+  }
+}
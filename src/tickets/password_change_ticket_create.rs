@@ -0,0 +1,124 @@
+//! Create a password change ticket.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Create a password change ticket for a user, without sending Auth0's password reset
+/// email.  Deliver the returned ticket URL through your own email system.
+///
+/// The user is identified either by `user_id`, or by `email` together with
+/// `connection_id`.  The two modes are mutually exclusive: Auth0 rejects requests that
+/// mix them with a `400 Bad Request`.  Use [PasswordChangeTicketCreate::for_user] or
+/// [PasswordChangeTicketCreate::for_email] to pick one.
+///
+/// Send as [Ticket](crate::Ticket).
+///
+/// # Scopes
+/// * `create:user_tickets`
+#[derive(Serialize)]
+pub struct PasswordChangeTicketCreate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  connection_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  email: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  result_url: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  ttl_sec: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  mark_email_as_verified: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  include_email_in_redirect: Option<bool>,
+}
+
+impl<'a> PasswordChangeTicketCreate<'a> {
+  /// Create password change ticket request for a user identified by ID.
+  ///
+  /// # Arguments
+  /// * `user_id` - ID of the user whose password should change.
+  pub fn for_user<S: AsRef<str>>(client: &'a Auth0Client, user_id: S) -> Self {
+    Self {
+      user_id: Some(user_id.as_ref().to_string()),
+      ..Self::empty(client)
+    }
+  }
+
+  /// Create password change ticket request for a user identified by email address and
+  /// database connection.
+  ///
+  /// # Arguments
+  /// * `connection_id` - ID of the connection the user belongs to.
+  /// * `email` - Email address of the user.
+  pub fn for_email<S: AsRef<str>, T: AsRef<str>>(
+    client: &'a Auth0Client,
+    connection_id: S,
+    email: T,
+  ) -> Self {
+    Self {
+      connection_id: Some(connection_id.as_ref().to_string()),
+      email: Some(email.as_ref().to_string()),
+      ..Self::empty(client)
+    }
+  }
+
+  fn empty(client: &'a Auth0Client) -> Self {
+    Self {
+      client,
+
+      user_id: None,
+      connection_id: None,
+      email: None,
+      result_url: None,
+      ttl_sec: None,
+      mark_email_as_verified: None,
+      include_email_in_redirect: None,
+    }
+  }
+
+  /// URL the user will be redirected to once the password has been changed.
+  pub fn result_url(mut self, result_url: &str) -> Self {
+    self.result_url = Some(result_url.to_owned());
+    self
+  }
+
+  /// Number of seconds for which the ticket is valid before expiration.  If unspecified
+  /// or set to 0, this value defaults to 432000 seconds (5 days).
+  pub fn ttl_sec(mut self, ttl_sec: u32) -> Self {
+    self.ttl_sec = Some(ttl_sec);
+    self
+  }
+
+  /// Whether to set the email_verified attribute to true (true) or whether it should not
+  /// be updated (false).
+  pub fn mark_email_as_verified(mut self, mark_email_as_verified: bool) -> Self {
+    self.mark_email_as_verified = Some(mark_email_as_verified);
+    self
+  }
+
+  /// Whether to include the email address as part of the `result_url` (true), or not
+  /// (false).
+  pub fn include_email_in_redirect(mut self, include_email_in_redirect: bool) -> Self {
+    self.include_email_in_redirect = Some(include_email_in_redirect);
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for PasswordChangeTicketCreate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for PasswordChangeTicketCreate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(Method::POST, "api/v2/tickets/password-change")
+      .json(self)
+  }
+}
@@ -0,0 +1,9 @@
+//! Ticket
+use serde::Deserialize;
+
+/// Ticket URL to send to a user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Ticket {
+  /// URL representing the ticket.
+  pub ticket: String,
+}
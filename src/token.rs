@@ -8,6 +8,9 @@ use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use std::fmt::Formatter;
 
+/// How long before expiring a cached token is refreshed.
+const REFRESH_LEEWAY: Duration = Duration::from_secs(60);
+
 /// Auth0 OAuth token.
 #[derive(Deserialize)]
 pub struct Token {
@@ -36,7 +39,6 @@ struct TokenOpts {
 
 #[derive(Deserialize, Clone, Debug)]
 struct TokenErrorResponse {
-  error: String,
   error_description: String,
 }
 
@@ -44,7 +46,8 @@ struct TokenErrorResponse {
 #[derive(Debug)]
 pub struct TokenManager {
   client: Client,
-  domain: String,
+  base_url: String,
+  static_token: Option<String>,
 
   token: Mutex<Option<String>>,
   token_opts: TokenOpts,
@@ -62,7 +65,8 @@ impl TokenManager {
   ) -> Self {
     Self {
       client,
-      domain: domain.to_owned(),
+      base_url: format!("https://{}", domain),
+      static_token: None,
       token: Mutex::new(None),
       token_opts: TokenOpts {
         audience: audience.to_owned(),
@@ -74,31 +78,69 @@ impl TokenManager {
     }
   }
 
-  /// Gets valid encoded JWT token.
-  pub async fn get_token(&self) -> Result<String, TokenError> {
-    let now = SystemTime::now();
-    let expiration = SystemTime::UNIX_EPOCH
-      + Duration::from_secs(self.token_expiration.load(Ordering::SeqCst));
+  /// Gets [TokenManager] that always uses a pre-fetched token rather than fetching one
+  /// from Auth0.  The token is never refreshed.
+  pub fn from_token(client: Client, token: &str) -> Self {
+    Self {
+      static_token: Some(token.to_owned()),
+      ..Self::new(client, "", "", "", "")
+    }
+  }
+
+  /// Fetch tokens from `{base_url}/oauth/token` instead of `https://{domain}/oauth/token`.
+  pub fn base_url(mut self, base_url: &str) -> Self {
+    self.base_url = base_url.trim_end_matches('/').to_owned();
+    self
+  }
 
-    if now > expiration {
-      return self.fetch_token().await;
+  /// Gets valid encoded JWT token.  The token is refreshed up front once it is within
+  /// 60 seconds of expiring, so it cannot expire while a request is in flight.
+  ///
+  /// The token lock is held while refreshing, so concurrent callers wait for a single
+  /// refresh and then share its token instead of each fetching their own.
+  pub async fn get_token(&self) -> Result<String, TokenError> {
+    if let Some(token) = &self.static_token {
+      return Ok(token.clone());
     }
 
-    {
-      let token = self.token.lock().await;
+    let mut token = self.token.lock().await;
+    if !self.is_expired(REFRESH_LEEWAY) {
       if let Some(token) = token.deref() {
         return Ok(token.to_string());
       }
     }
 
-    self.fetch_token().await
+    let fetched = self.fetch_token().await?;
+    *token = Some(fetched.access_token.clone());
+    self.token_expiration.store(
+      (SystemTime::now() + Duration::from_secs(fetched.expires_in))
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs(),
+      Ordering::SeqCst,
+    );
+
+    Ok(fetched.access_token)
+  }
+
+  /// Whether the cached token has expired, or will expire within `leeway`.  Also true
+  /// when no token has been fetched yet.  A pre-fetched token never expires.
+  pub fn is_expired(&self, leeway: Duration) -> bool {
+    if self.static_token.is_some() {
+      return false;
+    }
+
+    let expiration = SystemTime::UNIX_EPOCH
+      + Duration::from_secs(self.token_expiration.load(Ordering::SeqCst));
+
+    SystemTime::now() + leeway >= expiration
   }
 
   /// Gets new encoded JWT token from auth0.
-  async fn fetch_token(&self) -> Result<String, TokenError> {
+  async fn fetch_token(&self) -> Result<Token, TokenError> {
     let res = self
       .client
-      .post(&format!("https://{}/oauth/token", self.domain))
+      .post(format!("{}/oauth/token", self.base_url))
       .form(&self.token_opts)
       .send()
       .await?;
@@ -107,18 +149,7 @@ impl TokenManager {
       return Err(res.json::<TokenErrorResponse>().await?.into());
     }
 
-    let token: Token = res.json().await?;
-
-    *self.token.lock().await = Some(token.access_token.clone());
-    self.token_expiration.store(
-      (SystemTime::now() + Duration::from_secs(token.expires_in))
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .expect("Time went backwards")
-        .as_secs(),
-      Ordering::SeqCst,
-    );
-
-    Ok(token.access_token)
+    Ok(res.json().await?)
   }
 }
 
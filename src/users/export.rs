@@ -0,0 +1,42 @@
+//! Export users as newline delimited JSON.
+use futures::StreamExt;
+use serde_json::Value;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{Auth0, Auth0Result, Pageable, PaginatedStream};
+
+/// Number of users requested per page while exporting.
+const EXPORT_PER_PAGE: u32 = 100;
+
+/// Write every user of the tenant to `writer` as [NDJSON](http://ndjson.org), one JSON
+/// object per line, and return the number of users written.
+///
+/// Users are written as returned by Auth0, so no fields are lost.  Only a single page of
+/// users is held in memory at once.  The writer is flushed but not shut down.
+///
+/// Note: Auth0 only allows paginating through the first 1000 users.  Use
+/// [export_users](crate::jobs::helpers::export_users) for larger tenants.
+///
+/// # Scopes
+/// * `read:users`
+pub async fn export_ndjson<W: AsyncWrite + Unpin>(
+  auth0: &Auth0,
+  mut writer: W,
+) -> Auth0Result<u64> {
+  let req = auth0.users.find().per_page(EXPORT_PER_PAGE);
+  let mut pages = PaginatedStream::<Value>::new(req);
+  let mut count = 0;
+
+  while let Some(page) = pages.next().await {
+    for user in page? {
+      let mut line = serde_json::to_vec(&user)?;
+      line.push(b'\n');
+
+      writer.write_all(&line).await?;
+      count += 1;
+    }
+  }
+
+  writer.flush().await?;
+  Ok(count)
+}
@@ -4,8 +4,12 @@
 #[doc(inline)]
 pub use permissions::*;
 #[doc(inline)]
+pub use secondary_identity_update::*;
+#[doc(inline)]
 pub use user::*;
 #[doc(inline)]
+pub use user_authentication_method_update::*;
+#[doc(inline)]
 pub use user_create::*;
 #[doc(inline)]
 pub use user_delete::*;
@@ -16,6 +20,8 @@ pub use user_get::*;
 #[doc(inline)]
 pub use user_logs_get::*;
 #[doc(inline)]
+pub use user_roles_get::*;
+#[doc(inline)]
 pub use user_update::*;
 #[doc(inline)]
 pub use users_find::*;
@@ -24,13 +30,17 @@ use crate::{Auth0Client, Auth0Error, Auth0RequestSimple, Auth0Result};
 use serde::de::DeserializeOwned;
 use std::sync::Arc;
 
+pub mod export;
 pub mod permissions;
+pub mod secondary_identity_update;
 pub mod user;
+pub mod user_authentication_method_update;
 pub mod user_create;
 pub mod user_delete;
 pub mod user_enrollments_get;
 pub mod user_get;
 pub mod user_logs_get;
+pub mod user_roles_get;
 pub mod user_update;
 pub mod users_find;
 
@@ -51,7 +61,7 @@ impl UsersManager {
   ///
   /// # Scopes
   /// * `create:users`
-  pub fn create(&self) -> UserCreate<'_, (), ()> {
+  pub fn create(&self) -> UserCreate<'_> {
     UserCreate::new(&self.0)
   }
 
@@ -74,7 +84,7 @@ impl UsersManager {
   pub async fn get_enrollments<S: AsRef<str>>(
     &self,
     id: S,
-  ) -> Auth0Result<Vec<UserEnrollment>> {
+  ) -> Auth0Result<Vec<Enrollment>> {
     UserEnrollmentsGet::new(id).send_to(&self.0).await
   }
 
@@ -93,6 +103,23 @@ impl UsersManager {
     UserGet::new(id).send_to(&self.0).await
   }
 
+  /// Retrieve users with the given phone number.
+  ///
+  /// # Arguments
+  /// * `phone_number` - The phone number of the users to retrieve.
+  /// # Scopes
+  /// * `read:users`
+  pub async fn get_by_phone<A, U>(
+    &self,
+    phone_number: &str,
+  ) -> Result<Vec<User<A, U>>, Auth0Error>
+  where
+    A: DeserializeOwned + Send + Sync,
+    U: DeserializeOwned + Send + Sync,
+  {
+    UsersByPhoneGet::new(phone_number).send_to(&self.0).await
+  }
+
   /// Retrieve log events for a specific user.
   ///
   /// Note: For more information on all possible event types, their respective acronyms
@@ -114,31 +141,76 @@ impl UsersManager {
     UserLogsGet::new(&self.0, id)
   }
 
+  /// Retrieve the roles assigned to a user.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the user.
+  /// # Scopes
+  /// * `read:users`
+  /// * `read:roles`
+  pub fn get_roles<S: AsRef<str>>(&self, id: S) -> UserRolesGet<'_> {
+    UserRolesGet::new(&self.0, id)
+  }
+
   /// Update a user.
   /// Some considerations:
   ///
   /// * The properties of the new object will replace the old ones.
   /// * The metadata fields are an exception to this rule (`user_metadata` and
-  /// `app_metadata`). These properties are merged instead of being replaced but be
-  /// careful, the merge only occurs on the first level.
+  ///   `app_metadata`). These properties are merged instead of being replaced but be
+  ///   careful, the merge only occurs on the first level.
   /// * If you are updating `email`, `email_verified`, `phone_number`, `phone_verified`,
-  /// `username` or `password` of a secondary identity, you need to specify the connection
-  /// property too.
+  ///   `username` or `password` of a secondary identity, you need to specify the connection
+  ///   property too.
   /// * If you are updating `email` or `phone_number` you can specify, optionally, the
-  /// `client_id` property.
+  ///   `client_id` property.
   /// * Updating `email_verified` is not supported for enterprise and passwordless sms
-  /// connections.
+  ///   connections.
   /// * Updating the `blocked` to `false` does not affect the user's blocked state from an
-  /// excessive amount of incorrectly provided credentials. Use the "Unblock a user"
-  /// endpoint from the "User Blocks" API to change the user's state.
+  ///   excessive amount of incorrectly provided credentials. Use the "Unblock a user"
+  ///   endpoint from the "User Blocks" API to change the user's state.
   ///
   /// # Scopes
   /// * `update:users`
   /// * `update:users_app_metadata`
-  pub fn update<S: AsRef<str>>(&self, id: S) -> UserUpdate<'_, (), ()> {
+  pub fn update<S: AsRef<str>>(&self, id: S) -> UserUpdate<'_> {
     UserUpdate::new(&self.0, id)
   }
 
+  /// Mark an authentication method of a user as confirmed (true) or unconfirmed (false).
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the user.
+  /// * `method_id` - The ID of the authentication method.
+  /// # Scopes
+  /// * `update:authentication_methods`
+  pub async fn update_authentication_method<S: AsRef<str>, T: AsRef<str>>(
+    &self,
+    id: S,
+    method_id: T,
+    confirmed: bool,
+  ) -> Auth0Result<UserAuthenticationMethod> {
+    UserAuthenticationMethodUpdate::new(id, method_id)
+      .confirmed(confirmed)
+      .send_to(&self.0)
+      .await
+  }
+
+  /// Update the `email`, `phone_number` or `password` of a user's secondary identity.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the primary user.
+  /// * `connection` - The name of the connection of the identity to update.
+  /// # Scopes
+  /// * `update:users`
+  pub fn update_secondary_identity<S: AsRef<str>>(
+    &self,
+    id: S,
+    connection: &str,
+  ) -> SecondaryIdentityUpdate<'_> {
+    SecondaryIdentityUpdate::new(&self.0, id, connection)
+  }
+
   /// Retrieve details of users.
   ///
   /// It is possible to:
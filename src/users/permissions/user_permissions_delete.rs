@@ -2,6 +2,7 @@
 
 use reqwest::{Method, RequestBuilder};
 
+use crate::client::path_segment;
 use crate::Permission;
 use crate::{Auth0Client, Auth0RequestBuilder};
 
@@ -34,7 +35,7 @@ impl<'a> UserPermissionsDelete<'a> {
   ///
   /// # Arguments
   /// * `permission` - The permission to delete.
-  pub fn permission(&mut self, permission: Permission) -> &mut Self {
+  pub fn permission(mut self, permission: Permission) -> Self {
     self.permissions.push(permission);
     self
   }
@@ -43,7 +44,7 @@ impl<'a> UserPermissionsDelete<'a> {
   ///
   /// # Arguments
   /// * `permissions` - The permissions to delete.
-  pub fn permissions<P: AsRef<[Permission]>>(&mut self, permissions: P) -> &mut Self {
+  pub fn permissions<P: AsRef<[Permission]>>(mut self, permissions: P) -> Self {
     self.permissions.extend_from_slice(permissions.as_ref());
     self
   }
@@ -60,7 +61,7 @@ impl<'a> Auth0RequestBuilder for UserPermissionsDelete<'a> {
     client
       .begin(
         Method::DELETE,
-        &format!("api/v2/users/{}/permissions", self.id),
+        &format!("api/v2/users/{}/permissions", path_segment(&self.id)),
       )
       .json(&self.permissions)
   }
@@ -1,6 +1,7 @@
 //! Retrieve all permissions associated with the user.
 use reqwest::{Method, RequestBuilder};
 
+use crate::client::path_segment;
 use crate::Page;
 use crate::{Auth0Client, Auth0RequestBuilder};
 
@@ -49,7 +50,7 @@ impl<'a> Auth0RequestBuilder for UserPermissionsGet<'a> {
     client
       .begin(
         Method::GET,
-        &format!("api/v2/users/{}/permissions", self.id),
+        &format!("api/v2/users/{}/permissions", path_segment(&self.id)),
       )
       .query(&self.page)
   }
@@ -2,6 +2,7 @@
 
 use reqwest::{Method, RequestBuilder};
 
+use crate::client::path_segment;
 use crate::Permission;
 use crate::{Auth0Client, Auth0RequestBuilder};
 
@@ -39,7 +40,7 @@ impl<'a> UserPermissionsUpdate<'a> {
   ///
   /// # Arguments
   /// * `permission` - The permission to add.
-  pub fn permission(&mut self, permission: Permission) -> &mut Self {
+  pub fn permission(mut self, permission: Permission) -> Self {
     self.permissions.push(permission);
     self
   }
@@ -48,7 +49,7 @@ impl<'a> UserPermissionsUpdate<'a> {
   ///
   /// # Arguments
   /// * `permissions` - The permissions to add.
-  pub fn permissions<P: AsRef<[Permission]>>(&mut self, permissions: P) -> &mut Self {
+  pub fn permissions<P: AsRef<[Permission]>>(mut self, permissions: P) -> Self {
     self.permissions.extend_from_slice(permissions.as_ref());
     self
   }
@@ -65,7 +66,7 @@ impl<'a> Auth0RequestBuilder for UserPermissionsUpdate<'a> {
     client
       .begin(
         Method::POST,
-        &format!("api/v2/users/{}/permissions", self.id),
+        &format!("api/v2/users/{}/permissions", path_segment(&self.id)),
       )
       .json(&self.permissions)
   }
@@ -0,0 +1,109 @@
+//! Update a secondary identity of a user.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Update the `email`, `phone_number` or `password` of one of a user's linked (secondary)
+/// identities.  Auth0 uses `connection` to pick the identity to update, so it is always
+/// sent.
+///
+/// Updating `email_verified` is not supported for enterprise and passwordless sms
+/// connections.
+///
+/// Send as [User](crate::User).
+///
+/// # Scopes
+/// * `update:users`
+#[derive(Serialize)]
+pub struct SecondaryIdentityUpdate<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  user_id: String,
+  connection: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  email: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  email_verified: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  phone_number: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  phone_verified: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  password: Option<String>,
+}
+
+impl<'a> SecondaryIdentityUpdate<'a> {
+  /// Create update secondary identity request.
+  ///
+  /// # Arguments
+  /// * `user_id` - The ID of the primary user the identity is linked to.
+  /// * `connection` - The name of the connection of the identity to update.
+  pub fn new<S: AsRef<str>>(
+    client: &'a Auth0Client,
+    user_id: S,
+    connection: &str,
+  ) -> Self {
+    Self {
+      client,
+
+      user_id: user_id.as_ref().to_string(),
+      connection: connection.to_owned(),
+      email: None,
+      email_verified: None,
+      phone_number: None,
+      phone_verified: None,
+      password: None,
+    }
+  }
+
+  /// Email address of the identity.
+  pub fn email(mut self, email: &str) -> Self {
+    self.email = Some(email.to_owned());
+    self
+  }
+
+  /// Whether the email address is verified (true) or unverified (false).
+  pub fn email_verified(mut self, email_verified: bool) -> Self {
+    self.email_verified = Some(email_verified);
+    self
+  }
+
+  /// The identity's phone number (following the E.164 recommendation).
+  pub fn phone_number(mut self, phone_number: &str) -> Self {
+    self.phone_number = Some(phone_number.to_owned());
+    self
+  }
+
+  /// Whether the phone number has been verified (true) or not (false).
+  pub fn phone_verified(mut self, phone_verified: bool) -> Self {
+    self.phone_verified = Some(phone_verified);
+    self
+  }
+
+  /// New password for the identity.
+  pub fn password(mut self, password: &str) -> Self {
+    self.password = Some(password.to_owned());
+    self
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for SecondaryIdentityUpdate<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for SecondaryIdentityUpdate<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!("api/v2/users/{}", path_segment(&self.user_id)),
+      )
+      .json(self)
+  }
+}
@@ -1,6 +1,8 @@
 //! User
 use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// User identity.
 #[derive(Serialize, Deserialize, Debug)]
@@ -13,7 +15,7 @@ pub struct Identity {
 
 /// User
 #[derive(Deserialize, Debug)]
-pub struct User<AppMetadata, UserMetadata> {
+pub struct User<AppMetadata = Value, UserMetadata = Value> {
   /// ID of the user which can be used when interacting with other APIs.
   pub user_id: String,
   /// Email address of this user.
@@ -62,3 +64,17 @@ pub struct User<AppMetadata, UserMetadata> {
   /// User metadata to which this user has read/write access.
   pub user_metadata: Option<UserMetadata>,
 }
+
+impl User {
+  /// Deserialize the untyped app metadata into `T`.  Missing metadata is treated as
+  /// `null`.
+  pub fn app_metadata_as<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
+    serde_json::from_value(self.app_metadata.clone().unwrap_or_default())
+  }
+
+  /// Deserialize the untyped user metadata into `T`.  Missing metadata is treated as
+  /// `null`.
+  pub fn user_metadata_as<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
+    serde_json::from_value(self.user_metadata.clone().unwrap_or_default())
+  }
+}
@@ -0,0 +1,75 @@
+//! Update an authentication method of a user.
+use chrono::{DateTime, Utc};
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Authentication method a user is enrolled with, e.g. a phone number or authenticator app.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserAuthenticationMethod {
+  /// ID of the authentication method.
+  pub id: String,
+  /// Type of the authentication method, e.g. `phone` or `totp`.
+  #[serde(rename = "type")]
+  pub kind: String,
+  /// Whether the enrollment has been confirmed.
+  pub confirmed: Option<bool>,
+  /// Friendly name of the authentication method.
+  pub name: Option<String>,
+  /// Date and time the authentication method was created.
+  pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Update an authentication method of a user, e.g. to mark a phone number as confirmed.
+///
+/// Send as [UserAuthenticationMethod].
+///
+/// # Scopes
+/// * `update:authentication_methods`
+#[derive(Serialize)]
+pub struct UserAuthenticationMethodUpdate {
+  #[serde(skip_serializing)]
+  user_id: String,
+  #[serde(skip_serializing)]
+  method_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  confirmed: Option<bool>,
+}
+
+impl UserAuthenticationMethodUpdate {
+  /// Create update user authentication method request.
+  ///
+  /// # Arguments
+  /// * `user_id` - The ID of the user.
+  /// * `method_id` - The ID of the authentication method.
+  pub fn new<S: AsRef<str>, T: AsRef<str>>(user_id: S, method_id: T) -> Self {
+    Self {
+      user_id: user_id.as_ref().to_string(),
+      method_id: method_id.as_ref().to_string(),
+      confirmed: None,
+    }
+  }
+
+  /// Whether the enrollment has been confirmed.
+  pub fn confirmed(mut self, confirmed: bool) -> Self {
+    self.confirmed = Some(confirmed);
+    self
+  }
+}
+
+impl Auth0RequestBuilder for UserAuthenticationMethodUpdate {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::PATCH,
+        &format!(
+          "api/v2/users/{}/authentication-methods/{}",
+          path_segment(&self.user_id),
+          path_segment(&self.method_id)
+        ),
+      )
+      .json(self)
+  }
+}
@@ -2,14 +2,18 @@
 use reqwest::Method;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use serde_json::Value;
 
 use crate::users::User;
 use crate::{Auth0Client, Auth0Result};
 
 /// Create a new user for a given [database](https://auth0.com/docs/connections/database) or
 /// [passwordless](https://auth0.com/docs/connections/passwordless) connection.
+///
+/// Metadata is untyped [Value] until set with
+/// [app_metadata](Self::app_metadata) or [user_metadata](Self::user_metadata).
 #[derive(Serialize, Clone, Debug)]
-pub struct UserCreate<'a, A, U> {
+pub struct UserCreate<'a, A = Value, U = Value> {
   #[serde(skip_serializing)]
   client: &'a Auth0Client,
 
@@ -50,7 +54,7 @@ pub struct UserCreate<'a, A, U> {
   user_metadata: Option<U>,
 }
 
-impl<'a> UserCreate<'a, (), ()> {
+impl<'a> UserCreate<'a> {
   /// Create create user request.
   pub fn new(client: &'a Auth0Client) -> Self {
     Self {
@@ -78,154 +82,150 @@ impl<'a> UserCreate<'a, (), ()> {
   }
 }
 
-impl<'a, A, U> UserCreate<'a, A, U>
-where
-  A: Clone,
-  U: Clone,
-{
+impl<'a, A, U> UserCreate<'a, A, U> {
   /// The user's email.
-  pub fn email(&mut self, email: &str) -> &mut Self {
+  pub fn email(mut self, email: &str) -> Self {
     self.email = Some(email.to_owned());
     self
   }
 
   /// The user's phone number (following the E.164 recommendation), only valid for users from SMS
   /// connections.
-  pub fn phone_number(&mut self, phone_number: &str) -> &mut Self {
+  pub fn phone_number(mut self, phone_number: &str) -> Self {
     self.phone_number = Some(phone_number.to_owned());
     self
   }
 
   /// Whether this user was blocked by an administrator (true) or not (false).
-  pub fn blocked(&mut self, blocked: bool) -> &mut Self {
+  pub fn blocked(mut self, blocked: bool) -> Self {
     self.blocked = Some(blocked);
     self
   }
 
   /// Whether this email address is verified (true) or unverified (false). User will receive a
   /// verification email after creation if email_verified is false or not specified.
-  pub fn email_verified(&mut self, email_verified: bool) -> &mut Self {
+  pub fn email_verified(mut self, email_verified: bool) -> Self {
     self.email_verified = Some(email_verified);
     self
   }
 
   /// Whether this phone number has been verified (true) or not (false).
-  pub fn phone_verified(&mut self, phone_verified: bool) -> &mut Self {
+  pub fn phone_verified(mut self, phone_verified: bool) -> Self {
     self.phone_verified = Some(phone_verified);
     self
   }
 
   /// The user's given name(s).
-  pub fn given_name(&mut self, given_name: &str) -> &mut Self {
+  pub fn given_name(mut self, given_name: &str) -> Self {
     self.given_name = Some(given_name.to_owned());
     self
   }
 
   /// The user's family name(s).
-  pub fn family_name(&mut self, family_name: &str) -> &mut Self {
+  pub fn family_name(mut self, family_name: &str) -> Self {
     self.family_name = Some(family_name.to_owned());
     self
   }
 
   /// The user's full name.
-  pub fn name(&mut self, name: &str) -> &mut Self {
+  pub fn name(mut self, name: &str) -> Self {
     self.name = Some(name.to_owned());
     self
   }
 
   /// The user's nickname.
-  pub fn nickname(&mut self, nickname: &str) -> &mut Self {
+  pub fn nickname(mut self, nickname: &str) -> Self {
     self.nickname = Some(nickname.to_owned());
     self
   }
 
   /// A URI pointing to the user's picture.
-  pub fn picture(&mut self, picture: &str) -> &mut Self {
+  pub fn picture(mut self, picture: &str) -> Self {
     self.picture = Some(picture.to_owned());
     self
   }
 
   /// The external user's id provided by the identity provider.
-  pub fn user_id(&mut self, user_id: &str) -> &mut Self {
+  pub fn user_id(mut self, user_id: &str) -> Self {
     self.user_id = Some(user_id.to_owned());
     self
   }
 
   /// Name of the connection this user should be created in.
-  pub fn connection(&mut self, connection: &str) -> &mut Self {
+  pub fn connection(mut self, connection: &str) -> Self {
     self.connection = Some(connection.to_owned());
     self
   }
 
   /// Initial password for this user (mandatory for non-SMS connections).
-  pub fn password(&mut self, password: &str) -> &mut Self {
+  pub fn password(mut self, password: &str) -> Self {
     self.password = Some(password.to_owned());
     self
   }
 
   /// Whether the user will receive a verification email after creation (true) or no email (false).
   /// Overrides behavior of email_verified parameter.
-  pub fn verify_email(&mut self, verify_email: bool) -> &mut Self {
+  pub fn verify_email(mut self, verify_email: bool) -> Self {
     self.verify_email = Some(verify_email);
     self
   }
 
   /// The user's username. Only valid if the connection requires a username.
-  pub fn username(&mut self, username: &str) -> &mut Self {
+  pub fn username(mut self, username: &str) -> Self {
     self.username = Some(username.to_owned());
     self
   }
 
   /// Data related to the user that does affect the application's core functionality.
-  pub fn app_metadata<AppMetadata: Clone>(
-    &mut self,
+  pub fn app_metadata<AppMetadata>(
+    self,
     app_metadata: AppMetadata,
   ) -> UserCreate<'a, AppMetadata, U> {
     UserCreate {
       client: self.client,
-      email: self.email.clone(),
-      phone_number: self.phone_number.clone(),
+      email: self.email,
+      phone_number: self.phone_number,
       blocked: self.blocked,
       email_verified: self.email_verified,
       phone_verified: self.phone_verified,
-      given_name: self.given_name.clone(),
-      family_name: self.family_name.clone(),
-      name: self.name.clone(),
-      nickname: self.nickname.clone(),
-      picture: self.picture.clone(),
-      user_id: self.user_id.clone(),
-      connection: self.connection.clone(),
-      password: self.password.clone(),
+      given_name: self.given_name,
+      family_name: self.family_name,
+      name: self.name,
+      nickname: self.nickname,
+      picture: self.picture,
+      user_id: self.user_id,
+      connection: self.connection,
+      password: self.password,
       verify_email: self.verify_email,
-      username: self.username.clone(),
+      username: self.username,
       app_metadata: Some(app_metadata),
-      user_metadata: self.user_metadata.clone(),
+      user_metadata: self.user_metadata,
     }
   }
 
   /// Data related to the user that does not affect the application's core functionality.
-  pub fn user_metadata<UserMetadata: Clone>(
-    &mut self,
+  pub fn user_metadata<UserMetadata>(
+    self,
     user_metadata: UserMetadata,
   ) -> UserCreate<'a, A, UserMetadata> {
     UserCreate {
       client: self.client,
-      email: self.email.clone(),
-      phone_number: self.phone_number.clone(),
+      email: self.email,
+      phone_number: self.phone_number,
       blocked: self.blocked,
       email_verified: self.email_verified,
       phone_verified: self.phone_verified,
-      given_name: self.given_name.clone(),
-      family_name: self.family_name.clone(),
-      name: self.name.clone(),
-      nickname: self.nickname.clone(),
-      picture: self.picture.clone(),
-      user_id: self.user_id.clone(),
-      connection: self.connection.clone(),
-      password: self.password.clone(),
+      given_name: self.given_name,
+      family_name: self.family_name,
+      name: self.name,
+      nickname: self.nickname,
+      picture: self.picture,
+      user_id: self.user_id,
+      connection: self.connection,
+      password: self.password,
       verify_email: self.verify_email,
-      username: self.username.clone(),
-      app_metadata: self.app_metadata.clone(),
+      username: self.username,
+      app_metadata: self.app_metadata,
       user_metadata: Some(user_metadata),
     }
   }
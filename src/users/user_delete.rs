@@ -1,6 +1,7 @@
 //! Delete a user.
 use reqwest::{Method, RequestBuilder};
 
+use crate::client::path_segment;
 use crate::{Auth0Client, Auth0RequestBuilder};
 
 /// Delete a user.
@@ -18,6 +19,9 @@ impl UserDelete {
 
 impl Auth0RequestBuilder for UserDelete {
   fn build(&self, client: &Auth0Client) -> RequestBuilder {
-    client.begin(Method::DELETE, &format!("api/v2/users/{}", self.0))
+    client.begin(
+      Method::DELETE,
+      &format!("api/v2/users/{}", path_segment(&self.0)),
+    )
   }
 }
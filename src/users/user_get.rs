@@ -1,11 +1,13 @@
 //! Retrieve user details. A list of fields to include or exclude may also be specified.
 use reqwest::{Method, RequestBuilder};
 
-use crate::{Auth0Client, Auth0RequestBuilder};
+use crate::client::path_segment;
+use crate::{Auth0Client, Auth0RequestBuilder, Fields, LuceneQuery};
 
 /// Retrieve user details. A list of fields to include or exclude may also be specified.
 pub struct UserGet {
   id: String,
+  fields: Fields,
 }
 
 impl UserGet {
@@ -13,13 +15,25 @@ impl UserGet {
   pub fn new<S: AsRef<str>>(id: S) -> Self {
     Self {
       id: id.as_ref().to_string(),
+      fields: Default::default(),
     }
   }
 }
 
+impl AsMut<Fields> for UserGet {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
 impl Auth0RequestBuilder for UserGet {
   fn build(&self, client: &Auth0Client) -> RequestBuilder {
-    client.begin(Method::GET, &format!("api/v2/users/{}", self.id))
+    client
+      .begin(
+        Method::GET,
+        &format!("api/v2/users/{}", path_segment(&self.id)),
+      )
+      .query(&self.fields)
   }
 }
 
@@ -46,3 +60,35 @@ impl Auth0RequestBuilder for GetUserByEmail {
       .query(&[("email", self.email.to_owned())])
   }
 }
+
+/// Search for users with the given phone number, e.g. for tenants using SMS
+/// passwordless login.
+///
+/// Send as `Vec<User<A, U>>`.
+///
+/// # Scopes
+/// * `read:users`
+pub struct UsersByPhoneGet {
+  phone_number: String,
+}
+
+impl UsersByPhoneGet {
+  /// Create get users by phone number request.
+  /// # Arguments
+  /// * `phone_number` - The phone number of the users to retrieve, e.g. `+15555550100`.
+  pub fn new(phone_number: &str) -> Self {
+    Self {
+      phone_number: phone_number.to_owned(),
+    }
+  }
+}
+
+impl Auth0RequestBuilder for UsersByPhoneGet {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    let q = LuceneQuery::new().and_eq("phone_number", &self.phone_number);
+
+    client
+      .begin(Method::GET, "api/v2/users")
+      .query(&[("q", q.to_string()), ("search_engine", "v3".to_owned())])
+  }
+}
@@ -1,82 +1,14 @@
 //! Retrieve log events for a specific user.
 use chrono::{DateTime, Utc};
 use reqwest::{Method, RequestBuilder};
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde::Serialize;
 
+use crate::client::path_segment;
 use crate::{Auth0Client, Auth0RequestBuilder};
-use crate::{Page, Sort};
-
-/// User log event.
-#[derive(Debug, Deserialize)]
-pub struct UserLog {
-  /// Date when the event occurred.
-  pub date: DateTime<Utc>,
-  /// Type of event.
-  #[serde(rename = "type")]
-  pub kind: String,
-  /// Description of this event.
-  pub description: String,
-  /// Name of the connection the event relates to.
-  pub connection: String,
-  /// ID of the connection the event relates to.
-  pub connection_id: String,
-  /// ID of the client (application).
-  pub client_id: String,
-  /// Name of the client (application).
-  pub client_name: String,
-  /// IP address of the log event source.
-  pub ip: String,
-  /// Hostname the event applies to.
-  pub hostname: Option<String>,
-  /// ID of the user involved in the event.
-  pub user_id: String,
-  /// Name of the user involved in the event.
-  pub user_name: String,
-  /// API audience the event applies to.
-  pub audience: Option<String>,
-  /// Scope permissions applied to the event.
-  pub scope: Option<String>,
-  /// Name of the strategy involved in the event.
-  pub strategy: String,
-  /// Type of strategy involved in the event.
-  pub strategy_type: String,
-  /// Unique ID of the event.
-  pub log_id: String,
-  /// Whether the client was a mobile device (true) or desktop/laptop/server (false).
-  #[serde(rename = "isMobile")]
-  pub is_mobile: bool,
-  /// User agent string from the client device that caused the event.
-  pub user_agent: String,
-  /// Additional useful details about this event (structure is dependent upon event type).
-  pub details: Value,
-  /// Information about the location that triggered this event based on the ip.
-  pub location_info: UserLogLocationInfo,
-}
+use crate::{LogEventType, LogPagination, LogQuery, Page, Sort};
 
-/// User log event location.
-#[derive(Debug, Deserialize)]
-pub struct UserLogLocationInfo {
-  /// Two-letter [Alpha-2 ISO 3166-1](https://www.iso.org/iso-3166-country-codes.html)
-  /// country code.
-  pub country_code: String,
-  /// Three-letter [Alpha-3 ISO 3166-1](https://www.iso.org/iso-3166-country-codes.html)
-  /// country code.
-  pub country_code3: String,
-  /// Full country name in English.
-  pub country_name: String,
-  /// Full city name in English.
-  pub city_name: String,
-  /// Global latitude (horizontal) position.
-  pub latitude: f32,
-  /// Global longitude (vertical) position.
-  pub longitude: f32,
-  /// Time zone name as found in the [tz database](https://www.iana.org/time-zones).
-  pub time_zone: String,
-  /// Continent the country is located within. Can be AF (Africa), AN (Antarctica),
-  /// AS (Asia), EU (Europe), NA (North America), OC (Oceania) or SA (South America).
-  pub continent_code: String,
-}
+#[doc(no_inline)]
+pub use crate::logs::{UserLog, UserLogLocationInfo};
 
 /// Retrieve log events for a specific user.
 #[derive(Serialize)]
@@ -86,8 +18,18 @@ pub struct UserLogsGet<'a> {
 
   #[serde(skip)]
   id: String,
+  #[serde(skip)]
+  connection_id: Option<String>,
+  #[serde(skip)]
+  event_types: Vec<LogEventType>,
+  #[serde(skip)]
+  since: Option<DateTime<Utc>>,
+  #[serde(skip)]
+  until: Option<DateTime<Utc>>,
+  #[serde(skip)]
+  q: Option<String>,
   #[serde(flatten)]
-  page: Page,
+  pagination: LogPagination,
   #[serde(skip_serializing_if = "Sort::is_emtpy")]
   sort: Sort,
 }
@@ -99,15 +41,100 @@ impl<'a> UserLogsGet<'a> {
       client,
 
       id: id.as_ref().to_string(),
-      page: Default::default(),
+      connection_id: None,
+      event_types: Vec::new(),
+      since: None,
+      until: None,
+      q: None,
+      pagination: Default::default(),
       sort: Default::default(),
     }
   }
+
+  /// Log event ID to start retrieving logs from (checkpoint pagination).  Replaces any
+  /// `page` and `per_page` parameters.
+  pub fn from(mut self, log_id: &str) -> Self {
+    self.pagination = LogPagination::Checkpoint(log_id.to_owned());
+    self
+  }
+
+  /// Only retrieve log events from the connection with this ID, useful when the user
+  /// has several identities.  The user logs endpoint has no such filter, so the request
+  /// is sent to the log search endpoint instead, which requires the `read:logs` scope.
+  pub fn for_connection(mut self, connection_id: &str) -> Self {
+    self.connection_id = Some(connection_id.to_owned());
+    self
+  }
+
+  /// Only retrieve log events of this type, e.g. [LogEventType::FailedLogin].  Call
+  /// again to also include other types.  Like [UserLogsGet::for_connection], this sends
+  /// the request to the log search endpoint.
+  pub fn event_type(mut self, event_type: LogEventType) -> Self {
+    self.event_types.push(event_type);
+    self
+  }
+
+  /// Only retrieve log events that happened at or after this time.  Like
+  /// [UserLogsGet::for_connection], this sends the request to the log search endpoint.
+  pub fn since(mut self, since: DateTime<Utc>) -> Self {
+    self.since = Some(since);
+    self
+  }
+
+  /// Only retrieve log events that happened at or before this time.  Like
+  /// [UserLogsGet::for_connection], this sends the request to the log search endpoint.
+  pub fn until(mut self, until: DateTime<Utc>) -> Self {
+    self.until = Some(until);
+    self
+  }
+
+  /// Only retrieve log events matching a query in
+  /// [Lucene query string syntax](https://auth0.com/docs/logs/log-search-query-syntax).
+  /// Combined with [UserLogsGet::event_type] using `AND`.  Like
+  /// [UserLogsGet::for_connection], this sends the request to the log search endpoint.
+  pub fn q(mut self, q: &str) -> Self {
+    self.q = Some(q.to_owned());
+    self
+  }
+
+  /// Log search query restricting the results to this user, or `None` when no filter
+  /// requires the log search endpoint.
+  fn search_query(&self) -> Option<String> {
+    if self.connection_id.is_none()
+      && self.event_types.is_empty()
+      && self.since.is_none()
+      && self.until.is_none()
+      && self.q.is_none()
+    {
+      return None;
+    }
+
+    let mut query = LogQuery::new().user_id(&self.id);
+    if let Some(connection_id) = &self.connection_id {
+      query = query.connection_id(connection_id);
+    }
+    if let Some(since) = self.since {
+      query = query.since(since);
+    }
+    if let Some(until) = self.until {
+      query = query.until(until);
+    }
+    for event_type in &self.event_types {
+      query = query.event_type(event_type.clone());
+    }
+
+    let mut q = query.build();
+    if let Some(custom) = &self.q {
+      q.push_str(&format!(" AND ({})", custom));
+    }
+
+    Some(q)
+  }
 }
 
 impl<'a> AsMut<Page> for UserLogsGet<'a> {
   fn as_mut(&mut self) -> &mut Page {
-    &mut self.page
+    self.pagination.offset_mut()
   }
 }
 
@@ -125,8 +152,17 @@ impl<'a> AsRef<Auth0Client> for UserLogsGet<'a> {
 
 impl<'a> Auth0RequestBuilder for UserLogsGet<'a> {
   fn build(&self, client: &Auth0Client) -> RequestBuilder {
-    client
-      .begin(Method::GET, &format!("api/v2/users/{}/logs", self.id))
-      .query(&self)
+    match self.search_query() {
+      Some(q) => client
+        .begin(Method::GET, "api/v2/logs")
+        .query(&[("q", q)])
+        .query(&self),
+      None => client
+        .begin(
+          Method::GET,
+          &format!("api/v2/users/{}/logs", path_segment(&self.id)),
+        )
+        .query(&self),
+    }
   }
 }
@@ -0,0 +1,74 @@
+//! Retrieve the roles assigned to a user.
+use reqwest::{Method, RequestBuilder};
+use serde::Serialize;
+
+use crate::client::path_segment;
+use crate::Page;
+use crate::{Auth0Client, Auth0RequestBuilder};
+
+/// Retrieve the roles assigned to a user.
+///
+/// Send as `Vec<Role>`, or as `ResponsePage<Role>` when [UserRolesGet::include_totals]
+/// is set.
+///
+/// # Scopes
+/// * `read:users`
+/// * `read:roles`
+#[derive(Serialize)]
+pub struct UserRolesGet<'a> {
+  #[serde(skip_serializing)]
+  client: &'a Auth0Client,
+
+  #[serde(skip_serializing)]
+  id: String,
+  #[serde(flatten)]
+  page: Page,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  include_totals: Option<bool>,
+}
+
+impl<'a> UserRolesGet<'a> {
+  /// Create get user roles request.
+  ///
+  /// # Arguments
+  /// * `id` - The ID of the user.
+  pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
+    Self {
+      client,
+
+      id: id.as_ref().to_string(),
+      page: Default::default(),
+      include_totals: None,
+    }
+  }
+
+  /// Return results inside an object that contains the total result count (true) or as a
+  /// direct array of results (false, default).
+  pub fn include_totals(mut self, include_totals: bool) -> Self {
+    self.include_totals = Some(include_totals);
+    self
+  }
+}
+
+impl<'a> AsMut<Page> for UserRolesGet<'a> {
+  fn as_mut(&mut self) -> &mut Page {
+    &mut self.page
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for UserRolesGet<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
+impl<'a> Auth0RequestBuilder for UserRolesGet<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client
+      .begin(
+        Method::GET,
+        &format!("api/v2/users/{}/roles", path_segment(&self.id)),
+      )
+      .query(&self)
+  }
+}
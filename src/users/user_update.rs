@@ -2,13 +2,18 @@
 use reqwest::Method;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use serde_json::Value;
 
+use crate::client::path_segment;
 use crate::users::User;
 use crate::{Auth0Client, Auth0Result};
 
 /// Update a user.
+///
+/// Metadata stays untyped [serde_json::Value] unless replaced with
+/// [app_metadata](Self::app_metadata) or [user_metadata](Self::user_metadata).
 #[derive(Serialize)]
-pub struct UserUpdate<'a, A, U> {
+pub struct UserUpdate<'a, A = Value, U = Value> {
   #[serde(skip_serializing)]
   client: &'a Auth0Client,
 
@@ -33,6 +38,8 @@ pub struct UserUpdate<'a, A, U> {
   #[serde(skip_serializing_if = "Option::is_none")]
   nickname: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
+  username: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   picture: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   password: Option<String>,
@@ -44,6 +51,10 @@ pub struct UserUpdate<'a, A, U> {
   verify_email: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
   verify_phone_number: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  multifactor: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  custom_domain: Option<String>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   app_metadata: Option<A>,
@@ -51,7 +62,7 @@ pub struct UserUpdate<'a, A, U> {
   user_metadata: Option<U>,
 }
 
-impl<'a> UserUpdate<'a, (), ()> {
+impl<'a> UserUpdate<'a> {
   /// Create update user request.
   pub fn new<S: AsRef<str>>(client: &'a Auth0Client, id: S) -> Self {
     Self {
@@ -67,171 +78,205 @@ impl<'a> UserUpdate<'a, (), ()> {
       family_name: None,
       name: None,
       nickname: None,
+      username: None,
       picture: None,
       verify_email: None,
       verify_phone_number: None,
       password: None,
       connection: None,
       client_id: None,
+      multifactor: None,
+      custom_domain: None,
       app_metadata: None,
       user_metadata: None,
     }
   }
 }
 
-impl<'a, A: Clone, U: Clone> UserUpdate<'a, A, U> {
+impl<'a, A, U> UserUpdate<'a, A, U> {
   /// ID of the user which can be used when interacting with other APIs.
-  pub fn user_id(&mut self, id: &str) -> &mut Self {
+  pub fn user_id(mut self, id: &str) -> Self {
     self.user_id = id.to_owned();
     self
   }
 
   /// Whether this user was blocked by an administrator (true) or not (false).
-  pub fn blocked(&mut self, blocked: bool) -> &mut Self {
+  pub fn blocked(mut self, blocked: bool) -> Self {
     self.blocked = Some(blocked);
     self
   }
 
   /// Email address of this user.
-  pub fn email(&mut self, email: &str) -> &mut Self {
+  pub fn email(mut self, email: &str) -> Self {
     self.email = Some(email.to_owned());
     self
   }
 
   /// Whether this email address is verified (true) or unverified (false). If set to false the user
   /// will not receive a verification email unless `verify_email` is set to true.
-  pub fn email_verified(&mut self, email_verified: bool) -> &mut Self {
+  pub fn email_verified(mut self, email_verified: bool) -> Self {
     self.email_verified = Some(email_verified);
     self
   }
 
   /// The user's phone number (following the E.164 recommendation), only valid for users from SMS
   /// connections.
-  pub fn phone_number(&mut self, phone_number: &str) -> &mut Self {
+  pub fn phone_number(mut self, phone_number: &str) -> Self {
     self.phone_number = Some(phone_number.to_owned());
     self
   }
 
   /// Whether this phone number has been verified (true) or not (false).
-  pub fn phone_verified(&mut self, phone_verified: bool) -> &mut Self {
+  pub fn phone_verified(mut self, phone_verified: bool) -> Self {
     self.phone_verified = Some(phone_verified);
     self
   }
 
   /// Given name/first name/forename of this user.
-  pub fn given_name(&mut self, given_name: &str) -> &mut Self {
+  pub fn given_name(mut self, given_name: &str) -> Self {
     self.given_name = Some(given_name.to_owned());
     self
   }
 
   /// Family name/last name/surname of this user.
-  pub fn family_name(&mut self, family_name: &str) -> &mut Self {
+  pub fn family_name(mut self, family_name: &str) -> Self {
     self.family_name = Some(family_name.to_owned());
     self
   }
 
   /// Name of this user.
-  pub fn name(&mut self, name: &str) -> &mut Self {
+  pub fn name(mut self, name: &str) -> Self {
     self.name = Some(name.to_owned());
     self
   }
 
   /// Preferred nickname or alias of this user.
-  pub fn nickname(&mut self, nickname: &str) -> &mut Self {
+  pub fn nickname(mut self, nickname: &str) -> Self {
     self.nickname = Some(nickname.to_owned());
     self
   }
 
+  /// The user's username.  Only valid for users of database connections with
+  /// `requires_username` enabled; Auth0 rejects the update with a 400 error otherwise.
+  pub fn username(mut self, username: &str) -> Self {
+    self.username = Some(username.to_owned());
+    self
+  }
+
   /// URL to picture, photo, or avatar of this user.
-  pub fn picture(&mut self, picture: &str) -> &mut Self {
+  pub fn picture(mut self, picture: &str) -> Self {
     self.picture = Some(picture.to_owned());
     self
   }
 
   /// Whether this user will receive a verification email after creation (true) or no email (false).
   /// Overrides behavior of `email_verified` parameter.
-  pub fn verify_email(&mut self, verify_email: bool) -> &mut Self {
+  pub fn verify_email(mut self, verify_email: bool) -> Self {
     self.verify_email = Some(verify_email);
     self
   }
 
   /// Whether this user will receive a text after changing the phone number (true) or no text
   /// (false). Only valid when changing phone number.
-  pub fn verify_phone_number(&mut self, verify_phone_number: bool) -> &mut Self {
+  pub fn verify_phone_number(mut self, verify_phone_number: bool) -> Self {
     self.verify_phone_number = Some(verify_phone_number);
     self
   }
 
   /// New password for this user (mandatory for non-SMS connections).
-  pub fn password(&mut self, password: &str) -> &mut Self {
+  pub fn password(mut self, password: &str) -> Self {
     self.password = Some(password.to_owned());
     self
   }
 
   /// ID of the connection this user should be created in.
-  pub fn connection(&mut self, connection: &str) -> &mut Self {
+  pub fn connection(mut self, connection: &str) -> Self {
     self.connection = Some(connection.to_owned());
     self
   }
 
   /// Auth0 client ID. Only valid when updating email address.
-  pub fn client_id(&mut self, client_id: &str) -> &mut Self {
+  pub fn client_id(mut self, client_id: &str) -> Self {
     self.client_id = Some(client_id.to_owned());
     self
   }
 
+  /// Add a multi-factor authentication provider (e.g. `duo` or `google-authenticator`) to
+  /// the list of providers this user is enrolled with.
+  pub fn multifactor_provider(mut self, provider: &str) -> Self {
+    self
+      .multifactor
+      .get_or_insert_with(Vec::new)
+      .push(provider.to_owned());
+    self
+  }
+
+  /// Custom domain that maps the user's email domain to a specific enterprise
+  /// connection.  Only relevant for users of enterprise connections; ignored for
+  /// database connection users.
+  pub fn custom_domain(mut self, domain: &str) -> Self {
+    self.custom_domain = Some(domain.to_owned());
+    self
+  }
+
   /// User metadata to which this user has read-only access.
   pub fn app_metadata<AppMetadata>(
-    &self,
+    self,
     app_metadata: AppMetadata,
   ) -> UserUpdate<'a, AppMetadata, U> {
     UserUpdate {
       client: self.client,
-      user_id: self.user_id.clone(),
+      user_id: self.user_id,
       blocked: self.blocked,
-      email: self.email.clone(),
+      email: self.email,
       email_verified: self.email_verified,
-      phone_number: self.phone_number.clone(),
+      phone_number: self.phone_number,
       phone_verified: self.phone_verified,
-      given_name: self.given_name.clone(),
-      family_name: self.family_name.clone(),
-      name: self.name.clone(),
-      nickname: self.nickname.clone(),
-      picture: self.picture.clone(),
-      password: self.password.clone(),
-      connection: self.connection.clone(),
-      client_id: self.client_id.clone(),
+      given_name: self.given_name,
+      family_name: self.family_name,
+      name: self.name,
+      nickname: self.nickname,
+      username: self.username,
+      picture: self.picture,
+      password: self.password,
+      connection: self.connection,
+      client_id: self.client_id,
       verify_email: self.verify_email,
       verify_phone_number: self.verify_phone_number,
+      multifactor: self.multifactor,
+      custom_domain: self.custom_domain,
       app_metadata: Some(app_metadata),
-      user_metadata: self.user_metadata.clone(),
+      user_metadata: self.user_metadata,
     }
   }
 
   /// User metadata to which this user has read/write access.
   pub fn user_metadata<UserMetadata>(
-    &mut self,
+    self,
     user_metadata: UserMetadata,
   ) -> UserUpdate<'a, A, UserMetadata> {
     UserUpdate {
       client: self.client,
-      user_id: self.user_id.clone(),
+      user_id: self.user_id,
       blocked: self.blocked,
-      email: self.email.clone(),
+      email: self.email,
       email_verified: self.email_verified,
-      phone_number: self.phone_number.clone(),
+      phone_number: self.phone_number,
       phone_verified: self.phone_verified,
-      given_name: self.given_name.clone(),
-      family_name: self.family_name.clone(),
-      name: self.name.clone(),
-      nickname: self.nickname.clone(),
-      picture: self.picture.clone(),
-      password: self.password.clone(),
-      connection: self.connection.clone(),
-      client_id: self.client_id.clone(),
+      given_name: self.given_name,
+      family_name: self.family_name,
+      name: self.name,
+      nickname: self.nickname,
+      username: self.username,
+      picture: self.picture,
+      password: self.password,
+      connection: self.connection,
+      client_id: self.client_id,
       verify_email: self.verify_email,
       verify_phone_number: self.verify_phone_number,
-      app_metadata: self.app_metadata.clone(),
+      multifactor: self.multifactor,
+      custom_domain: self.custom_domain,
+      app_metadata: self.app_metadata,
       user_metadata: Some(user_metadata),
     }
   }
@@ -251,7 +296,10 @@ impl<'a, AIn, UIn> UserUpdate<'a, AIn, UIn> {
       .send(
         self
           .client
-          .begin(Method::PATCH, &format!("api/v2/users/{}", self.user_id))
+          .begin(
+            Method::PATCH,
+            &format!("api/v2/users/{}", path_segment(&self.user_id)),
+          )
           .json(self),
       )
       .await
@@ -1,10 +1,23 @@
 //! Retrieve details of users.
-use reqwest::Method;
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::{Method, RequestBuilder};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use crate::{Auth0Client, Auth0Result, User};
-use crate::{Page, Sort};
+use crate::{Auth0Client, Auth0RequestBuilder, Auth0Result, User};
+use crate::{Fields, Page, PaginatedStream, Sort};
+
+/// Version of the user search engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchEngine {
+  /// Legacy search engine.
+  V1,
+  /// Legacy search engine.
+  V2,
+  /// Current search engine.
+  V3,
+}
 
 /// Retrieve details of users.
 #[derive(Serialize)]
@@ -12,10 +25,16 @@ pub struct UsersFind<'a> {
   #[serde(skip_serializing)]
   client: &'a Auth0Client,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
+  q: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  search_engine: Option<SearchEngine>,
   #[serde(flatten)]
   page: Page,
   #[serde(skip_serializing_if = "Sort::is_emtpy")]
   sort: Sort,
+  #[serde(flatten)]
+  fields: Fields,
 }
 
 impl<'a> UsersFind<'a> {
@@ -24,10 +43,27 @@ impl<'a> UsersFind<'a> {
     Self {
       client,
 
+      q: None,
+      search_engine: None,
       page: Default::default(),
       sort: Default::default(),
+      fields: Default::default(),
     }
   }
+
+  /// Query in [Lucene query string syntax](https://auth0.com/docs/manage-users/user-search/user-search-query-syntax).
+  /// Accepts a string or a [LuceneQuery](crate::LuceneQuery).
+  pub fn q<Q: ToString>(mut self, q: Q) -> Self {
+    self.q = Some(q.to_string());
+    self
+  }
+
+  /// The version of the search engine to use.  Use [SearchEngine::V3] for boosted
+  /// queries.
+  pub fn search_engine(mut self, search_engine: SearchEngine) -> Self {
+    self.search_engine = Some(search_engine);
+    self
+  }
 }
 
 impl<'a> AsMut<Page> for UsersFind<'a> {
@@ -42,6 +78,18 @@ impl<'a> AsMut<Sort> for UsersFind<'a> {
   }
 }
 
+impl<'a> AsMut<Fields> for UsersFind<'a> {
+  fn as_mut(&mut self) -> &mut Fields {
+    &mut self.fields
+  }
+}
+
+impl<'a> AsRef<Auth0Client> for UsersFind<'a> {
+  fn as_ref(&self) -> &Auth0Client {
+    self.client
+  }
+}
+
 impl<'a> UsersFind<'a> {
   /// Send
   pub async fn send<AOut, UOut>(&self) -> Auth0Result<Vec<User<AOut, UOut>>>
@@ -49,9 +97,35 @@ impl<'a> UsersFind<'a> {
     AOut: DeserializeOwned,
     UOut: DeserializeOwned,
   {
-    self
-      .client
-      .send(self.client.begin(Method::GET, "api/v2/users").query(self))
-      .await
+    self.client.send(self.build(self.client)).await
+  }
+
+  /// Stream every user matching this request one at a time.
+  ///
+  /// Pages are requested lazily by a [PaginatedStream], starting
+  /// from the configured page (or the first page).  Only a single page of users is held
+  /// in memory at once.  The stream ends after yielding the first error.
+  ///
+  /// Note: Auth0 only allows paginating through the first 1000 users of a search.  Use
+  /// an export job to retrieve more.
+  pub fn into_user_stream<AOut, UOut>(
+    self,
+  ) -> impl Stream<Item = Auth0Result<User<AOut, UOut>>> + 'a
+  where
+    AOut: DeserializeOwned + Send + 'a,
+    UOut: DeserializeOwned + Send + 'a,
+  {
+    PaginatedStream::<User<AOut, UOut>>::new(self).flat_map(|page| {
+      stream::iter(match page {
+        Ok(users) => users.into_iter().map(Ok).collect(),
+        Err(err) => vec![Err(err)],
+      })
+    })
+  }
+}
+
+impl<'a> Auth0RequestBuilder for UsersFind<'a> {
+  fn build(&self, client: &Auth0Client) -> RequestBuilder {
+    client.begin(Method::GET, "api/v2/users").query(self)
   }
 }
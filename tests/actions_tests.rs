@@ -0,0 +1,55 @@
+use auth0_management::{
+  ActionBindingRef, ActionBindingUpdate, Auth0, Auth0RequestBuilder,
+};
+
+use crate::helpers::{get_mock_client, get_request_client, serve_json};
+
+mod helpers;
+
+#[test]
+fn test_binding_update_body() {
+  let client = get_request_client();
+  let req = ActionBindingUpdate::new(&client, "post-login")
+    .binding(
+      ActionBindingRef::ActionId("act_1".to_owned()),
+      Some("First"),
+    )
+    .binding(ActionBindingRef::ActionName("second".to_owned()), None)
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/actions/triggers/post-login/bindings"
+  );
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "bindings": [
+        { "ref": { "type": "action_id", "value": "act_1" }, "display_name": "First" },
+        { "ref": { "type": "action_name", "value": "second" } }
+      ]
+    })
+  );
+}
+
+#[tokio::test]
+async fn test_deploy_returns_version() {
+  let (base_url, server) = serve_json(vec![
+    r#"{"id":"ver_1","code":"exports.onExecutePostLogin = async () => {};","runtime":"node18","status":"built","number":3,"deployed":true,"created_at":"2024-01-01T00:00:00Z"}"#,
+  ]);
+  let auth0 = Auth0::new(get_mock_client(&base_url));
+
+  let version = auth0.actions.deploy("act_1").await.unwrap();
+
+  assert_eq!(version.id, "ver_1");
+  assert_eq!(version.number, 3);
+  assert!(version.deployed);
+  assert_eq!(
+    server.join().unwrap(),
+    vec!["/api/v2/actions/actions/act_1/deploy"]
+  );
+}
@@ -0,0 +1,19 @@
+use auth0_management::{AnomalyIpBlockGet, Auth0RequestBuilder};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_ip_block_get_encodes_ipv6() {
+  let client = get_request_client();
+  let req = AnomalyIpBlockGet::new("2001:db8::1")
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/anomaly/blocks/ips/2001%3Adb8%3A%3A1"
+  );
+}
@@ -0,0 +1,70 @@
+use auth0_management::{
+  Auth0RequestBuilder, BruteForceMode, BruteForceProtection, BruteForceShield,
+  SuspiciousIpThrottling, SuspiciousIpThrottlingUpdate,
+};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_suspicious_ip_throttling_update_stage() {
+  let client = get_request_client();
+  let req = SuspiciousIpThrottlingUpdate::new(&client)
+    .enabled(true)
+    .pre_login(100, 864000)
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/attack-protection/suspicious-ip-throttling"
+  );
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "enabled": true,
+      "stage": { "pre-login": { "max_attempts": 100, "rate": 864000 } }
+    })
+  );
+}
+
+#[test]
+fn test_brute_force_protection_deserialize() {
+  let settings: BruteForceProtection = serde_json::from_value(serde_json::json!({
+    "enabled": true,
+    "shields": ["block", "user_notification"],
+    "allowlist": ["10.0.0.0/8"],
+    "mode": "count_per_identifier_and_ip",
+    "max_attempts": 10
+  }))
+  .unwrap();
+
+  assert_eq!(
+    settings.shields,
+    vec![BruteForceShield::Block, BruteForceShield::UserNotification]
+  );
+  assert_eq!(settings.mode, Some(BruteForceMode::CountPerIdentifierAndIp));
+  assert_eq!(settings.cooldown_duration, None);
+}
+
+#[test]
+fn test_suspicious_ip_throttling_deserialize_stage() {
+  let settings: SuspiciousIpThrottling = serde_json::from_value(serde_json::json!({
+    "enabled": true,
+    "shields": ["admin_notification"],
+    "stage": {
+      "pre-login": { "max_attempts": 100, "rate": 864000 },
+      "pre-user-registration": { "max_attempts": 50, "rate": 1200 }
+    }
+  }))
+  .unwrap();
+
+  assert_eq!(
+    settings.stage.pre_user_registration.unwrap().max_attempts,
+    50
+  );
+}
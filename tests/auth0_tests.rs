@@ -0,0 +1,157 @@
+use auth0_management::rate::RateLimit;
+use auth0_management::token::TokenManager;
+use auth0_management::{
+  Auth0, Auth0BuilderError, Auth0Client, Auth0RequestBuilder, JobGet,
+};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::{Client, Method};
+
+use crate::helpers::{get_mock_client, get_request_client, serve_requests};
+
+mod helpers;
+
+#[test]
+fn test_tenant_urls() {
+  let auth0 = Auth0::new(get_request_client());
+
+  assert_eq!(
+    auth0.authorize_url().unwrap().as_str(),
+    "https://example.auth0.com/authorize"
+  );
+  assert_eq!(
+    auth0.token_url().unwrap().as_str(),
+    "https://example.auth0.com/oauth/token"
+  );
+  assert_eq!(
+    auth0.jwks_url().unwrap().as_str(),
+    "https://example.auth0.com/.well-known/jwks.json"
+  );
+  assert_eq!(
+    auth0.logout_url().unwrap().as_str(),
+    "https://example.auth0.com/v2/logout"
+  );
+}
+
+#[test]
+fn test_tenant_urls_invalid_domain() {
+  let client = Auth0Client::new(
+    RateLimit::new(),
+    TokenManager::from_token(Client::new(), "TOKEN"),
+    Client::new(),
+    "not a domain",
+  );
+
+  assert!(Auth0::new(client).authorize_url().is_err());
+}
+
+#[test]
+fn test_builder_rejects_invalid_domain() {
+  let err = Auth0::builder()
+    .domain("not a domain")
+    .audience("AUDIENCE")
+    .client_id("CLIENT_ID")
+    .client_secret("CLIENT_SECRET")
+    .build()
+    .err();
+
+  assert_eq!(err, Some(Auth0BuilderError::InvalidDomain));
+}
+
+#[test]
+fn test_token_expired_before_first_fetch() {
+  let auth0 = Auth0::new(get_request_client());
+
+  assert!(auth0.is_token_expired());
+}
+
+#[test]
+fn test_tenant_name() {
+  let custom = |domain: &str| {
+    Auth0::builder()
+      .domain(domain)
+      .audience("AUDIENCE")
+      .client_id("CLIENT_ID")
+      .client_secret("CLIENT_SECRET")
+      .build()
+      .unwrap()
+  };
+
+  assert_eq!(Auth0::new(get_request_client()).tenant_name(), "example");
+  assert_eq!(custom("example.eu.auth0.com").tenant_name(), "example");
+  assert_eq!(
+    custom("login.example.com").tenant_name(),
+    "login.example.com"
+  );
+}
+
+#[test]
+fn test_with_client_credentials() {
+  let auth0 =
+    Auth0::with_client_credentials("example.auth0.com", "CLIENT_ID", "CLIENT_SECRET")
+      .unwrap();
+
+  assert_eq!(auth0.tenant_name(), "example");
+  assert!(auth0.is_token_expired());
+
+  let err =
+    Auth0::with_client_credentials("not a domain", "CLIENT_ID", "CLIENT_SECRET").err();
+  assert_eq!(err, Some(Auth0BuilderError::InvalidDomain));
+}
+
+#[test]
+fn test_builder_with_token_and_base_url() {
+  let auth0 = Auth0::builder()
+    .domain("example.auth0.com")
+    .base_url("http://127.0.0.1:8080/")
+    .token("TOKEN")
+    .build()
+    .unwrap();
+
+  assert!(!auth0.is_token_expired());
+
+  let err = Auth0::builder()
+    .domain("example.auth0.com")
+    .base_url("not a url")
+    .token("TOKEN")
+    .build()
+    .err();
+  assert_eq!(err, Some(Auth0BuilderError::InvalidBaseUrl));
+}
+
+#[test]
+fn test_client_base_url() {
+  let client = get_request_client().base_url("http://127.0.0.1:8080/");
+  let req = JobGet::new("job_1").build(&client).build().unwrap();
+
+  assert_eq!(
+    req.url().as_str(),
+    "http://127.0.0.1:8080/api/v2/jobs/job_1"
+  );
+}
+
+#[tokio::test]
+async fn test_client_default_headers() {
+  let (base_url, server) = serve_requests(vec![(200, "{}")]);
+  let mut headers = HeaderMap::new();
+  headers.insert("x-request-id", HeaderValue::from_static("req_1"));
+  headers.insert("x-tenant", HeaderValue::from_static("default"));
+  headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+
+  let client = get_mock_client(&base_url).default_headers(headers);
+  let _: serde_json::Value = client
+    .send(
+      client
+        .begin(Method::POST, "api/v2/rules")
+        .header("x-tenant", "override")
+        .json(&serde_json::json!({ "name": "rule" })),
+    )
+    .await
+    .unwrap();
+
+  let req = server.join().unwrap().remove(0).to_ascii_lowercase();
+  assert!(req.contains("\r\nx-request-id: req_1\r\n"));
+  assert!(req.contains("\r\nx-tenant: override\r\n"));
+  assert!(!req.contains("x-tenant: default"));
+  assert!(req.contains("\r\ncontent-type: application/json\r\n"));
+  assert!(!req.contains("text/plain"));
+}
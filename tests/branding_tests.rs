@@ -0,0 +1,22 @@
+use auth0_management::{Auth0RequestBuilder, UniversalLoginTemplateUpdate};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_universal_login_template_raw_body() {
+  let client = get_request_client();
+  let template = "<html>{%- auth0:head -%}{%- auth0:widget -%}</html>";
+  let req = UniversalLoginTemplateUpdate::new(template)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/branding/templates/universal-login"
+  );
+  assert_eq!(req.headers()["content-type"], "text/html");
+  assert_eq!(req.body().unwrap().as_bytes().unwrap(), template.as_bytes());
+}
@@ -0,0 +1,23 @@
+use auth0_management::{Auth0RequestBuilder, ClientGrantsList, Pageable};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_client_grants_list_filters() {
+  let client = get_request_client();
+  let req = ClientGrantsList::new(&client)
+    .client_id("client_1")
+    .audience("https://api.example.com/")
+    .per_page(10)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/client-grants");
+  assert_eq!(
+    req.url().query().unwrap(),
+    "client_id=client_1&audience=https%3A%2F%2Fapi.example.com%2F&per_page=10"
+  );
+}
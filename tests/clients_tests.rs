@@ -0,0 +1,75 @@
+use auth0_management::{Auth0RequestBuilder, ClientCreate, ClientUpdate, GrantType};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_client_update_urls() {
+  let client = get_request_client();
+  let req = ClientUpdate::new(&client, "client_1")
+    .callbacks(vec!["https://app.example.com/callback".to_owned()])
+    .allowed_logout_urls(vec!["https://app.example.com".to_owned()])
+    .web_origins(Vec::new())
+    .build(&client)
+    .build()
+    .unwrap();
+
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/clients/client_1");
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "callbacks": ["https://app.example.com/callback"],
+      "web_origins": [],
+      "allowed_logout_urls": ["https://app.example.com"]
+    })
+  );
+}
+
+#[test]
+fn test_client_create_grant_types() {
+  let client = get_request_client();
+  let req = ClientCreate::new(&client, "app")
+    .grant_types(vec![
+      GrantType::ClientCredentials,
+      GrantType::DeviceCode,
+      GrantType::Unknown("custom".to_owned()),
+    ])
+    .build(&client)
+    .build()
+    .unwrap();
+
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    body["grant_types"],
+    serde_json::json!([
+      "client_credentials",
+      "urn:ietf:params:oauth:grant-type:device_code",
+      "custom"
+    ])
+  );
+}
+
+#[test]
+fn test_grant_type_deserialize() {
+  let grant_types: Vec<GrantType> = serde_json::from_value(serde_json::json!([
+    "http://auth0.com/oauth/grant-type/mfa-otp",
+    "urn:openid:params:grant-type:ciba",
+    "custom"
+  ]))
+  .unwrap();
+
+  assert_eq!(
+    grant_types,
+    vec![
+      GrantType::MfaOtp,
+      GrantType::Ciba,
+      GrantType::Unknown("custom".to_owned())
+    ]
+  );
+}
@@ -0,0 +1,443 @@
+use std::collections::HashMap;
+
+use auth0_management::{
+  AdfsConnectionOptions, Auth0RequestBuilder, Connection, ConnectionCreate,
+  ConnectionOptions, ConnectionStrategy, ConnectionUpdate, ConnectionsList,
+  DatabaseConnectionOptions, GoogleOAuth2ConnectionOptions, OidcConnectionOptions,
+  OidcConnectionType, PasswordPolicy, Projectable, SamlDigestAlgorithm,
+  SamlSignatureAlgorithm, SamlpConnectionOptions, ScimConfiguration,
+  ScimConfigurationUpdate, ScimTokenCreate, ScimTokenDelete, WaadConnectionOptions,
+  WaadIdentityApi, WaadProtocol,
+};
+
+use crate::helpers::{get_mock_client, get_request_client, serve_json};
+
+mod helpers;
+
+#[test]
+fn test_oidc_connection_create_body() {
+  let client = get_request_client();
+  let options = OidcConnectionOptions {
+    client_id: "CLIENT_ID".to_owned(),
+    client_secret: Some("CLIENT_SECRET".to_owned()),
+    kind: OidcConnectionType::BackChannel,
+    discovery_url: Some(
+      "https://idp.example.com/.well-known/openid-configuration".to_owned(),
+    ),
+    scope: Some("openid profile email".to_owned()),
+    ..Default::default()
+  };
+  let create =
+    ConnectionCreate::oidc(&client, "example-oidc", options).display_name("Example");
+
+  assert_eq!(
+    serde_json::to_value(&create).unwrap(),
+    serde_json::json!({
+      "name": "example-oidc",
+      "strategy": "oidc",
+      "display_name": "Example",
+      "options": {
+        "client_id": "CLIENT_ID",
+        "client_secret": "CLIENT_SECRET",
+        "type": "back_channel",
+        "discovery_url": "https://idp.example.com/.well-known/openid-configuration",
+        "scope": "openid profile email"
+      }
+    })
+  );
+}
+
+#[test]
+fn test_waad_connection_create_body() {
+  let client = get_request_client();
+  let options = WaadConnectionOptions {
+    client_id: "CLIENT_ID".to_owned(),
+    tenant_domain: "example.onmicrosoft.com".to_owned(),
+    domain_aliases: vec!["example.com".to_owned()],
+    waad_protocol: Some(WaadProtocol::OpenidConnect),
+    identity_api: Some(WaadIdentityApi::MicrosoftIdentityPlatformV2),
+    ..Default::default()
+  };
+  let create = ConnectionCreate::waad(&client, "example-waad", options);
+
+  assert_eq!(
+    serde_json::to_value(&create).unwrap(),
+    serde_json::json!({
+      "name": "example-waad",
+      "strategy": "waad",
+      "options": {
+        "client_id": "CLIENT_ID",
+        "tenant_domain": "example.onmicrosoft.com",
+        "domain_aliases": ["example.com"],
+        "waad_protocol": "openid-connect",
+        "identity_api": "microsoft-identity-platform-v2.0"
+      }
+    })
+  );
+}
+
+#[test]
+fn test_adfs_connection_create_body() {
+  let client = get_request_client();
+  let options = AdfsConnectionOptions {
+    fed_metadata_xml: Some("<EntityDescriptor />".to_owned()),
+    thumbprints: vec!["ABCDEF".to_owned()],
+    ..Default::default()
+  };
+  let create = ConnectionCreate::adfs(&client, "example-adfs", options);
+
+  assert_eq!(
+    serde_json::to_value(&create).unwrap(),
+    serde_json::json!({
+      "name": "example-adfs",
+      "strategy": "adfs",
+      "options": {
+        "thumbprints": ["ABCDEF"],
+        "fedMetadataXml": "<EntityDescriptor />"
+      }
+    })
+  );
+}
+
+#[test]
+fn test_google_oauth2_connection_create_body() {
+  let client = get_request_client();
+  let options = GoogleOAuth2ConnectionOptions {
+    client_id: Some("CLIENT_ID".to_owned()),
+    client_secret: Some("CLIENT_SECRET".to_owned()),
+    allowed_audiences: vec!["NATIVE_CLIENT_ID".to_owned()],
+    ..Default::default()
+  };
+  let create = ConnectionCreate::google_oauth2(&client, "google-oauth2", options);
+
+  assert_eq!(
+    serde_json::to_value(&create).unwrap(),
+    serde_json::json!({
+      "name": "google-oauth2",
+      "strategy": "google-oauth2",
+      "options": {
+        "client_id": "CLIENT_ID",
+        "client_secret": "CLIENT_SECRET",
+        "allowed_audiences": ["NATIVE_CLIENT_ID"]
+      }
+    })
+  );
+}
+
+#[test]
+fn test_samlp_connection_create_body() {
+  let client = get_request_client();
+  let options = SamlpConnectionOptions {
+    sign_in_endpoint: Some("https://idp.example.com/saml".to_owned()),
+    signing_cert: Some("LS0tLS1CRUdJTg==".to_owned()),
+    sign_saml_request: Some(true),
+    digest_algorithm: Some(SamlDigestAlgorithm::Sha256),
+    signature_algorithm: Some(SamlSignatureAlgorithm::RsaSha256),
+    user_id_attribute: Some("uid".to_owned()),
+    ..Default::default()
+  };
+  let create = ConnectionCreate::samlp(&client, "example-saml", options);
+
+  assert_eq!(
+    serde_json::to_value(&create).unwrap(),
+    serde_json::json!({
+      "name": "example-saml",
+      "strategy": "samlp",
+      "options": {
+        "signInEndpoint": "https://idp.example.com/saml",
+        "signingCert": "LS0tLS1CRUdJTg==",
+        "signSAMLRequest": true,
+        "digestAlgorithm": "sha256",
+        "signatureAlgorithm": "rsa-sha256",
+        "user_id_attribute": "uid"
+      }
+    })
+  );
+}
+
+#[test]
+fn test_database_connection_create_body() {
+  let client = get_request_client();
+  let options = DatabaseConnectionOptions {
+    password_policy: Some(PasswordPolicy::Excellent),
+    brute_force_protection: Some(true),
+    requires_username: Some(false),
+    ..Default::default()
+  };
+  let create = ConnectionCreate::database(&client, "example-db", options);
+
+  assert_eq!(
+    serde_json::to_value(&create).unwrap(),
+    serde_json::json!({
+      "name": "example-db",
+      "strategy": "auth0",
+      "options": {
+        "passwordPolicy": "excellent",
+        "brute_force_protection": true,
+        "requires_username": false
+      }
+    })
+  );
+}
+
+#[test]
+fn test_connection_create_strategy() {
+  let client = get_request_client();
+  let create = ConnectionCreate::new(
+    &client,
+    "example",
+    ConnectionStrategy::Unknown("custom-idp".to_owned()),
+  );
+
+  assert_eq!(
+    serde_json::to_value(&create).unwrap(),
+    serde_json::json!({ "name": "example", "strategy": "custom-idp" })
+  );
+}
+
+#[test]
+fn test_connections_list_fields() {
+  let client = get_request_client();
+  let req = ConnectionsList::new(&client)
+    .strategy("auth0")
+    .fields(&["id", "name"])
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    req.url().query().unwrap(),
+    "strategy=auth0&fields=id%2Cname"
+  );
+}
+
+#[test]
+fn test_connection_update_metadata_removes_keys() {
+  let client = get_request_client();
+  let mut metadata = HashMap::new();
+  metadata.insert("team".to_owned(), Some("identity".to_owned()));
+  metadata.insert("stale".to_owned(), None);
+
+  let update = ConnectionUpdate::new(&client, "con_1").metadata(metadata);
+
+  assert_eq!(
+    serde_json::to_value(&update).unwrap(),
+    serde_json::json!({ "metadata": { "team": "identity", "stale": null } })
+  );
+}
+
+#[test]
+fn test_scim_configuration_update_body() {
+  let client = get_request_client();
+  let req = ScimConfigurationUpdate::new(&client, "con_1")
+    .user_id_attribute("externalId")
+    .mapping("email", "emails[primary eq true].value")
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(req.method(), "PATCH");
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/connections/con_1/scim-configuration"
+  );
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "user_id_attribute": "externalId",
+      "mapping": [{ "auth0": "email", "scim": "emails[primary eq true].value" }]
+    })
+  );
+}
+
+#[test]
+fn test_scim_configuration_deserialize() {
+  let config: ScimConfiguration = serde_json::from_value(serde_json::json!({
+    "connection_id": "con_1",
+    "connection_name": "okta",
+    "strategy": "okta",
+    "tenant_name": "example",
+    "user_id_attribute": "externalId",
+    "mapping": [{ "auth0": "email", "scim": "userName" }]
+  }))
+  .unwrap();
+
+  assert_eq!(config.strategy, ConnectionStrategy::Okta);
+  assert_eq!(config.mapping[0].scim, "userName");
+}
+
+#[test]
+fn test_scim_tokens() {
+  let client = get_request_client();
+  let req = ScimTokenCreate::new(&client, "con_1")
+    .scope("get:users")
+    .token_lifetime(3600)
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/connections/con_1/scim-configuration/tokens"
+  );
+  assert_eq!(
+    body,
+    serde_json::json!({ "scopes": ["get:users"], "token_lifetime": 3600 })
+  );
+
+  let req = ScimTokenDelete::new("con_1", String::from("tok/1"))
+    .build(&client)
+    .build()
+    .unwrap();
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/connections/con_1/scim-configuration/tokens/tok%2F1"
+  );
+}
+
+#[test]
+fn test_connection_options_by_strategy() {
+  let connection: Connection<ConnectionOptions> =
+    serde_json::from_value(serde_json::json!({
+      "id": "con_1",
+      "name": "Username-Password-Authentication",
+      "strategy": "auth0",
+      "options": { "passwordPolicy": "good", "disable_signup": true }
+    }))
+    .unwrap();
+
+  assert_eq!(connection.strategy, ConnectionStrategy::Auth0);
+  match connection.options {
+    Some(ConnectionOptions::Database(options)) => {
+      assert_eq!(options.password_policy, Some(PasswordPolicy::Good));
+      assert_eq!(options.disable_signup, Some(true));
+    }
+    options => panic!("unexpected options {:?}", options),
+  }
+
+  let connection: Connection<ConnectionOptions> =
+    serde_json::from_value(serde_json::json!({
+      "id": "con_2",
+      "name": "github",
+      "strategy": "github",
+      "options": { "scope": ["user"] }
+    }))
+    .unwrap();
+
+  assert_eq!(connection.strategy, ConnectionStrategy::Github);
+  assert!(matches!(
+    connection.options,
+    Some(ConnectionOptions::Other(_))
+  ));
+}
+
+#[test]
+fn test_connection_options_serialize_untagged() {
+  let options = ConnectionOptions::Samlp(SamlpConnectionOptions {
+    sign_in_endpoint: Some("https://idp.example.com/sso".to_owned()),
+    ..Default::default()
+  });
+
+  assert_eq!(
+    serde_json::to_value(&options).unwrap(),
+    serde_json::json!({ "signInEndpoint": "https://idp.example.com/sso" })
+  );
+}
+
+#[test]
+fn test_connection_options_keep_unmodeled_keys() {
+  let options = serde_json::json!({
+    "passwordPolicy": "custom",
+    "passwordHistory": { "enable": true, "size": 5 },
+    "mfa": { "active": true }
+  });
+  let connection: Connection<ConnectionOptions> =
+    serde_json::from_value(serde_json::json!({
+      "id": "con_1",
+      "name": "db",
+      "strategy": "auth0",
+      "options": options
+    }))
+    .unwrap();
+
+  match &connection.options {
+    Some(ConnectionOptions::Database(database)) => {
+      assert_eq!(database.password_policy, Some(PasswordPolicy::Custom));
+    }
+    options => panic!("unexpected options {:?}", options),
+  }
+
+  let client = get_request_client();
+  let update =
+    ConnectionUpdate::new(&client, "con_1").options(connection.options.unwrap());
+
+  assert_eq!(
+    serde_json::to_value(&update).unwrap(),
+    serde_json::json!({ "options": options })
+  );
+}
+
+#[test]
+fn test_password_policy_unknown() {
+  let policy: PasswordPolicy =
+    serde_json::from_value(serde_json::json!("strict")).unwrap();
+
+  assert_eq!(policy, PasswordPolicy::Unknown("strict".to_owned()));
+  assert_eq!(serde_json::to_value(&policy).unwrap(), "strict");
+}
+
+#[tokio::test]
+async fn test_connections_list_ids() {
+  let (base_url, server) = serve_json(vec![
+    r#"{"id":"con_x","name":"a","strategy":"auth0"}"#,
+    r#"{"id":"con_x","name":"b","strategy":"auth0"}"#,
+    r#"{"id":"con_x","name":"c","strategy":"auth0"}"#,
+  ]);
+  let client = get_mock_client(&base_url);
+
+  let connections: Vec<Connection<serde_json::Value>> = ConnectionsList::new(&client)
+    .ids(&["con_1", "con_2", "con_3"])
+    .await
+    .unwrap();
+
+  assert_eq!(connections.len(), 3);
+  let mut paths = server.join().unwrap();
+  paths.sort();
+  assert_eq!(
+    paths,
+    vec![
+      "/api/v2/connections/con_1",
+      "/api/v2/connections/con_2",
+      "/api/v2/connections/con_3"
+    ]
+  );
+}
+
+#[tokio::test]
+async fn test_connections_list_ids_fields() {
+  let (base_url, server) = serve_json(vec![
+    r#"{"id":"con_x","name":"a","strategy":"auth0"}"#,
+    r#"{"id":"con_x","name":"b","strategy":"auth0"}"#,
+  ]);
+  let client = get_mock_client(&base_url);
+
+  let _: Vec<Connection<serde_json::Value>> = ConnectionsList::new(&client)
+    .fields(&["id", "name"])
+    .ids(&["con_1", "con_2"])
+    .await
+    .unwrap();
+
+  let mut paths = server.join().unwrap();
+  paths.sort();
+  assert_eq!(
+    paths,
+    vec![
+      "/api/v2/connections/con_1?fields=id%2Cname",
+      "/api/v2/connections/con_2?fields=id%2Cname"
+    ]
+  );
+}
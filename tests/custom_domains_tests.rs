@@ -0,0 +1,91 @@
+use auth0_management::{
+  Auth0RequestBuilder, CustomDomain, CustomDomainCreate, CustomDomainStatus,
+  CustomDomainType, CustomDomainUpdate, TlsPolicy,
+};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_custom_domain_create_body() {
+  let client = get_request_client();
+  let req = CustomDomainCreate::new(
+    &client,
+    "login.example.com",
+    CustomDomainType::Auth0ManagedCerts,
+  )
+  .tls_policy(TlsPolicy::Recommended)
+  .build(&client)
+  .build()
+  .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "domain": "login.example.com",
+      "type": "auth0_managed_certs",
+      "tls_policy": "recommended"
+    })
+  );
+}
+
+#[test]
+fn test_custom_domain_pending_verification() {
+  let domain: CustomDomain = serde_json::from_value(serde_json::json!({
+    "custom_domain_id": "cd_1",
+    "domain": "login.example.com",
+    "primary": true,
+    "status": "pending_verification",
+    "type": "self_managed_certs",
+    "cname_api_key": "KEY",
+    "verification": {
+      "methods": [{ "name": "cname", "record": "example-cd-1.edge.tenants.auth0.com" }]
+    }
+  }))
+  .unwrap();
+
+  assert_eq!(domain.status, CustomDomainStatus::PendingVerification);
+  assert_eq!(domain.kind, CustomDomainType::SelfManagedCerts);
+  assert_eq!(domain.verification.unwrap().methods[0].name, "cname");
+}
+
+#[test]
+fn test_custom_domain_update_body() {
+  let client = get_request_client();
+  let req = CustomDomainUpdate::new(&client, "cd_1")
+    .tls_policy(TlsPolicy::Recommended)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(req.method(), "PATCH");
+  assert_eq!(req.url().path(), "/api/v2/custom-domains/cd_1");
+  assert_eq!(body, serde_json::json!({ "tls_policy": "recommended" }));
+}
+
+#[test]
+fn test_custom_domain_unknown_status_and_type() {
+  let domain: CustomDomain = serde_json::from_value(serde_json::json!({
+    "custom_domain_id": "cd_1",
+    "domain": "login.example.com",
+    "primary": false,
+    "status": "pending",
+    "type": "partner_managed_certs"
+  }))
+  .unwrap();
+
+  assert_eq!(
+    domain.status,
+    CustomDomainStatus::Unknown("pending".to_owned())
+  );
+  assert_eq!(
+    domain.kind,
+    CustomDomainType::Unknown("partner_managed_certs".to_owned())
+  );
+}
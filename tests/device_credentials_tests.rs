@@ -0,0 +1,44 @@
+use auth0_management::{
+  Auth0RequestBuilder, DeviceCredentialType, DeviceCredentialsGet, Projectable,
+};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_device_credentials_get_query() {
+  let client = get_request_client();
+  let req = DeviceCredentialsGet::new(&client, "auth0|123")
+    .kind(DeviceCredentialType::RefreshToken)
+    .fields(&["id", "device_name"])
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/device-credentials");
+  assert_eq!(
+    req.url().query().unwrap(),
+    "user_id=auth0%7C123&type=refresh_token&fields=id%2Cdevice_name"
+  );
+}
+
+#[test]
+fn test_device_credential_type_fallback() {
+  let kind = |kind: &str| -> DeviceCredentialType {
+    serde_json::from_value(serde_json::json!(kind)).unwrap()
+  };
+
+  assert_eq!(
+    kind("rotating_refresh_token"),
+    DeviceCredentialType::RotatingRefreshToken
+  );
+  assert_eq!(
+    kind("passkey"),
+    DeviceCredentialType::Unknown("passkey".to_owned())
+  );
+  assert_eq!(
+    serde_json::to_value(DeviceCredentialType::Unknown("passkey".to_owned())).unwrap(),
+    "passkey"
+  );
+}
@@ -0,0 +1,21 @@
+use auth0_management::EmailTemplateName;
+
+#[test]
+fn test_email_template_name_fallback() {
+  let name = |name: &str| -> EmailTemplateName {
+    serde_json::from_value(serde_json::json!(name)).unwrap()
+  };
+
+  assert_eq!(
+    name("verify_email_by_code"),
+    EmailTemplateName::VerifyEmailByCode
+  );
+  assert_eq!(
+    name("async_approval"),
+    EmailTemplateName::Unknown("async_approval".to_owned())
+  );
+  assert_eq!(
+    EmailTemplateName::Unknown("async_approval".to_owned()).to_string(),
+    "async_approval"
+  );
+}
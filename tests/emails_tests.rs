@@ -0,0 +1,20 @@
+use auth0_management::EmailProviderName;
+
+#[test]
+fn test_email_provider_name_fallback() {
+  let name = |name: &str| -> EmailProviderName {
+    serde_json::from_value(serde_json::json!(name)).unwrap()
+  };
+
+  assert_eq!(name("sendgrid"), EmailProviderName::Sendgrid);
+  assert_eq!(name("azure_cs"), EmailProviderName::AzureCs);
+  assert_eq!(name("ms365"), EmailProviderName::Ms365);
+  assert_eq!(
+    name("postmark"),
+    EmailProviderName::Unknown("postmark".to_owned())
+  );
+  assert_eq!(
+    serde_json::to_value(EmailProviderName::Custom).unwrap(),
+    "custom"
+  );
+}
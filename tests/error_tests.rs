@@ -0,0 +1,104 @@
+use auth0_management::{
+  Auth0Error, Auth0ErrorResponse, Auth0RequestSimple, Auth0Result, ErrorCode, UserGet,
+};
+use serde_json::Value;
+
+use crate::helpers::{get_mock_client, serve_requests};
+
+mod helpers;
+
+#[test]
+fn test_api_error_response() {
+  let res: Auth0ErrorResponse = serde_json::from_value(serde_json::json!({
+    "statusCode": 400,
+    "error": "Bad Request",
+    "message": "The connection strategy is invalid",
+    "errorCode": "invalid_body"
+  }))
+  .unwrap();
+
+  match Auth0Error::from(res) {
+    Auth0Error::Api {
+      status,
+      error,
+      message,
+      code,
+    } => {
+      assert_eq!(status, 400);
+      assert_eq!(error, "Bad Request");
+      assert_eq!(message, "The connection strategy is invalid");
+      assert_eq!(code, Some(ErrorCode::InvalidBody));
+    }
+    err => panic!("Unexpected error {:?}", err),
+  }
+}
+
+#[test]
+fn test_api_error_response_without_status() {
+  let res: Auth0ErrorResponse = serde_json::from_value(serde_json::json!({
+    "message": "Missing status code"
+  }))
+  .unwrap();
+
+  match Auth0Error::from(res) {
+    Auth0Error::Api { message, .. } => assert_eq!(message, "Missing status code"),
+    err => panic!("Unexpected error {:?}", err),
+  }
+
+  let res: Auth0ErrorResponse = serde_json::from_value(serde_json::json!({
+    "error": "access_denied",
+    "error_description": "Unauthorized"
+  }))
+  .unwrap();
+
+  match Auth0Error::from(res) {
+    Auth0Error::Api { error, message, .. } => {
+      assert_eq!(error, "access_denied");
+      assert_eq!(message, "Unauthorized");
+    }
+    err => panic!("Unexpected error {:?}", err),
+  }
+}
+
+#[tokio::test]
+async fn test_api_error_status_from_response() {
+  let (base_url, server) =
+    serve_requests(vec![(403, r#"{"message":"Insufficient scope"}"#)]);
+  let client = get_mock_client(&base_url);
+
+  let res: Auth0Result<Value> = UserGet::new("auth0|1").send_to(&client).await;
+  server.join().unwrap();
+
+  match res {
+    Err(Auth0Error::Api {
+      status, message, ..
+    }) => {
+      assert_eq!(status, 403);
+      assert_eq!(message, "Insufficient scope");
+    }
+    res => panic!("Unexpected result {:?}", res),
+  }
+}
+
+#[test]
+fn test_api_error_code() {
+  let err = |code: &str| {
+    let res: Auth0ErrorResponse = serde_json::from_value(serde_json::json!({
+      "statusCode": 404,
+      "error": "Not Found",
+      "message": "The user does not exist.",
+      "errorCode": code
+    }))
+    .unwrap();
+
+    Auth0Error::from(res)
+  };
+
+  assert!(err("inexistent_user").is_user_not_found());
+  assert!(!err("insufficient_scope").is_user_not_found());
+  assert!(err("insufficient_scope").is_insufficient_scope());
+  assert_eq!(
+    err("custom_error").error_code(),
+    Some(&ErrorCode::Unknown("custom_error".to_owned()))
+  );
+}
@@ -0,0 +1,37 @@
+use auth0_management::{Auth0RequestBuilder, Grant, GrantsGet, Pageable};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_grants_get_query() {
+  let client = get_request_client();
+  let req = GrantsGet::new(&client, "auth0|123")
+    .audience("https://api.example.com")
+    .per_page(10)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/grants");
+  assert_eq!(
+    req.url().query().unwrap(),
+    "user_id=auth0%7C123&audience=https%3A%2F%2Fapi.example.com&per_page=10"
+  );
+}
+
+#[test]
+fn test_grant_deserialize() {
+  let grant: Grant = serde_json::from_value(serde_json::json!({
+    "id": "grt_1",
+    "clientID": "CLIENT_ID",
+    "user_id": "auth0|123",
+    "audience": "https://api.example.com",
+    "scope": ["openid", "read:messages"]
+  }))
+  .unwrap();
+
+  assert_eq!(grant.client_id, "CLIENT_ID");
+  assert_eq!(grant.scope, vec!["openid", "read:messages"]);
+}
@@ -0,0 +1,23 @@
+use auth0_management::{Auth0RequestBuilder, FactorName, GuardianFactorUpdate};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_factor_update_uses_factor_name_in_path() {
+  let client = get_request_client();
+  let req = GuardianFactorUpdate::new(FactorName::WebauthnRoaming, true)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/guardian/factors/webauthn-roaming"
+  );
+  assert_eq!(
+    req.body().unwrap().as_bytes().unwrap(),
+    br#"{"enabled":true}"#
+  );
+}
@@ -1,11 +1,142 @@
-use auth0_management::Auth0;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
 
+use auth0_management::rate::RateLimit;
+use auth0_management::token::TokenManager;
+use auth0_management::{Auth0, Auth0Client};
+use reqwest::Client;
+
+#[allow(dead_code)]
 pub fn get_client() -> Auth0 {
   Auth0::builder()
-    .domain(&env!("AUTH0_DOMAIN"))
-    .audience(&env!("AUTH0_AUDIENCE"))
-    .client_id(&env!("AUTH0_CLIENT_ID"))
+    .domain(env!("AUTH0_DOMAIN"))
+    .audience(env!("AUTH0_AUDIENCE"))
+    .client_id(env!("AUTH0_CLIENT_ID"))
     .client_secret(env!("AUTH0_CLIENT_SECRET"))
     .build()
     .unwrap()
 }
+
+/// Client used to inspect built requests without sending them.
+#[allow(dead_code)]
+pub fn get_request_client() -> Auth0Client {
+  let client = Client::new();
+
+  Auth0Client::new(
+    RateLimit::new(),
+    TokenManager::new(
+      client.clone(),
+      "example.auth0.com",
+      "https://example.auth0.com/api/v2/",
+      "CLIENT_ID",
+      "CLIENT_SECRET",
+    ),
+    client,
+    "example.auth0.com",
+  )
+}
+
+/// Serve each of `bodies` as a JSON response to one request on a local port.  Returns
+/// the base URL of the server and a handle resolving to the requested paths.
+#[allow(dead_code)]
+pub fn serve_json(bodies: Vec<&'static str>) -> (String, JoinHandle<Vec<String>>) {
+  let (base_url, server) =
+    serve_requests(bodies.into_iter().map(|body| (200, body)).collect());
+  let paths = thread::spawn(move || {
+    server
+      .join()
+      .unwrap()
+      .into_iter()
+      .map(|req| req.split(' ').nth(1).unwrap().to_owned())
+      .collect()
+  });
+
+  (base_url, paths)
+}
+
+/// Serve each of `responses`, a status code and a JSON body, to one request on a local
+/// port.  Returns the base URL of the server and a handle resolving to the raw requests,
+/// including their headers and body.
+#[allow(dead_code)]
+pub fn serve_requests(
+  responses: Vec<(u16, &'static str)>,
+) -> (String, JoinHandle<Vec<String>>) {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let addr = listener.local_addr().unwrap();
+  let server = thread::spawn(move || {
+    let mut requests = Vec::new();
+
+    for (status, body) in responses {
+      let (mut stream, _) = listener.accept().unwrap();
+      let mut reader = BufReader::new(stream.try_clone().unwrap());
+      let mut req = String::new();
+      let mut length = 0;
+      let mut chunked = false;
+
+      loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let header = line.to_ascii_lowercase();
+        if let Some(value) = header.strip_prefix("content-length:") {
+          length = value.trim().parse().unwrap();
+        }
+        if header.starts_with("transfer-encoding:") && header.contains("chunked") {
+          chunked = true;
+        }
+        req.push_str(&line);
+        if line == "\r\n" {
+          break;
+        }
+      }
+
+      let mut content = Vec::new();
+      if chunked {
+        loop {
+          let mut size = String::new();
+          reader.read_line(&mut size).unwrap();
+          let size = usize::from_str_radix(size.trim(), 16).unwrap();
+          let mut chunk = vec![0; size + 2];
+          reader.read_exact(&mut chunk).unwrap();
+          if size == 0 {
+            break;
+          }
+          content.extend_from_slice(&chunk[..size]);
+        }
+      } else {
+        content.resize(length, 0);
+        reader.read_exact(&mut content).unwrap();
+      }
+      req.push_str(&String::from_utf8_lossy(&content));
+      requests.push(req);
+
+      write!(
+        stream,
+        "HTTP/1.1 {} Status\r\ncontent-type: application/json\r\nretry-after: 0\r\n\
+         connection: close\r\ncontent-length: {}\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+      )
+      .unwrap();
+    }
+
+    requests
+  });
+
+  (format!("http://{}", addr), server)
+}
+
+/// Client authenticated with a static token that sends requests to `base_url`.
+#[allow(dead_code)]
+pub fn get_mock_client(base_url: &str) -> Auth0Client {
+  let client = Client::new();
+
+  Auth0Client::new(
+    RateLimit::new(),
+    TokenManager::from_token(client.clone(), "TOKEN"),
+    client,
+    "example.auth0.com",
+  )
+  .base_url(base_url)
+}
@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use auth0_management::jobs::helpers::export_users;
+use auth0_management::{
+  Auth0, ExportField, ImportUser, Job, JobGet, JobStatus, JobUsersImport,
+};
+
+use crate::helpers::{get_mock_client, serve_json, serve_requests};
+
+mod helpers;
+
+fn job(status: &str) -> Job {
+  serde_json::from_value(serde_json::json!({
+    "id": "job_1",
+    "type": "users_export",
+    "status": status,
+    "created_at": "2024-01-01T00:00:00.000Z"
+  }))
+  .unwrap()
+}
+
+#[test]
+fn test_job_status() {
+  assert!(job("completed").is_successful());
+  assert!(job("failed").is_terminal());
+  assert!(!job("failed").is_successful());
+  assert!(!job("processing").is_terminal());
+  assert_eq!(
+    job("cancelled").status,
+    JobStatus::Unknown("cancelled".to_owned())
+  );
+}
+
+const PENDING: &str = r#"{"id":"job_1","type":"users_export","status":"pending","created_at":"2024-01-01T00:00:00Z"}"#;
+const COMPLETED: &str = r#"{"id":"job_1","type":"users_export","status":"completed","created_at":"2024-01-01T00:00:00Z","location":"https://example.test/export.csv.gz"}"#;
+
+#[tokio::test(start_paused = true)]
+async fn test_wait_until_done() {
+  let (base_url, server) = serve_json(vec![PENDING, COMPLETED]);
+  let client = get_mock_client(&base_url);
+
+  let job = JobGet::new("job_1")
+    .wait_until_done(&client, Duration::from_secs(1), Duration::from_secs(60))
+    .await
+    .unwrap();
+
+  assert!(job.is_successful());
+  assert_eq!(
+    server.join().unwrap(),
+    vec!["/api/v2/jobs/job_1", "/api/v2/jobs/job_1"]
+  );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_wait_until_done_timeout() {
+  let (base_url, server) = serve_json(vec![PENDING, PENDING]);
+  let client = get_mock_client(&base_url);
+
+  let res = JobGet::new("job_1")
+    .wait_until_done(&client, Duration::from_secs(10), Duration::from_secs(15))
+    .await;
+
+  assert!(res.is_err());
+  assert_eq!(server.join().unwrap().len(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_export_users() {
+  let (base_url, server) = serve_json(vec![PENDING, PENDING, COMPLETED]);
+  let auth0 = Auth0::new(get_mock_client(&base_url));
+
+  let job = export_users(
+    &auth0,
+    "con_1",
+    &[ExportField {
+      name: "email".to_owned(),
+      export_as: None,
+    }],
+  )
+  .await
+  .unwrap();
+
+  assert_eq!(
+    job.location.as_deref(),
+    Some("https://example.test/export.csv.gz")
+  );
+  assert_eq!(
+    server.join().unwrap(),
+    vec![
+      "/api/v2/jobs/users-exports",
+      "/api/v2/jobs/job_1",
+      "/api/v2/jobs/job_1"
+    ]
+  );
+}
+
+#[tokio::test]
+async fn test_users_import_rebuilds_form_on_retry() {
+  let (base_url, server) = serve_requests(vec![
+    (
+      429,
+      r#"{"statusCode":429,"error":"Too Many Requests","message":"Slow down"}"#,
+    ),
+    (202, PENDING),
+  ]);
+  let client = get_mock_client(&base_url).auto_retry_on_rate_limit(1);
+  let users = vec![ImportUser {
+    email: "user@example.test".to_owned(),
+    ..Default::default()
+  }];
+
+  let job = JobUsersImport::new(&client, "con_1", users)
+    .upsert(true)
+    .send()
+    .await
+    .unwrap();
+
+  assert_eq!(job.id, "job_1");
+  let requests = server.join().unwrap();
+  assert_eq!(requests.len(), 2);
+  for req in requests {
+    assert!(req.starts_with("POST /api/v2/jobs/users-imports "));
+    assert!(req.contains(
+      "Content-Disposition: form-data; name=\"users\"; filename=\"users.json\"\r\n\
+       Content-Type: application/json\r\n\r\n[{\"email\":\"user@example.test\"}]\r\n"
+    ));
+    assert!(req.contains(
+      "Content-Disposition: form-data; name=\"connection_id\"\r\n\r\ncon_1\r\n"
+    ));
+    assert!(
+      req.contains("Content-Disposition: form-data; name=\"upsert\"\r\n\r\ntrue\r\n")
+    );
+  }
+}
@@ -0,0 +1,20 @@
+use auth0_management::SigningKey;
+
+#[test]
+fn test_signing_key_deserialize() {
+  let key: SigningKey = serde_json::from_value(serde_json::json!({
+    "kid": "KID",
+    "cert": "-----BEGIN CERTIFICATE-----",
+    "pkcs7": "-----BEGIN PKCS7-----",
+    "current": true,
+    "current_since": "2024-01-01T00:00:00.000Z",
+    "fingerprint": "AA:BB",
+    "thumbprint": "AABB"
+  }))
+  .unwrap();
+
+  assert!(key.current);
+  assert!(!key.next && !key.previous && !key.revoked);
+  assert!(key.current_since.is_some());
+  assert!(key.revoked_at.is_none());
+}
@@ -0,0 +1,106 @@
+use auth0_management::{
+  Auth0RequestBuilder, HttpContentFormat, LogStream, LogStreamCreate, LogStreamPause,
+  LogStreamResume, LogStreamSink, LogStreamStatus, LogStreamType,
+};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_log_stream_create_http_body() {
+  let client = get_request_client();
+  let sink = LogStreamSink::Http {
+    endpoint: "https://logs.example.com".to_owned(),
+    authorization: Some("Bearer TOKEN".to_owned()),
+    content_format: Some(HttpContentFormat::JsonLines),
+    content_type: None,
+  };
+  let req = LogStreamCreate::new(&client, LogStreamType::Http, sink)
+    .name("Example")
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "name": "Example",
+      "type": "http",
+      "sink": {
+        "httpEndpoint": "https://logs.example.com",
+        "httpAuthorization": "Bearer TOKEN",
+        "httpContentFormat": "JSONLINES"
+      }
+    })
+  );
+}
+
+#[test]
+fn test_log_stream_deserialize_sinks() {
+  let streams: Vec<LogStream> = serde_json::from_value(serde_json::json!([
+    {
+      "id": "lst_1",
+      "name": "Datadog",
+      "type": "datadog",
+      "status": "active",
+      "sink": { "datadogRegion": "eu", "datadogApiKey": "KEY" }
+    },
+    {
+      "id": "lst_2",
+      "name": "EventBridge",
+      "type": "eventbridge",
+      "status": "suspended",
+      "sink": {
+        "awsAccountId": "123456789012",
+        "awsRegion": "us-east-1",
+        "awsPartnerEventSource": "aws.partner/auth0.com/example"
+      }
+    },
+    {
+      "id": "lst_3",
+      "name": "Future",
+      "type": "future",
+      "status": "paused",
+      "sink": { "futureKey": "KEY" }
+    }
+  ]))
+  .unwrap();
+
+  assert!(matches!(
+    &streams[0].sink,
+    LogStreamSink::Datadog { region, .. } if region == "eu"
+  ));
+  assert_eq!(streams[1].kind, LogStreamType::EventBridge);
+  assert_eq!(streams[1].status, LogStreamStatus::Suspended);
+  assert!(matches!(
+    &streams[1].sink,
+    LogStreamSink::EventBridge {
+      partner_event_source: Some(_),
+      ..
+    }
+  ));
+  assert_eq!(streams[2].kind, LogStreamType::Unknown);
+  assert!(matches!(streams[2].sink, LogStreamSink::Unknown(_)));
+}
+
+#[test]
+fn test_log_stream_pause_resume_body() {
+  let client = get_request_client();
+  let body = |req: reqwest::Request| -> serde_json::Value {
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap()
+  };
+
+  let req = LogStreamPause::new("lst_1").build(&client).build().unwrap();
+  assert_eq!(req.method(), "PATCH");
+  assert_eq!(req.url().path(), "/api/v2/log-streams/lst_1");
+  assert_eq!(body(req), serde_json::json!({ "status": "paused" }));
+
+  let req = LogStreamResume::new("lst_1")
+    .build(&client)
+    .build()
+    .unwrap();
+  assert_eq!(body(req), serde_json::json!({ "status": "active" }));
+}
@@ -0,0 +1,222 @@
+use auth0_management::{
+  Auth0RequestBuilder, ConnectionStrategy, LogEventType, LogQuery, LogsGet, Pageable,
+  UserLog, UserLogsGet,
+};
+use chrono::{TimeZone, Utc};
+use futures::TryStreamExt;
+
+use crate::helpers::{get_mock_client, get_request_client, serve_json};
+
+mod helpers;
+
+#[test]
+fn test_user_log_without_optional_fields() {
+  let log: UserLog = serde_json::from_value(serde_json::json!({
+    "date": "2024-01-01T00:00:00.000Z",
+    "type": "sapi",
+    "description": "Update a user",
+    "ip": "127.0.0.1",
+    "log_id": "9001",
+    "details": {}
+  }))
+  .unwrap();
+
+  assert_eq!(log.kind, LogEventType::SuccessApiOperation);
+  assert!(log.user_id.is_none());
+  assert!(log.user_name.is_none());
+  assert!(!log.is_mobile);
+  assert!(log.connection.is_none());
+  assert!(log.client_id.is_none());
+  assert!(log.location_info.is_none());
+}
+
+#[test]
+fn test_user_log_strategy() {
+  let log = |strategy: &str| -> UserLog {
+    serde_json::from_value(serde_json::json!({
+      "date": "2024-01-01T00:00:00.000Z",
+      "type": "s",
+      "description": "Successful login",
+      "ip": "127.0.0.1",
+      "user_id": "auth0|1",
+      "user_name": "user@example.com",
+      "log_id": "9001",
+      "isMobile": false,
+      "details": {},
+      "strategy": strategy
+    }))
+    .unwrap()
+  };
+
+  assert_eq!(
+    log("google-oauth2").strategy,
+    Some(ConnectionStrategy::GoogleOAuth2)
+  );
+  assert_eq!(
+    log("custom-idp").strategy,
+    Some(ConnectionStrategy::Unknown("custom-idp".to_owned()))
+  );
+}
+
+#[test]
+fn test_log_event_type_unknown_code() {
+  let kind: LogEventType = serde_json::from_str("\"not_a_real_code\"").unwrap();
+
+  assert_eq!(kind, LogEventType::Unknown("not_a_real_code".to_owned()));
+  assert_eq!(kind.code(), "not_a_real_code");
+  assert_eq!(LogEventType::FailedLogin.description(), "Failed Login");
+}
+
+#[test]
+fn test_logs_get_from_replaces_offset_pagination() {
+  let client = get_request_client();
+  let logs = LogsGet::new(&client)
+    .page(2)
+    .per_page(10)
+    .from("9001")
+    .take(20);
+  let req = logs.build(&client).build().unwrap();
+
+  assert_eq!(req.url().query(), Some("from=9001&take=20"));
+}
+
+#[test]
+fn test_user_logs_get_page_replaces_checkpoint() {
+  let client = get_request_client();
+  let logs = UserLogsGet::new(&client, "auth0|1").from("9001").page(1);
+  let req = logs.build(&client).build().unwrap();
+
+  assert_eq!(req.url().query(), Some("page=1"));
+}
+
+#[test]
+fn test_user_logs_for_connection() {
+  let client = get_request_client();
+  let logs = UserLogsGet::new(&client, "auth0|1")
+    .for_connection("con_1")
+    .page(2);
+  let req = logs.build(&client).build().unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/logs");
+  assert_eq!(
+    req.url().query(),
+    Some("q=user_id%3A%22auth0%7C1%22+AND+connection_id%3Acon_1&page=2")
+  );
+}
+
+#[test]
+fn test_user_logs_event_type_and_q() {
+  let client = get_request_client();
+  let logs = UserLogsGet::new(&client, "auth0|1")
+    .event_type(LogEventType::FailedLogin)
+    .event_type(LogEventType::FailedSilentAuth)
+    .q("ip:10.0.0.1");
+  let req = logs.build(&client).build().unwrap();
+
+  let q = req
+    .url()
+    .query_pairs()
+    .find(|(key, _)| key == "q")
+    .unwrap()
+    .1
+    .into_owned();
+
+  assert_eq!(req.url().path(), "/api/v2/logs");
+  assert_eq!(
+    q,
+    r#"user_id:"auth0|1" AND (type:(f fsa)) AND (ip:10.0.0.1)"#
+  );
+}
+
+#[test]
+fn test_user_logs_without_filters() {
+  let client = get_request_client();
+  let req = UserLogsGet::new(&client, "auth0|1")
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/users/auth0%7C1/logs");
+}
+
+#[test]
+fn test_user_logs_date_range() {
+  let client = get_request_client();
+  let logs = UserLogsGet::new(&client, "auth0|1")
+    .since(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+    .event_type(LogEventType::SuccessLogin);
+  let req = logs.build(&client).build().unwrap();
+
+  let q = req
+    .url()
+    .query_pairs()
+    .find(|(key, _)| key == "q")
+    .unwrap()
+    .1
+    .into_owned();
+
+  assert_eq!(
+    q,
+    r#"user_id:"auth0|1" AND date:[2024-01-01T00:00:00.000Z TO *] AND (type:s)"#
+  );
+}
+
+#[test]
+fn test_log_query() {
+  let q = LogQuery::new()
+    .user_id("auth0|1")
+    .client_id("abc123")
+    .ip("1.2.3.4")
+    .date_range(
+      Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+      Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap(),
+    )
+    .build();
+
+  assert_eq!(
+    q,
+    r#"user_id:"auth0|1" AND client_id:abc123 AND ip:1.2.3.4 AND date:[2024-01-01T00:00:00.000Z TO 2024-12-31T00:00:00.000Z]"#
+  );
+  assert_eq!(LogQuery::new().build(), "");
+}
+
+#[test]
+fn test_log_query_event_types() {
+  let q = LogQuery::new()
+    .event_type(LogEventType::FailedLogin)
+    .event_type(LogEventType::FailedSilentAuth)
+    .since(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+    .build();
+
+  assert_eq!(q, "date:[2024-01-01T00:00:00.000Z TO *] AND (type:(f fsa))");
+}
+
+#[tokio::test]
+async fn test_logs_csv_stream() {
+  let (base_url, server) = serve_json(vec![
+    r#"[
+      {"date":"2024-01-01T00:00:00Z","type":"f","description":"Wrong email or password.","ip":"127.0.0.1","user_id":"auth0|1","user_name":"a@example.test","log_id":"1","isMobile":false,"details":{}},
+      {"date":"2024-01-02T00:00:00Z","type":"new_code","description":"Said \"hi\", then\nleft","client_name":"App, Inc.","ip":"127.0.0.1","user_id":"auth0|2","user_name":"b@example.test","log_id":"2","isMobile":false,"details":{}}
+    ]"#,
+  ]);
+  let client = get_mock_client(&base_url);
+
+  let rows: Vec<String> = LogsGet::new(&client)
+    .to_csv_stream()
+    .try_collect()
+    .await
+    .unwrap();
+
+  assert_eq!(
+    rows,
+    vec![
+      "date,type,description,connection,client_id,client_name,ip,hostname,user_id,user_name,user_agent,log_id\n",
+      "2024-01-01T00:00:00+00:00,f,Wrong email or password.,,,,127.0.0.1,,auth0|1,a@example.test,,1\n",
+      "2024-01-02T00:00:00+00:00,new_code,\"Said \"\"hi\"\", then\nleft\",,,\"App, Inc.\",127.0.0.1,,auth0|2,b@example.test,,2\n",
+    ]
+  );
+  assert_eq!(
+    server.join().unwrap(),
+    vec!["/api/v2/logs?page=0&per_page=50"]
+  );
+}
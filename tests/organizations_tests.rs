@@ -0,0 +1,122 @@
+use auth0_management::{
+  Auth0RequestBuilder, CursorPageable, OrganizationGetByName,
+  OrganizationInvitationCreate, OrganizationInvitationsGet,
+  OrganizationMemberRolesAssign, OrganizationMembersGet, OrganizationsGet, Projectable,
+};
+
+use crate::helpers::{get_mock_client, get_request_client, serve_json};
+
+mod helpers;
+
+#[test]
+fn test_get_by_name_encodes_name() {
+  let client = get_request_client();
+  let req = OrganizationGetByName::new("acme / co?#1")
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/organizations/name/acme%20%2F%20co%3F%231"
+  );
+}
+
+#[test]
+fn test_members_get_checkpoint_query() {
+  let client = get_request_client();
+  let first = OrganizationMembersGet::new(&client, "org_1")
+    .build(&client)
+    .build()
+    .unwrap();
+  let next = OrganizationMembersGet::new(&client, "org_1")
+    .from("CHECKPOINT")
+    .take(10)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(first.url().query(), Some("take=50"));
+  assert_eq!(next.url().query(), Some("from=CHECKPOINT&take=10"));
+}
+
+#[test]
+fn test_member_roles_encodes_ids() {
+  let client = get_request_client();
+  let req = OrganizationMemberRolesAssign::new(&client, "org_1", "auth0|a/b?c#d")
+    .role("rol_1")
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/organizations/org_1/members/auth0%7Ca%2Fb%3Fc%23d/roles"
+  );
+  assert_eq!(body, serde_json::json!({ "roles": ["rol_1"] }));
+}
+
+#[test]
+fn test_invitations_get_joins_fields() {
+  let client = get_request_client();
+  let req = OrganizationInvitationsGet::new(&client, "org_1")
+    .fields(&["id", "invitation_url"])
+    .include_fields(true)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    req.url().query(),
+    Some("fields=id%2Cinvitation_url&include_fields=true")
+  );
+}
+
+#[test]
+fn test_invitation_create_send_invitation_email() {
+  let client = get_request_client();
+  let body = |create: OrganizationInvitationCreate| -> serde_json::Value {
+    let req = create.build(&client).build().unwrap();
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap()
+  };
+
+  let create =
+    OrganizationInvitationCreate::new(&client, "org_1", "Admin", "a@example.test", "c");
+  assert_eq!(body(create)["send_invitation_email"], true);
+
+  let create =
+    OrganizationInvitationCreate::new(&client, "org_1", "Admin", "a@example.test", "c")
+      .send_invitation_email(false);
+  assert_eq!(body(create)["send_invitation_email"], false);
+}
+
+#[tokio::test]
+async fn test_metadata_filter_follows_checkpoints() {
+  let (base_url, server) = serve_json(vec![
+    r#"{"organizations":[{"id":"org_1","name":"a","metadata":{"tier":"gold"}},{"id":"org_2","name":"b"}],"next":"cp_1"}"#,
+    r#"{"organizations":[{"id":"org_3","name":"c","metadata":{"tier":"silver"}},{"id":"org_4","name":"d","metadata":{"tier":"gold"}}]}"#,
+  ]);
+  let client = get_mock_client(&base_url);
+
+  let organizations = OrganizationsGet::new(&client)
+    .metadata_filter("tier", "gold")
+    .await
+    .unwrap();
+
+  assert_eq!(
+    organizations
+      .iter()
+      .map(|organization| organization.id.as_str())
+      .collect::<Vec<_>>(),
+    vec!["org_1", "org_4"]
+  );
+  assert_eq!(
+    server.join().unwrap(),
+    vec![
+      "/api/v2/organizations?take=100",
+      "/api/v2/organizations?from=cp_1&take=100"
+    ]
+  );
+}
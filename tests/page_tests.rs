@@ -0,0 +1,99 @@
+use auth0_management::{next_link, Pageable, PaginatedStream, Rule, RulesGet};
+use futures::TryStreamExt;
+
+use reqwest::Method;
+
+use crate::helpers::{get_mock_client, get_request_client, serve_json};
+
+mod helpers;
+
+#[test]
+fn test_next_link() {
+  let header = concat!(
+    r#"<https://example.auth0.com/api/v2/users?page=0>; rel="prev", "#,
+    r#"<https://example.auth0.com/api/v2/users?page=2>; rel="next""#,
+  );
+
+  assert_eq!(
+    next_link(header),
+    Some("https://example.auth0.com/api/v2/users?page=2".to_owned())
+  );
+}
+
+#[test]
+fn test_next_link_comma_in_url() {
+  let header = concat!(
+    r#"<https://example.auth0.com/api/v2/users?fields=a,b&page=0>; rel="prev", "#,
+    r#"<https://example.auth0.com/api/v2/users?fields=a,b&page=2>; rel="next""#,
+  );
+
+  assert_eq!(
+    next_link(header),
+    Some("https://example.auth0.com/api/v2/users?fields=a,b&page=2".to_owned())
+  );
+}
+
+#[test]
+fn test_begin_url_same_origin() {
+  let client = get_request_client();
+
+  let req = client
+    .begin_url(Method::GET, "https://example.auth0.com/api/v2/users?page=2")
+    .unwrap()
+    .build()
+    .unwrap();
+  assert_eq!(
+    req.url().as_str(),
+    "https://example.auth0.com/api/v2/users?page=2"
+  );
+
+  let req = client
+    .begin_url(Method::GET, "/api/v2/users?page=2")
+    .unwrap()
+    .build()
+    .unwrap();
+  assert_eq!(
+    req.url().as_str(),
+    "https://example.auth0.com/api/v2/users?page=2"
+  );
+
+  for url in &[
+    "https://attacker.test/api/v2/users?page=2",
+    "http://example.auth0.com/api/v2/users?page=2",
+    "https://example.auth0.com:8443/api/v2/users?page=2",
+  ] {
+    assert!(client.begin_url(Method::GET, url).is_err(), "{}", url);
+  }
+}
+
+#[test]
+fn test_next_link_missing() {
+  assert_eq!(
+    next_link(r#"<https://example.auth0.com/api/v2/users?page=0>; rel="prev""#),
+    None
+  );
+  assert_eq!(next_link("not a link"), None);
+}
+
+#[tokio::test]
+async fn test_paginated_stream() {
+  let (base_url, server) = serve_json(vec![
+    r#"[{"id":"rul_1","name":"a"},{"id":"rul_2","name":"b"}]"#,
+    r#"[{"id":"rul_3","name":"c"}]"#,
+  ]);
+  let client = get_mock_client(&base_url);
+  let rules = RulesGet::new(&client).per_page(2);
+
+  let pages: Vec<Vec<Rule>> = PaginatedStream::new(rules).try_collect().await.unwrap();
+
+  assert_eq!(pages.len(), 2);
+  assert_eq!(pages[0].len(), 2);
+  assert_eq!(pages[1][0].id, "rul_3");
+  assert_eq!(
+    server.join().unwrap(),
+    vec![
+      "/api/v2/rules?page=0&per_page=2",
+      "/api/v2/rules?page=1&per_page=2"
+    ]
+  );
+}
@@ -0,0 +1,73 @@
+use auth0_management::{
+  Auth0RequestBuilder, Prompt, PromptCustomTextGet, PromptCustomTextUpdate,
+  PromptSettings, PromptSettingsUpdate, UniversalLoginExperience,
+};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_prompt_settings_update_body() {
+  let client = get_request_client();
+  let update = PromptSettingsUpdate::new(&client)
+    .universal_login_experience(UniversalLoginExperience::New)
+    .identifier_first(true);
+
+  assert_eq!(
+    serde_json::to_value(&update).unwrap(),
+    serde_json::json!({ "universal_login_experience": "new", "identifier_first": true })
+  );
+}
+
+#[test]
+fn test_prompt_settings_deserialize() {
+  let settings: PromptSettings = serde_json::from_value(serde_json::json!({
+    "universal_login_experience": "classic",
+    "identifier_first": false,
+    "webauthn_platform_first_factor": true
+  }))
+  .unwrap();
+
+  assert_eq!(
+    settings.universal_login_experience,
+    Some(UniversalLoginExperience::Classic)
+  );
+  assert!(settings.webauthn_platform_first_factor);
+}
+
+#[test]
+fn test_prompt_custom_text_update() {
+  let client = get_request_client();
+  let req = PromptCustomTextUpdate::new(&client, Prompt::SignupPassword, "pt-BR")
+    .text("signup-password", "title", "Crie sua senha")
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(req.method(), "PUT");
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/prompts/signup-password/custom-text/pt-BR"
+  );
+  assert_eq!(
+    body,
+    serde_json::json!({ "signup-password": { "title": "Crie sua senha" } })
+  );
+}
+
+#[test]
+fn test_prompt_custom_text_get_encodes_language() {
+  let client = get_request_client();
+  let req = PromptCustomTextGet::new(Prompt::Login, "en/../fr")
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/prompts/login/custom-text/en%2F..%2Ffr"
+  );
+}
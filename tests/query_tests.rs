@@ -0,0 +1,13 @@
+use auth0_management::LuceneQuery;
+
+#[test]
+fn test_eq_escapes_quoted_values() {
+  let q = LuceneQuery::new()
+    .and_eq("name", r#"Jane "JD" Doe"#)
+    .or_eq("email", "jane@example.com");
+
+  assert_eq!(
+    q.to_string(),
+    r#"name:"Jane \"JD\" Doe" OR email:jane@example.com"#
+  );
+}
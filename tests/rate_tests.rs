@@ -1,3 +1,52 @@
+use std::time::Duration;
+
+use auth0_management::rate::{parse_retry_after, retry_delay};
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn test_parse_retry_after_seconds() {
+  assert_eq!(
+    parse_retry_after("30", Utc::now()),
+    Some(Duration::from_secs(30))
+  );
+}
+
+#[test]
+fn test_parse_retry_after_http_date() {
+  let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 27, 30).unwrap();
+
+  assert_eq!(
+    parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+    Some(Duration::from_secs(30))
+  );
+  assert_eq!(
+    parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now),
+    Some(Duration::ZERO)
+  );
+  assert_eq!(parse_retry_after("soon", now), None);
+}
+
+#[test]
+fn test_retry_delay() {
+  let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+  let reset = Some(now + chrono::Duration::seconds(20));
+
+  assert_eq!(
+    retry_delay(Some(Duration::from_secs(5)), reset, now),
+    Duration::from_secs(5)
+  );
+  assert_eq!(retry_delay(None, reset, now), Duration::from_secs(20));
+  assert_eq!(
+    retry_delay(None, Some(now - chrono::Duration::seconds(5)), now),
+    Duration::ZERO
+  );
+  assert_eq!(retry_delay(None, None, now), Duration::from_secs(1));
+  assert_eq!(
+    retry_delay(Some(Duration::from_secs(3600)), None, now),
+    Duration::from_secs(60)
+  );
+}
+
 // use auth0_management::rate::RateLimit;
 // use std::time::{Duration, SystemTime};
 //
@@ -0,0 +1,112 @@
+use auth0_management::{
+  Auth0RequestBuilder, Pageable, ResourceServer, ResourceServerCreate,
+  ResourceServersList, ResponsePage, TokenDialect,
+};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_response_page_with_totals() {
+  let page: ResponsePage<ResourceServer> = serde_json::from_value(serde_json::json!({
+    "resource_servers": [{ "id": "1", "identifier": "https://api.example.com" }],
+    "start": 0,
+    "limit": 50,
+    "total": 1
+  }))
+  .unwrap();
+
+  assert_eq!(page.items.len(), 1);
+  assert_eq!(page.items[0].identifier, "https://api.example.com");
+  assert_eq!(page.total, Some(1));
+}
+
+#[test]
+fn test_response_page_without_totals() {
+  let page: ResponsePage<ResourceServer> = serde_json::from_value(serde_json::json!([
+    { "id": "1", "identifier": "https://api.example.com" }
+  ]))
+  .unwrap();
+
+  assert_eq!(page.items.len(), 1);
+  assert_eq!(page.total, None);
+}
+
+#[test]
+fn test_response_page_ambiguous_arrays() {
+  let res = serde_json::from_value::<ResponsePage<ResourceServer>>(serde_json::json!({
+    "resource_servers": [],
+    "warnings": [],
+    "total": 0
+  }));
+
+  assert!(res.is_err());
+}
+
+#[test]
+fn test_response_page_total_out_of_range() {
+  let page: ResponsePage<ResourceServer> = serde_json::from_value(serde_json::json!({
+    "resource_servers": [],
+    "total": u64::from(u32::MAX) + 1
+  }))
+  .unwrap();
+
+  assert_eq!(page.total, None);
+}
+
+#[test]
+fn test_create_with_rbac() {
+  let client = get_request_client();
+  let req = ResourceServerCreate::with_rbac(&client, "API", "https://api.example.com")
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "identifier": "https://api.example.com",
+      "name": "API",
+      "enforce_policies": true,
+      "token_dialect": "access_token_authz"
+    })
+  );
+}
+
+#[test]
+fn test_resource_servers_list_page() {
+  let client = get_request_client();
+  let req = ResourceServersList::new(&client)
+    .include_totals(true)
+    .page(2)
+    .per_page(25)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/resource-servers");
+  assert_eq!(
+    req.url().query().unwrap(),
+    "include_totals=true&page=2&per_page=25"
+  );
+}
+
+#[test]
+fn test_token_dialect_fallback() {
+  let dialect = |dialect: &str| -> TokenDialect {
+    serde_json::from_value(serde_json::json!(dialect)).unwrap()
+  };
+
+  assert_eq!(dialect("rfc9068_profile"), TokenDialect::Rfc9068Profile);
+  assert_eq!(
+    dialect("new_dialect"),
+    TokenDialect::Unknown("new_dialect".to_owned())
+  );
+  assert_eq!(
+    serde_json::to_value(TokenDialect::Unknown("new_dialect".to_owned())).unwrap(),
+    "new_dialect"
+  );
+}
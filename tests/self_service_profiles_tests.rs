@@ -0,0 +1,41 @@
+use auth0_management::{
+  Auth0RequestBuilder, Pageable, ResponsePage, SelfServiceProfile,
+  SelfServiceProfilesList,
+};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_self_service_profiles_list_page() {
+  let client = get_request_client();
+  let req = SelfServiceProfilesList::new(&client)
+    .include_totals(true)
+    .page(1)
+    .per_page(10)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/self-service-profiles");
+  assert_eq!(
+    req.url().query().unwrap(),
+    "include_totals=true&page=1&per_page=10"
+  );
+}
+
+#[test]
+fn test_self_service_profiles_response_page() {
+  let page: ResponsePage<SelfServiceProfile> =
+    serde_json::from_value(serde_json::json!({
+      "self_service_profiles": [{ "id": "ssp_1", "name": "Default" }],
+      "start": 0,
+      "limit": 50,
+      "total": 1
+    }))
+    .unwrap();
+
+  assert_eq!(page.items[0].id, "ssp_1");
+  assert_eq!(page.total, Some(1));
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDate;
+
+use auth0_management::{Auth0RequestBuilder, DailyStat, DailyStatsGet};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_daily_stats_date_format() {
+  let client = get_request_client();
+  let req = DailyStatsGet::new(&client)
+    .from(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+    .to(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().query().unwrap(), "from=20240101&to=20240131");
+}
+
+#[test]
+fn test_daily_stat_deserialize() {
+  let stat: DailyStat = serde_json::from_value(serde_json::json!({
+    "date": "2024-01-31T00:00:00.000Z",
+    "logins": 100,
+    "signups": 10,
+    "leaked_passwords": 1,
+    "updated_at": "2024-02-01T00:00:00.000Z",
+    "created_at": "2024-01-31T00:00:00.000Z"
+  }))
+  .unwrap();
+
+  assert_eq!(stat.date, NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+  assert_eq!(stat.logins, 100);
+}
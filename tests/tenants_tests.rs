@@ -0,0 +1,46 @@
+use auth0_management::{Auth0RequestBuilder, TenantSettings, TenantSettingsUpdate};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_settings_update_sends_only_set_fields() {
+  let client = get_request_client();
+  let req = TenantSettingsUpdate::new(&client)
+    .friendly_name("Example")
+    .session_lifetime(72.0)
+    .enable_sso(true)
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/tenants/settings");
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "friendly_name": "Example",
+      "session_lifetime": 72.0,
+      "flags": { "enable_sso": true }
+    })
+  );
+}
+
+#[test]
+fn test_settings_deserialize_partial_flags() {
+  let settings: TenantSettings = serde_json::from_value(serde_json::json!({
+    "friendly_name": "Example",
+    "session_lifetime": 168,
+    "idle_session_lifetime": 72,
+    "enabled_locales": ["en", "fr"],
+    "flags": { "enable_client_connections": false, "some_new_flag": true }
+  }))
+  .unwrap();
+
+  assert_eq!(settings.session_lifetime, 168.0);
+  assert_eq!(settings.enabled_locales, vec!["en", "fr"]);
+  assert_eq!(settings.flags.enable_client_connections, Some(false));
+  assert_eq!(settings.flags.enable_sso, None);
+}
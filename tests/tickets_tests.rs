@@ -0,0 +1,27 @@
+use auth0_management::{Auth0RequestBuilder, PasswordChangeTicketCreate};
+
+use crate::helpers::get_request_client;
+
+mod helpers;
+
+#[test]
+fn test_password_change_by_email_omits_user_id() {
+  let client = get_request_client();
+  let req = PasswordChangeTicketCreate::for_email(&client, "con_1", "user@example.com")
+    .ttl_sec(60)
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/tickets/password-change");
+  assert_eq!(
+    body,
+    serde_json::json!({
+      "connection_id": "con_1",
+      "email": "user@example.com",
+      "ttl_sec": 60
+    })
+  );
+}
@@ -1,3 +1,32 @@
+use auth0_management::token::TokenManager;
+use futures::future::join_all;
+use reqwest::Client;
+
+use crate::helpers::serve_json;
+
+mod helpers;
+
+#[tokio::test]
+async fn test_get_token_single_flight() {
+  let (base_url, server) =
+    serve_json(vec![r#"{"access_token":"TOKEN","expires_in":86400}"#]);
+  let manager = TokenManager::new(
+    Client::new(),
+    "example.auth0.com",
+    "https://example.auth0.com/api/v2/",
+    "CLIENT_ID",
+    "CLIENT_SECRET",
+  )
+  .base_url(&base_url);
+
+  let tokens = join_all((0..5).map(|_| manager.get_token())).await;
+
+  for token in tokens {
+    assert_eq!(token.unwrap(), "TOKEN");
+  }
+  assert_eq!(server.join().unwrap(), vec!["/oauth/token"]);
+}
+
 // use std::time::{Duration, SystemTime};
 //
 // use auth0_management::token::{Token, TokenManager};
@@ -1,8 +1,16 @@
 use serde::{Deserialize, Serialize};
 
-use auth0_management::{Ordering, Pageable, Sortable};
+use auth0_management::users::export::export_ndjson;
+use auth0_management::{
+  Auth0, Auth0RequestBuilder, LuceneQuery, Ordering, Pageable, Projectable, ResponsePage,
+  Role, SearchEngine, SecondaryIdentityUpdate, Sortable, User,
+  UserAuthenticationMethodUpdate, UserCreate, UserGet, UserRolesGet, UserUpdate,
+  UsersByPhoneGet, UsersFind,
+};
+use futures::TryStreamExt;
+use serde_json::Value;
 
-use crate::helpers::get_client;
+use crate::helpers::{get_client, get_mock_client, get_request_client, serve_json};
 
 mod helpers;
 
@@ -49,3 +57,257 @@ async fn test_find_user() {
     .await
     .expect("Failed to delete user.");
 }
+
+#[test]
+fn test_find_boosted_query() {
+  let client = get_request_client();
+  let find = UsersFind::new(&client)
+    .q(
+      LuceneQuery::new()
+        .field_boosted_eq("name", "John", 3.0)
+        .field_boosted_eq("email", "john@*", 1.5),
+    )
+    .search_engine(SearchEngine::V3)
+    .per_page(5);
+
+  let req = find.build(&client).build().unwrap();
+  assert_eq!(
+    req.url().query().unwrap(),
+    "q=name%3AJohn%5E3+email%3Ajohn%40*%5E1.5&search_engine=v3&per_page=5"
+  );
+}
+
+#[test]
+fn test_users_by_phone_query() {
+  let client = get_request_client();
+  let req = UsersByPhoneGet::new("+15555550100")
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/users");
+  assert_eq!(
+    req.url().query().unwrap(),
+    "q=phone_number%3A%22%2B15555550100%22&search_engine=v3"
+  );
+}
+
+#[test]
+fn test_user_roles_include_totals() {
+  let client = get_request_client();
+  let req = UserRolesGet::new(&client, "auth0|123")
+    .page(1)
+    .include_totals(true)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(req.url().path(), "/api/v2/users/auth0%7C123/roles");
+  assert_eq!(req.url().query().unwrap(), "page=1&include_totals=true");
+}
+
+#[test]
+fn test_user_roles_page_totals() {
+  let page: ResponsePage<Role> = serde_json::from_value(serde_json::json!({
+    "roles": [{ "id": "rol_1", "name": "admin" }],
+    "start": 0,
+    "limit": 50,
+    "total": 45
+  }))
+  .unwrap();
+
+  assert_eq!(page.items.len(), 1);
+  assert_eq!(page.total, Some(45));
+}
+
+#[test]
+fn test_user_create_default_metadata() {
+  let client = get_request_client();
+  let create: UserCreate = UserCreate::new(&client)
+    .email("test@example.test")
+    .connection("db");
+
+  assert_eq!(
+    serde_json::to_value(&create).unwrap(),
+    serde_json::json!({ "email": "test@example.test", "connection": "db" })
+  );
+}
+
+#[test]
+fn test_user_metadata_as() {
+  #[derive(Deserialize)]
+  struct AppMetadata {
+    plan: String,
+  }
+
+  let user: User = serde_json::from_value(serde_json::json!({
+    "user_id": "auth0|123",
+    "email": "test@example.test",
+    "email_verified": true,
+    "created_at": "2021-01-01T00:00:00.000Z",
+    "updated_at": "2021-01-01T00:00:00.000Z",
+    "identities": [],
+    "picture": "https://example.test/picture.png",
+    "name": "Test",
+    "nickname": "test",
+    "app_metadata": { "plan": "pro" }
+  }))
+  .unwrap();
+
+  assert_eq!(user.app_metadata_as::<AppMetadata>().unwrap().plan, "pro");
+  assert!(user
+    .user_metadata_as::<Option<AppMetadata>>()
+    .unwrap()
+    .is_none());
+}
+
+#[test]
+fn test_user_get_fields() {
+  let client = get_request_client();
+  let req = UserGet::new("auth0|123")
+    .fields(&["email", "user_id"])
+    .include_fields(true)
+    .build(&client)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    req.url().query().unwrap(),
+    "fields=email%2Cuser_id&include_fields=true"
+  );
+
+  let req = UserGet::new("auth0|123").build(&client).build().unwrap();
+  assert_eq!(req.url().query(), None);
+}
+
+#[test]
+fn test_user_update_username() {
+  let client = get_request_client();
+  let update = UserUpdate::new(&client, "auth0|123").username("johndoe");
+
+  assert_eq!(
+    serde_json::to_value(&update).unwrap(),
+    serde_json::json!({ "username": "johndoe" })
+  );
+}
+
+#[test]
+fn test_user_update_multifactor_provider() {
+  let client = get_request_client();
+  let update = UserUpdate::new(&client, "auth0|123")
+    .multifactor_provider("guardian")
+    .multifactor_provider("duo");
+
+  assert_eq!(
+    serde_json::to_value(&update).unwrap(),
+    serde_json::json!({ "multifactor": ["guardian", "duo"] })
+  );
+  assert_eq!(
+    serde_json::to_value(update.app_metadata(serde_json::json!({ "plan": "pro" })))
+      .unwrap(),
+    serde_json::json!({
+      "multifactor": ["guardian", "duo"],
+      "app_metadata": { "plan": "pro" }
+    })
+  );
+}
+
+#[test]
+fn test_secondary_identity_update() {
+  let client = get_request_client();
+  let req = SecondaryIdentityUpdate::new(&client, "auth0|123", "sms")
+    .phone_number("+15555550100")
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(req.method(), "PATCH");
+  assert_eq!(
+    body,
+    serde_json::json!({ "connection": "sms", "phone_number": "+15555550100" })
+  );
+}
+
+#[test]
+fn test_user_authentication_method_update() {
+  let client = get_request_client();
+  let req = UserAuthenticationMethodUpdate::new("auth0|123", "phone|dev_1")
+    .confirmed(true)
+    .build(&client)
+    .build()
+    .unwrap();
+  let body: serde_json::Value =
+    serde_json::from_slice(req.body().unwrap().as_bytes().unwrap()).unwrap();
+
+  assert_eq!(
+    req.url().path(),
+    "/api/v2/users/auth0%7C123/authentication-methods/phone%7Cdev_1"
+  );
+  assert_eq!(body, serde_json::json!({ "confirmed": true }));
+}
+
+#[tokio::test]
+async fn test_export_ndjson() {
+  let (base_url, server) = serve_json(vec![
+    r#"[{"user_id":"auth0|1","email":"a@example.test"},{"user_id":"auth0|2"}]"#,
+  ]);
+  let auth0 = Auth0::new(get_mock_client(&base_url));
+  let mut out = Vec::new();
+
+  let count = export_ndjson(&auth0, &mut out).await.unwrap();
+
+  assert_eq!(count, 2);
+  assert_eq!(
+    String::from_utf8(out).unwrap(),
+    "{\"email\":\"a@example.test\",\"user_id\":\"auth0|1\"}\n{\"user_id\":\"auth0|2\"}\n"
+  );
+  assert_eq!(
+    server.join().unwrap(),
+    vec!["/api/v2/users?page=0&per_page=100"]
+  );
+}
+
+macro_rules! user_json {
+  ($id:literal) => {
+    concat!(
+      r#"{"user_id":""#,
+      $id,
+      r#"","email":"user@example.test","email_verified":true,"#,
+      r#""created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z","#,
+      r#""identities":[],"picture":"","name":"user","nickname":"user"}"#
+    )
+  };
+}
+
+#[tokio::test]
+async fn test_into_user_stream() {
+  let (base_url, server) = serve_json(vec![
+    concat!("[", user_json!("auth0|1"), ",", user_json!("auth0|2"), "]"),
+    concat!("[", user_json!("auth0|3"), "]"),
+  ]);
+  let client = get_mock_client(&base_url);
+
+  let users: Vec<User<Value, Value>> = UsersFind::new(&client)
+    .per_page(2)
+    .into_user_stream()
+    .try_collect()
+    .await
+    .unwrap();
+
+  assert_eq!(
+    users
+      .iter()
+      .map(|user| user.user_id.as_str())
+      .collect::<Vec<_>>(),
+    vec!["auth0|1", "auth0|2", "auth0|3"]
+  );
+  assert_eq!(
+    server.join().unwrap(),
+    vec![
+      "/api/v2/users?page=0&per_page=2",
+      "/api/v2/users?page=1&per_page=2"
+    ]
+  );
+}